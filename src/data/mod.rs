@@ -0,0 +1,5 @@
+pub mod rng;
+pub mod save;
+pub mod settings;
+pub mod states;
+pub mod stats;
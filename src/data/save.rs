@@ -0,0 +1,62 @@
+use std::path::PathBuf;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Long-lived player progress flags, persisted across sessions.
+#[derive(Resource, Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SaveState {
+    pub calibration_complete: bool,
+    pub lever_ending_reached: bool,
+    /// Set by the ultra-violent false start; unlocks rampage mode.
+    #[serde(default)]
+    pub rampage_unlocked: bool,
+    /// Set while a replay is running so nothing writes progress.
+    #[serde(skip)]
+    pub suppress_writes: bool,
+}
+
+/// Directory our persisted files (save, window layout, collections) live
+/// in. Falls back to the working directory when no platform dir exists.
+pub fn user_data_dir() -> PathBuf {
+    let base = std::env::var_os("XDG_DATA_HOME")
+        .map(PathBuf::from)
+        .or_else(|| {
+            std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".local/share"))
+        })
+        .or_else(|| std::env::var_os("APPDATA").map(PathBuf::from))
+        .unwrap_or_else(|| PathBuf::from("."));
+    base.join("morality_engine")
+}
+
+/// Serializes a value as RON into the user data dir, logging rather than
+/// failing on IO errors: losing a save write should never crash the game.
+pub fn write_ron<T: Serialize>(file_name: &str, value: &T) {
+    let dir = user_data_dir();
+    if let Err(error) = std::fs::create_dir_all(&dir) {
+        warn!("could not create data dir {dir:?}: {error}");
+        return;
+    }
+    match ron::ser::to_string_pretty(value, ron::ser::PrettyConfig::default()) {
+        Ok(serialized) => {
+            if let Err(error) = std::fs::write(dir.join(file_name), serialized) {
+                warn!("could not write {file_name}: {error}");
+            }
+        }
+        Err(error) => warn!("could not serialize {file_name}: {error}"),
+    }
+}
+
+/// Reads a RON value from the user data dir, returning `None` when the
+/// file is absent or unreadable.
+pub fn read_ron<T: for<'de> Deserialize<'de>>(file_name: &str) -> Option<T> {
+    let path = user_data_dir().join(file_name);
+    let contents = std::fs::read_to_string(path).ok()?;
+    match ron::from_str(&contents) {
+        Ok(value) => Some(value),
+        Err(error) => {
+            warn!("could not parse {file_name}: {error}");
+            None
+        }
+    }
+}
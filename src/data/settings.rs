@@ -0,0 +1,225 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::data::save::{read_ron, write_ron};
+
+const SETTINGS_FILE: &str = "settings.ron";
+
+/// How the primary window occupies the screen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum VideoDisplayMode {
+    #[default]
+    Windowed,
+    Borderless,
+}
+
+/// Optional FPS cap, for machines where uncapped rendering (vsync off)
+/// would spin the GPU needlessly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum FrameLimit {
+    #[default]
+    Off,
+    Fps30,
+    Fps60,
+    Fps120,
+    Fps144,
+}
+
+impl FrameLimit {
+    /// Every selectable cap, in menu cycling order.
+    pub const ALL: [FrameLimit; 5] = [
+        FrameLimit::Off,
+        FrameLimit::Fps30,
+        FrameLimit::Fps60,
+        FrameLimit::Fps120,
+        FrameLimit::Fps144,
+    ];
+
+    /// The cap in frames per second, or `None` when unlimited.
+    pub fn max_fps(self) -> Option<u32> {
+        match self {
+            FrameLimit::Off => None,
+            FrameLimit::Fps30 => Some(30),
+            FrameLimit::Fps60 => Some(60),
+            FrameLimit::Fps120 => Some(120),
+            FrameLimit::Fps144 => Some(144),
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            FrameLimit::Off => "OFF",
+            FrameLimit::Fps30 => "30 FPS",
+            FrameLimit::Fps60 => "60 FPS",
+            FrameLimit::Fps120 => "120 FPS",
+            FrameLimit::Fps144 => "144 FPS",
+        }
+    }
+}
+
+/// Persisted video choices. The live window is the source of truth while
+/// running; this is what survives restarts.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct VideoSettings {
+    pub display_mode: VideoDisplayMode,
+    pub vsync: bool,
+    pub resolution: (u32, u32),
+    #[serde(default)]
+    pub frame_limit: FrameLimit,
+    /// Screen brightness multiplier in `[0.5, 1.5]`, rendered as an
+    /// overlay rather than an OS-level gamma change.
+    #[serde(default = "default_brightness")]
+    pub brightness: f32,
+}
+
+fn default_brightness() -> f32 {
+    1.0
+}
+
+impl Default for VideoSettings {
+    fn default() -> Self {
+        Self {
+            display_mode: VideoDisplayMode::Windowed,
+            vsync: true,
+            resolution: (1280, 720),
+            frame_limit: FrameLimit::Off,
+            brightness: default_brightness(),
+        }
+    }
+}
+
+/// A mixer channel the options menu can adjust.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AudioChannel {
+    Master,
+    Music,
+    Sfx,
+    Ui,
+}
+
+/// Persisted mixer levels, all in `[0, 1]`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct AudioSettings {
+    pub master_volume: f32,
+    pub music_volume: f32,
+    pub sfx_volume: f32,
+    #[serde(default = "default_ui_volume")]
+    pub ui_volume: f32,
+}
+
+fn default_ui_volume() -> f32 {
+    1.0
+}
+
+impl Default for AudioSettings {
+    fn default() -> Self {
+        Self {
+            master_volume: 1.0,
+            music_volume: 0.8,
+            sfx_volume: 1.0,
+            ui_volume: 1.0,
+        }
+    }
+}
+
+impl AudioSettings {
+    pub fn volume(&self, channel: AudioChannel) -> f32 {
+        match channel {
+            AudioChannel::Master => self.master_volume,
+            AudioChannel::Music => self.music_volume,
+            AudioChannel::Sfx => self.sfx_volume,
+            AudioChannel::Ui => self.ui_volume,
+        }
+    }
+
+    /// Steps a channel by `delta` (a step is 10%), clamped to `[0, 1]`.
+    pub fn adjust(&mut self, channel: AudioChannel, delta: i8) {
+        let volume = match channel {
+            AudioChannel::Master => &mut self.master_volume,
+            AudioChannel::Music => &mut self.music_volume,
+            AudioChannel::Sfx => &mut self.sfx_volume,
+            AudioChannel::Ui => &mut self.ui_volume,
+        };
+        *volume = (*volume + delta as f32 * 0.1).clamp(0.0, 1.0);
+    }
+
+    /// Sets a channel to an absolute level, clamped to `[0, 1]`.
+    pub fn set_volume(&mut self, channel: AudioChannel, volume: f32) {
+        let slot = match channel {
+            AudioChannel::Master => &mut self.master_volume,
+            AudioChannel::Music => &mut self.music_volume,
+            AudioChannel::Sfx => &mut self.sfx_volume,
+            AudioChannel::Ui => &mut self.ui_volume,
+        };
+        *slot = volume.clamp(0.0, 1.0);
+    }
+}
+
+/// Which colorblind remap the palette uses. The colour values
+/// themselves live in `systems::colors`; this is just the persisted
+/// selector.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ColorblindMode {
+    #[default]
+    None,
+    Protanopia,
+    Deuteranopia,
+    Tritanopia,
+}
+
+impl ColorblindMode {
+    /// Every selectable mode, in menu cycling order.
+    pub const ALL: [ColorblindMode; 4] = [
+        ColorblindMode::None,
+        ColorblindMode::Protanopia,
+        ColorblindMode::Deuteranopia,
+        ColorblindMode::Tritanopia,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ColorblindMode::None => "OFF",
+            ColorblindMode::Protanopia => "PROTANOPIA",
+            ColorblindMode::Deuteranopia => "DEUTERANOPIA",
+            ColorblindMode::Tritanopia => "TRITANOPIA",
+        }
+    }
+}
+
+/// Persisted interface preferences.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct InterfaceSettings {
+    /// Global UI scale factor applied to menu and table font sizes.
+    pub ui_scale: f32,
+    #[serde(default)]
+    pub colorblind_mode: ColorblindMode,
+}
+
+impl Default for InterfaceSettings {
+    fn default() -> Self {
+        Self {
+            ui_scale: 1.0,
+            colorblind_mode: ColorblindMode::None,
+        }
+    }
+}
+
+/// Everything the options menu persists, stored as one RON file so the
+/// pages stay in sync on disk.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct UserSettings {
+    pub video: VideoSettings,
+    pub audio: AudioSettings,
+    #[serde(default)]
+    pub interface: InterfaceSettings,
+}
+
+impl UserSettings {
+    pub fn load() -> Self {
+        read_ron(SETTINGS_FILE).unwrap_or_default()
+    }
+
+    pub fn save(&self) {
+        write_ron(SETTINGS_FILE, self);
+    }
+}
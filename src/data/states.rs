@@ -0,0 +1,35 @@
+use bevy::prelude::*;
+
+/// Top level flow of the game.
+#[derive(States, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum MainState {
+    /// Startup text and loading bar before anything interactive.
+    #[default]
+    Boot,
+    Menu,
+    Dilemma,
+    /// The custom-dilemma builder, unlocked by calibration.
+    Sandbox,
+    /// The falling-levers toy, unlocked by the lever ending.
+    LeverHeaven,
+    Ending,
+}
+
+/// Whether gameplay is currently paused behind a menu.
+#[derive(States, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum PauseState {
+    #[default]
+    Running,
+    Paused,
+}
+
+/// Phases a dilemma moves through once loaded.
+#[derive(SubStates, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[source(MainState = MainState::Dilemma)]
+pub enum DilemmaPhase {
+    #[default]
+    Intro,
+    Decision,
+    Resolution,
+    Results,
+}
@@ -0,0 +1,72 @@
+use bevy::prelude::*;
+
+use crate::scenes::dilemma::{decision::DecisionKind, DilemmaId};
+
+/// One committed choice within a run.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DecisionRecord {
+    pub dilemma: DilemmaId,
+    pub kind: DecisionKind,
+    /// Seconds into the decision phase the input landed.
+    pub at_secs: f32,
+}
+
+/// The full record of a run: the RNG seed plus every decision in order.
+/// Enough to re-execute the run deterministically.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct DecisionLog {
+    pub seed: u64,
+    pub records: Vec<DecisionRecord>,
+}
+
+impl DecisionLog {
+    pub fn push(&mut self, record: DecisionRecord) {
+        self.records.push(record);
+    }
+
+    pub fn clear(&mut self) {
+        self.records.clear();
+    }
+}
+
+/// Aggregate outcome tallies for the current run.
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RunStats {
+    pub killed: u32,
+    pub saved: u32,
+    pub decisions: u32,
+    pub drifts: u32,
+    /// Trains brought to rest by the slow-death lever.
+    pub slowed: u32,
+}
+
+impl RunStats {
+    pub fn record_decision(&mut self, kind: DecisionKind) {
+        self.decisions += 1;
+        if kind == DecisionKind::Drift {
+            self.drifts += 1;
+        }
+    }
+}
+
+/// How violent the paths taken so far have been, across runs within a
+/// session. Drives cosmetic escalation (the bloodying title) rather
+/// than gameplay.
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PathChoices {
+    pub violent: u32,
+    pub merciful: u32,
+}
+
+impl PathChoices {
+    /// 0 for an untouched or merciful record, approaching 1 as violent
+    /// choices dominate.
+    pub fn blood_level(&self) -> f32 {
+        let total = self.violent + self.merciful;
+        if total == 0 {
+            0.0
+        } else {
+            self.violent as f32 / total as f32
+        }
+    }
+}
@@ -0,0 +1,113 @@
+// MORALITY ENGINE
+//
+// TODO (rough, in no particular order):
+//  - Menus:
+//      - Level selector for debug + completed levels
+//      - Clock + day/night cycle on the menu screen
+//      - Title gets bloodier the more violent the path taken,
+//        letters flee the mouse, can be exploded
+//  - Dilemma:
+//      - Reaction time ending. Bomb if no selection
+//      - Consequence descriptions under each choice
+//      - Hover highlight + "this train will not stop" on train click
+//      - Flashy Selector
+//      - Drift Button
+//      - Slow death lever (lever only slows the train)
+//      - Window Ordering to resolve z-fighting issues
+//      - Link Clickthrough
+//      - Train Blood persistant
+//  - Dialogue:
+//      - System Startup Text
+//      - Simulation Loading Text and Bar
+//      - Make dialogue more robust
+//      - Fix dialogue fadeout
+//  - Results screen:
+//      - Colored numbers
+//      - Fireworks
+//  - Audio:
+//      - Bitcrushed "daisy" for the vengeance ending
+//      - Spatial audio? Train approaching from the left should sound left
+//      - Clickable nodes in graph make tune
+//  - Long term:
+//      - Achievements
+//      - Sandbox mode unlocked by calibration
+//      - Rampage mode unlocked by the ultra violent false start
+//      - Lever heaven MODE unlocked by the lever ending
+
+mod data;
+mod scenes;
+mod systems;
+mod ui;
+
+use bevy::prelude::*;
+
+use crate::{
+    data::{
+        rng::GameRng,
+        save::SaveState,
+        states::{DilemmaPhase, MainState, PauseState},
+        stats::{DecisionLog, PathChoices, RunStats},
+    },
+    scenes::{
+        boot::BootPlugin, dilemma::DilemmaPlugin, ending::EndingPlugin,
+        lever_heaven::LeverHeavenPlugin, menu::MenuScenePlugin, sandbox::SandboxPlugin,
+    },
+    systems::{
+        achievements::AchievementsPlugin, audio::AudioSystemsPlugin, colors::ColorScheme,
+        interaction::InteractionPlugin, scheduling::SchedulingPlugin, time::TimePlugin,
+    },
+    ui::{
+        context_menu::ContextMenuPlugin, focus::FocusPlugin, graph::GraphPlugin,
+        hold_confirm::HoldConfirmPlugin, menu::MenuPlugin, notifications::NotificationsPlugin,
+        text::RichTextPlugin, tooltip::TooltipPlugin,
+        window::WindowPlugin,
+    },
+};
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins.set(bevy::window::WindowPlugin {
+            primary_window: Some(bevy::window::Window {
+                title: String::from("MORALITY ENGINE"),
+                ..default()
+            }),
+            ..default()
+        }))
+        .init_state::<MainState>()
+        .init_state::<PauseState>()
+        .add_sub_state::<DilemmaPhase>()
+        .init_resource::<GameRng>()
+        .init_resource::<SaveState>()
+        .init_resource::<DecisionLog>()
+        .init_resource::<RunStats>()
+        .init_resource::<PathChoices>()
+        .init_resource::<ColorScheme>()
+        .add_plugins((
+            TimePlugin,
+            AudioSystemsPlugin,
+            AchievementsPlugin,
+            SchedulingPlugin,
+            InteractionPlugin,
+            WindowPlugin,
+            MenuPlugin,
+            FocusPlugin,
+            TooltipPlugin,
+            ContextMenuPlugin,
+            HoldConfirmPlugin,
+            NotificationsPlugin,
+            RichTextPlugin,
+            GraphPlugin,
+            BootPlugin,
+            DilemmaPlugin,
+            EndingPlugin,
+            LeverHeavenPlugin,
+            MenuScenePlugin,
+            SandboxPlugin,
+        ))
+        .add_systems(Startup, setup_camera)
+        .run();
+}
+
+fn setup_camera(mut commands: Commands) {
+    commands.spawn((Camera2d, ui::window::OffscreenCamera));
+}
@@ -0,0 +1,239 @@
+use bevy::prelude::*;
+
+use crate::{
+    data::states::MainState,
+    systems::colors::{DIM_COLOR, PRIMARY_COLOR},
+    ui::{
+        shapes::HollowRectangle,
+        text::Typewriter,
+    },
+};
+
+/// Startup lines typed out while the system "comes online". Edit freely;
+/// the scene lays them out and paces them from this list alone.
+pub const BOOT_LINES: &[&str] = &[
+    "MORALITY ENGINE v2.3.1",
+    "INITIALISING ETHICS CORE .......... OK",
+    "LOADING TROLLEY SUBSYSTEM ......... OK",
+    "CALIBRATING LEVER SERVOS .......... OK",
+    "MOUNTING DECISION LOG ............. OK",
+    "OPERATOR DETECTED.",
+    "BEGINNING SIMULATION.",
+];
+
+/// Holding-page skip: jump straight past the boot sequence.
+pub const BOOT_SKIP_KEY: KeyCode = KeyCode::Escape;
+
+/// Characters per second the boot lines type at; faster than dialogue so
+/// the sequence reads as machine output rather than speech.
+pub const BOOT_TYPE_SPEED: f32 = 60.0;
+
+/// Seconds the loading bar takes to fill once the lines are done. There
+/// is nothing heavyweight to actually wait on yet, so the bar paces the
+/// fiction rather than real IO.
+pub const BOOT_BAR_SECS: f32 = 1.6;
+
+const BOOT_LINE_HEIGHT: f32 = 22.0;
+const BOOT_FONT_SIZE: f32 = 14.0;
+const BOOT_BAR_SIZE: Vec2 = Vec2::new(320.0, 18.0);
+const BOOT_BAR_INSET: f32 = 3.0;
+
+/// Root of the boot screen; everything despawns with it on exit.
+#[derive(Component)]
+struct BootScreen;
+
+/// One typed startup line; the next spawns when this one completes.
+#[derive(Component)]
+struct BootLine {
+    index: usize,
+}
+
+/// The loading bar frame; `elapsed_secs` runs once every line has typed.
+#[derive(Component)]
+struct BootLoadingBar {
+    elapsed_secs: f32,
+}
+
+/// The sprite filling the bar from the left.
+#[derive(Component)]
+struct BootBarFill;
+
+/// The "LOADING NN%" label under the bar.
+#[derive(Component)]
+struct BootBarLabel;
+
+/// Fraction of the bar filled after `elapsed_secs` of loading.
+pub fn boot_bar_fraction(elapsed_secs: f32) -> f32 {
+    (elapsed_secs / BOOT_BAR_SECS).clamp(0.0, 1.0)
+}
+
+/// Width and centre x of the left-anchored fill sprite inside the frame.
+pub fn boot_bar_fill_geometry(fraction: f32) -> (f32, f32) {
+    let inner = BOOT_BAR_SIZE.x - 2.0 * BOOT_BAR_INSET;
+    let width = inner * fraction.clamp(0.0, 1.0);
+    (width, -inner * 0.5 + width * 0.5)
+}
+
+/// Local y of line `index`, counting down from the top of the block.
+fn boot_line_y(index: usize) -> f32 {
+    let block = BOOT_LINES.len() as f32 * BOOT_LINE_HEIGHT;
+    block * 0.5 - (index as f32 + 0.5) * BOOT_LINE_HEIGHT
+}
+
+fn spawn_boot_line(commands: &mut Commands, screen: Entity, index: usize) {
+    commands.spawn((
+        BootLine { index },
+        Text2d::new(BOOT_LINES[index]),
+        TextFont::from_font_size(BOOT_FONT_SIZE),
+        TextColor(PRIMARY_COLOR),
+        Typewriter::new(BOOT_TYPE_SPEED),
+        bevy::sprite::Anchor::CenterLeft,
+        Transform::from_xyz(-BOOT_BAR_SIZE.x * 0.5, boot_line_y(index), 0.1),
+        ChildOf(screen),
+    ));
+}
+
+fn spawn_boot_screen(mut commands: Commands) {
+    let screen = commands
+        .spawn((
+            BootScreen,
+            Transform::default(),
+            Visibility::Inherited,
+        ))
+        .id();
+    spawn_boot_line(&mut commands, screen, 0);
+
+    let bar_y = boot_line_y(BOOT_LINES.len()) - BOOT_LINE_HEIGHT;
+    let bar = commands
+        .spawn((
+            BootLoadingBar { elapsed_secs: 0.0 },
+            HollowRectangle {
+                dimensions: BOOT_BAR_SIZE,
+                thickness: 1.0,
+                color: DIM_COLOR,
+            },
+            Transform::from_xyz(0.0, bar_y, 0.1),
+            ChildOf(screen),
+        ))
+        .id();
+    commands.spawn((
+        BootBarFill,
+        Sprite {
+            color: PRIMARY_COLOR,
+            custom_size: Some(Vec2::new(0.0, BOOT_BAR_SIZE.y - 2.0 * BOOT_BAR_INSET)),
+            ..default()
+        },
+        Transform::from_xyz(0.0, 0.0, 0.1),
+        ChildOf(bar),
+    ));
+    commands.spawn((
+        BootBarLabel,
+        Text2d::new("LOADING 0%"),
+        TextFont::from_font_size(BOOT_FONT_SIZE),
+        TextColor(DIM_COLOR),
+        Transform::from_xyz(0.0, -BOOT_BAR_SIZE.y - 6.0, 0.1),
+        ChildOf(bar),
+    ));
+}
+
+/// Spawns the next startup line once the newest one finishes typing.
+fn advance_boot_lines(
+    mut commands: Commands,
+    screens: Query<Entity, With<BootScreen>>,
+    lines: Query<(&BootLine, &Typewriter)>,
+) {
+    let Ok(screen) = screens.single() else {
+        return;
+    };
+    let Some((line, typewriter)) = lines.iter().max_by_key(|(line, _)| line.index) else {
+        return;
+    };
+    if typewriter.is_complete() && line.index + 1 < BOOT_LINES.len() {
+        spawn_boot_line(&mut commands, screen, line.index + 1);
+    }
+}
+
+/// Fills the loading bar once every line has typed, then hands over to
+/// the menu.
+fn fill_boot_loading_bar(
+    time: Res<Time>,
+    mut next_state: ResMut<NextState<MainState>>,
+    lines: Query<(&BootLine, &Typewriter)>,
+    mut bars: Query<&mut BootLoadingBar>,
+    mut fills: Query<(&mut Sprite, &mut Transform), With<BootBarFill>>,
+    mut labels: Query<&mut Text2d, With<BootBarLabel>>,
+) {
+    let all_typed = lines.iter().count() == BOOT_LINES.len()
+        && lines.iter().all(|(_, typewriter)| typewriter.is_complete());
+    if !all_typed {
+        return;
+    }
+    for mut bar in &mut bars {
+        bar.elapsed_secs += time.delta_secs();
+        let fraction = boot_bar_fraction(bar.elapsed_secs);
+        let (width, centre_x) = boot_bar_fill_geometry(fraction);
+        for (mut sprite, mut transform) in &mut fills {
+            if let Some(size) = sprite.custom_size.as_mut() {
+                size.x = width;
+            }
+            transform.translation.x = centre_x;
+        }
+        for mut label in &mut labels {
+            label.0 = format!("LOADING {:.0}%", fraction * 100.0);
+        }
+        if fraction >= 1.0 {
+            next_state.set(MainState::Menu);
+        }
+    }
+}
+
+/// The skip key drops the whole ceremony and goes straight to the menu.
+fn skip_boot_sequence(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut next_state: ResMut<NextState<MainState>>,
+) {
+    if keys.just_pressed(BOOT_SKIP_KEY) {
+        next_state.set(MainState::Menu);
+    }
+}
+
+fn despawn_boot_screen(mut commands: Commands, screens: Query<Entity, With<BootScreen>>) {
+    for screen in &screens {
+        commands.entity(screen).despawn();
+    }
+}
+
+pub struct BootPlugin;
+
+impl Plugin for BootPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(MainState::Boot), spawn_boot_screen)
+            .add_systems(
+                Update,
+                (advance_boot_lines, fill_boot_loading_bar, skip_boot_sequence)
+                    .run_if(in_state(MainState::Boot)),
+            )
+            .add_systems(OnExit(MainState::Boot), despawn_boot_screen);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_bar_fills_left_anchored_and_clamps() {
+        assert_eq!(boot_bar_fraction(0.0), 0.0);
+        assert_eq!(boot_bar_fraction(BOOT_BAR_SECS * 2.0), 1.0);
+        let (empty_width, _) = boot_bar_fill_geometry(0.0);
+        assert_eq!(empty_width, 0.0);
+        let (full_width, full_centre) = boot_bar_fill_geometry(1.0);
+        assert_eq!(full_width, BOOT_BAR_SIZE.x - 2.0 * BOOT_BAR_INSET);
+        // A full bar sits centred in the frame.
+        assert!(full_centre.abs() < f32::EPSILON);
+        // A half bar hugs the left edge.
+        let (half_width, half_centre) = boot_bar_fill_geometry(0.5);
+        assert!(half_centre < 0.0);
+        assert_eq!(half_width, full_width * 0.5);
+    }
+}
@@ -0,0 +1,121 @@
+use bevy::{prelude::*, text::TextBounds};
+
+use crate::{
+    data::states::DilemmaPhase,
+    systems::{colors::SYSTEM_MENU_COLOR, interaction::Hoverable},
+};
+
+/// Width the consequence text wraps inside; long descriptions break
+/// rather than marching off screen.
+pub const CONSEQUENCE_PANEL_WIDTH: f32 = 320.0;
+
+const CONSEQUENCE_PANEL_Y: f32 = -200.0;
+const CONSEQUENCE_FONT_SIZE: f32 = 13.0;
+
+/// A short "what happens if you pick this" blurb on a track or lever
+/// choice. Needs a [`Hoverable`] on the same entity; the panel shows the
+/// hovered choice's text and clears when nothing is hovered.
+#[derive(Component, Debug, Clone)]
+pub struct ChoiceConsequence {
+    pub text: String,
+}
+
+impl ChoiceConsequence {
+    pub fn new(text: impl Into<String>) -> Self {
+        Self { text: text.into() }
+    }
+}
+
+/// The floating panel the hovered consequence renders into.
+#[derive(Component)]
+struct ConsequencePanel;
+
+/// Picks which consequence to show: the choice the cursor has rested on
+/// longest, so a cursor grazing two overlapping regions reads stably.
+pub fn hovered_consequence<'a>(
+    choices: impl IntoIterator<Item = (&'a ChoiceConsequence, &'a Hoverable)>,
+) -> Option<&'a str> {
+    choices
+        .into_iter()
+        .filter(|(_, hoverable)| hoverable.hovered)
+        .max_by(|(_, a), (_, b)| a.hovered_secs.total_cmp(&b.hovered_secs))
+        .map(|(consequence, _)| consequence.text.as_str())
+}
+
+/// Keeps the panel in step with the hovered choice: spawns it on the
+/// first hover, retargets its text as the hover moves, despawns it when
+/// the cursor leaves every choice.
+fn sync_consequence_panel(
+    mut commands: Commands,
+    choices: Query<(&ChoiceConsequence, &Hoverable)>,
+    mut panels: Query<(Entity, &mut Text2d), With<ConsequencePanel>>,
+) {
+    let Some(text) = hovered_consequence(&choices) else {
+        for (panel, _) in &panels {
+            commands.entity(panel).despawn();
+        }
+        return;
+    };
+    if let Ok((_, mut panel_text)) = panels.single_mut() {
+        if panel_text.0 != text {
+            panel_text.0 = text.to_string();
+        }
+        return;
+    }
+    commands.spawn((
+        ConsequencePanel,
+        Text2d::new(text),
+        TextFont::from_font_size(CONSEQUENCE_FONT_SIZE),
+        TextColor(SYSTEM_MENU_COLOR),
+        TextLayout::new_with_justify(JustifyText::Center),
+        TextBounds::new_horizontal(CONSEQUENCE_PANEL_WIDTH),
+        Transform::from_xyz(0.0, CONSEQUENCE_PANEL_Y, 0.5),
+    ));
+}
+
+fn despawn_consequence_panel(
+    mut commands: Commands,
+    panels: Query<Entity, With<ConsequencePanel>>,
+) {
+    for panel in &panels {
+        commands.entity(panel).despawn();
+    }
+}
+
+pub struct ConsequencePlugin;
+
+impl Plugin for ConsequencePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            sync_consequence_panel.run_if(in_state(DilemmaPhase::Decision)),
+        )
+        .add_systems(OnExit(DilemmaPhase::Decision), despawn_consequence_panel);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hovering(secs: f32) -> Hoverable {
+        let mut hoverable = Hoverable::new(Vec2::splat(10.0));
+        hoverable.hovered = true;
+        hoverable.hovered_secs = secs;
+        hoverable
+    }
+
+    #[test]
+    fn the_longest_hover_wins_and_no_hover_clears() {
+        let main = (ChoiceConsequence::new("FIVE DIE"), hovering(0.8));
+        let side = (ChoiceConsequence::new("ONE DIES"), hovering(0.2));
+        let idle = (ChoiceConsequence::new("UNSEEN"), Hoverable::new(Vec2::splat(10.0)));
+        let picked = hovered_consequence([
+            (&main.0, &main.1),
+            (&side.0, &side.1),
+            (&idle.0, &idle.1),
+        ]);
+        assert_eq!(picked, Some("FIVE DIE"));
+        assert_eq!(hovered_consequence([(&idle.0, &idle.1)]), None);
+    }
+}
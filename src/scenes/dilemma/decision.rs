@@ -0,0 +1,147 @@
+use bevy::prelude::*;
+
+use crate::data::states::DilemmaPhase;
+
+/// How long an early decision press stays valid. Uses real time so a
+/// frame hitch or heavy `Dilation` cannot eat a tap.
+pub const DECISION_INPUT_BUFFER_SECS: f64 = 0.12;
+
+/// The ways a player can resolve a decision.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecisionKind {
+    Commit,
+    Drift,
+}
+
+/// Fired when a decision input lands while the decision phase is active.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct DecisionEvent {
+    pub kind: DecisionKind,
+}
+
+/// A decision press recorded slightly before the decision phase became
+/// active (or during a hitch). Consumed by the decision system if still
+/// inside the buffer window when the phase opens.
+#[derive(Resource, Debug, Default)]
+pub struct BufferedDecisionInput {
+    pending: Option<(DecisionKind, f64)>,
+}
+
+impl BufferedDecisionInput {
+    /// Records a press at `now` (seconds of real time), replacing any
+    /// earlier pending press.
+    pub fn record(&mut self, kind: DecisionKind, now: f64) {
+        self.pending = Some((kind, now));
+    }
+
+    /// Takes the pending press if it is still within the buffer window.
+    /// Stale presses are dropped either way.
+    pub fn take_fresh(&mut self, now: f64) -> Option<DecisionKind> {
+        let (kind, pressed_at) = self.pending.take()?;
+        (now - pressed_at <= DECISION_INPUT_BUFFER_SECS).then_some(kind)
+    }
+
+    pub fn clear(&mut self) {
+        self.pending = None;
+    }
+}
+
+/// The drift input: weave between the tracks instead of committing.
+pub const DECISION_DRIFT_KEY: KeyCode = KeyCode::KeyD;
+
+fn pressed_decision(keys: &ButtonInput<KeyCode>) -> Option<DecisionKind> {
+    if keys.just_pressed(KeyCode::Enter) || keys.just_pressed(KeyCode::Space) {
+        Some(DecisionKind::Commit)
+    } else if keys.just_pressed(DECISION_DRIFT_KEY) {
+        Some(DecisionKind::Drift)
+    } else {
+        None
+    }
+}
+
+/// Captures decision presses. While the decision phase is active they
+/// fire immediately; otherwise they are buffered so a tap landing just
+/// before the phase opens still registers.
+fn capture_decision_input(
+    keys: Res<ButtonInput<KeyCode>>,
+    time: Res<Time<Real>>,
+    phase: Option<Res<State<DilemmaPhase>>>,
+    mut buffered: ResMut<BufferedDecisionInput>,
+    mut events: EventWriter<DecisionEvent>,
+) {
+    let Some(kind) = pressed_decision(&keys) else {
+        return;
+    };
+    let in_decision = phase.is_some_and(|phase| *phase.get() == DilemmaPhase::Decision);
+    if in_decision {
+        events.write(DecisionEvent { kind });
+    } else {
+        buffered.record(kind, time.elapsed_secs_f64());
+    }
+}
+
+/// Flushes a still-fresh buffered press once the decision phase is
+/// active.
+fn consume_buffered_decision(
+    time: Res<Time<Real>>,
+    mut buffered: ResMut<BufferedDecisionInput>,
+    mut events: EventWriter<DecisionEvent>,
+) {
+    if let Some(kind) = buffered.take_fresh(time.elapsed_secs_f64()) {
+        events.write(DecisionEvent { kind });
+    }
+}
+
+fn clear_buffered_decision(mut buffered: ResMut<BufferedDecisionInput>) {
+    buffered.clear();
+}
+
+pub struct DecisionPlugin;
+
+impl Plugin for DecisionPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<BufferedDecisionInput>()
+            .add_event::<DecisionEvent>()
+            .add_systems(
+                Update,
+                (
+                    capture_decision_input,
+                    consume_buffered_decision.run_if(in_state(DilemmaPhase::Decision)),
+                )
+                    .chain(),
+            )
+            // Stale presses must not fire after a phase change or restart.
+            .add_systems(OnExit(DilemmaPhase::Decision), clear_buffered_decision)
+            .add_systems(OnEnter(DilemmaPhase::Intro), clear_buffered_decision);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn early_press_fires_when_phase_opens_within_window() {
+        let mut buffered = BufferedDecisionInput::default();
+        buffered.record(DecisionKind::Commit, 10.0);
+        // The phase opens 80ms later: inside the buffer window.
+        assert_eq!(buffered.take_fresh(10.08), Some(DecisionKind::Commit));
+        // Consumed: a second flush must not fire again.
+        assert_eq!(buffered.take_fresh(10.09), None);
+    }
+
+    #[test]
+    fn stale_press_is_dropped() {
+        let mut buffered = BufferedDecisionInput::default();
+        buffered.record(DecisionKind::Commit, 10.0);
+        assert_eq!(buffered.take_fresh(10.0 + DECISION_INPUT_BUFFER_SECS + 0.01), None);
+    }
+
+    #[test]
+    fn clear_discards_pending_press() {
+        let mut buffered = BufferedDecisionInput::default();
+        buffered.record(DecisionKind::Commit, 10.0);
+        buffered.clear();
+        assert_eq!(buffered.take_fresh(10.01), None);
+    }
+}
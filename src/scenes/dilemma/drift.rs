@@ -0,0 +1,214 @@
+use bevy::prelude::*;
+
+use crate::{
+    data::{
+        rng::GameRng,
+        states::{DilemmaPhase, PauseState},
+        stats::RunStats,
+    },
+    scenes::dilemma::{
+        decision::{DecisionEvent, DecisionKind},
+        timer::DecisionTimerExpired,
+        train::Train,
+    },
+    systems::time::Dilation,
+};
+
+/// World distance between the two track centrelines the drifting train
+/// weaves across.
+pub const TRACK_SPACING: f32 = 80.0;
+/// Seconds of drifting at which the derail chance reaches its cap.
+const DERAIL_RAMP_SECS: f32 = 6.0;
+/// Derail probability once the ramp saturates.
+const MAX_DERAIL_CHANCE: f32 = 0.75;
+/// Weave cycles per second.
+const WEAVE_RATE: f32 = 0.7;
+/// Lean applied at the steepest part of the weave, in radians.
+const WEAVE_TILT: f32 = 0.12;
+
+/// Whether the train is currently drifting between tracks, and for how
+/// long. Cleared on commit, restart, and each fresh dilemma.
+#[derive(Resource, Debug, Clone, Copy, Default)]
+pub struct DriftState {
+    pub active: bool,
+    pub elapsed_secs: f32,
+}
+
+impl DriftState {
+    fn begin(&mut self) {
+        self.active = true;
+        self.elapsed_secs = 0.0;
+    }
+
+    fn clear(&mut self) {
+        *self = Self::default();
+    }
+}
+
+/// How a drift ends when the decision window closes around it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DriftOutcome {
+    /// The train clips both tracks: a blended outcome.
+    Blended,
+    /// The weave went too long; the train leaves the rails entirely.
+    Derailed,
+}
+
+/// Fired when the decision window closes on an active drift. Listeners
+/// should prefer this over the timer's own [`DecisionTimerExpired`]
+/// outcome when both land in the same frame.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct DriftResolved {
+    pub outcome: DriftOutcome,
+}
+
+/// Lateral offset of a drifting train: a weave from the home track
+/// across to the other and back.
+fn drift_lateral_offset(elapsed_secs: f32) -> f32 {
+    TRACK_SPACING * 0.5 * (1.0 - (elapsed_secs * WEAVE_RATE * std::f32::consts::TAU).cos())
+}
+
+/// Chance the drift ends in a derail instead of a blended outcome,
+/// ramping with how long the train has been weaving.
+fn derail_probability(elapsed_secs: f32) -> f32 {
+    (elapsed_secs / DERAIL_RAMP_SECS).clamp(0.0, 1.0) * MAX_DERAIL_CHANCE
+}
+
+/// The home translation of a train captured when its drift starts, so
+/// the weave is applied absolutely rather than compounding.
+#[derive(Component, Debug, Clone, Copy)]
+struct DriftVisual {
+    home_y: f32,
+}
+
+/// Starts a drift on the drift input and ends it early if the player
+/// commits after all. The decision timer keeps running either way, so
+/// drifting never buys extra time.
+fn handle_drift_decisions(
+    mut commands: Commands,
+    mut events: EventReader<DecisionEvent>,
+    mut state: ResMut<DriftState>,
+    mut stats: ResMut<RunStats>,
+    trains: Query<(Entity, &Transform, Option<&DriftVisual>), With<Train>>,
+) {
+    for event in events.read() {
+        match event.kind {
+            DecisionKind::Drift if !state.active => {
+                state.begin();
+                stats.record_decision(DecisionKind::Drift);
+                for (train, transform, visual) in &trains {
+                    if visual.is_none() {
+                        commands.entity(train).insert(DriftVisual {
+                            home_y: transform.translation.y,
+                        });
+                    }
+                }
+            }
+            DecisionKind::Commit if state.active => {
+                state.clear();
+                for (train, _, visual) in &trains {
+                    if visual.is_some() {
+                        commands.entity(train).remove::<DriftVisual>();
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Weaves drifting trains between the tracks, leaning into the turns.
+fn animate_drifting_trains(
+    time: Res<Time>,
+    dilation: Res<Dilation>,
+    mut state: ResMut<DriftState>,
+    mut trains: Query<(&DriftVisual, &mut Transform), With<Train>>,
+) {
+    if !state.active {
+        return;
+    }
+    state.elapsed_secs += dilation.scale(time.delta_secs());
+    let offset = drift_lateral_offset(state.elapsed_secs);
+    // Lean with the direction of travel across the gap.
+    let phase = state.elapsed_secs * WEAVE_RATE * std::f32::consts::TAU;
+    let tilt = WEAVE_TILT * phase.sin();
+    for (visual, mut transform) in &mut trains {
+        transform.translation.y = visual.home_y + offset;
+        transform.rotation = Quat::from_rotation_z(tilt);
+    }
+}
+
+/// The drift's own resolution branch: when the decision window closes
+/// mid-drift, roll for a blended outcome or a derail and move on.
+fn resolve_drift_on_timeout(
+    mut expirations: EventReader<DecisionTimerExpired>,
+    mut state: ResMut<DriftState>,
+    mut rng: ResMut<GameRng>,
+    mut resolved: EventWriter<DriftResolved>,
+    mut next_phase: ResMut<NextState<DilemmaPhase>>,
+) {
+    if expirations.read().next().is_none() || !state.active {
+        return;
+    }
+    let outcome = if rng.next_f32() < derail_probability(state.elapsed_secs) {
+        DriftOutcome::Derailed
+    } else {
+        DriftOutcome::Blended
+    };
+    state.active = false;
+    resolved.write(DriftResolved { outcome });
+    next_phase.set(DilemmaPhase::Resolution);
+}
+
+fn clear_drift_state(
+    mut commands: Commands,
+    mut state: ResMut<DriftState>,
+    visuals: Query<Entity, With<DriftVisual>>,
+) {
+    state.clear();
+    for entity in &visuals {
+        commands.entity(entity).remove::<DriftVisual>();
+    }
+}
+
+pub struct DriftPlugin;
+
+impl Plugin for DriftPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DriftState>()
+            .add_event::<DriftResolved>()
+            .add_systems(
+                Update,
+                (
+                    handle_drift_decisions,
+                    animate_drifting_trains.run_if(in_state(PauseState::Running)),
+                    resolve_drift_on_timeout,
+                )
+                    .chain()
+                    .run_if(in_state(DilemmaPhase::Decision)),
+            )
+            .add_systems(OnEnter(DilemmaPhase::Intro), clear_drift_state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_weave_spans_exactly_the_track_gap() {
+        assert!(drift_lateral_offset(0.0).abs() < 1e-4);
+        // Half a weave cycle later the train sits on the far track.
+        let half_cycle = 0.5 / WEAVE_RATE;
+        assert!((drift_lateral_offset(half_cycle) - TRACK_SPACING).abs() < 1e-3);
+    }
+
+    #[test]
+    fn derail_chance_ramps_and_caps() {
+        assert_eq!(derail_probability(0.0), 0.0);
+        let early = derail_probability(DERAIL_RAMP_SECS * 0.5);
+        assert!(early > 0.0 && early < MAX_DERAIL_CHANCE);
+        assert_eq!(derail_probability(DERAIL_RAMP_SECS), MAX_DERAIL_CHANCE);
+        assert_eq!(derail_probability(DERAIL_RAMP_SECS * 3.0), MAX_DERAIL_CHANCE);
+    }
+}
@@ -0,0 +1,154 @@
+use bevy::prelude::*;
+
+use crate::{
+    data::rng::GameRng,
+    systems::{
+        colors::{HIGHLIGHT_COLOR, PRIMARY_COLOR, SYSTEM_MENU_COLOR, WARNING_COLOR},
+        time::Dilation,
+    },
+};
+
+const GRAVITY: f32 = -220.0;
+/// Particles render well below window chrome (windows sit at z >= 600)
+/// so celebrations never cover interactive UI.
+const PARTICLE_Z: f32 = 450.0;
+const PARTICLE_SIZE: f32 = 3.0;
+const PARTICLE_LIFETIME_SECS: f32 = 1.4;
+const BURST_SPEED: f32 = 160.0;
+
+/// A fireworks emitter: bursts of short-lived particles launched from
+/// random points inside `area`, centred on the emitter's transform.
+#[derive(Component, Debug, Clone)]
+pub struct Fireworks {
+    /// Particles per burst.
+    pub spawn_count: usize,
+    /// Seconds between bursts, in dilated time.
+    pub interval_secs: f32,
+    pub palette: Vec<Color>,
+    /// Region bursts originate from, around the emitter.
+    pub area: Vec2,
+    /// Bursts left before the emitter despawns itself; `None` keeps
+    /// firing forever. Lets one-shot explosions reuse the emitter.
+    pub remaining_bursts: Option<usize>,
+    since_last_burst: f32,
+}
+
+impl Default for Fireworks {
+    fn default() -> Self {
+        Self {
+            spawn_count: 24,
+            interval_secs: 0.8,
+            palette: vec![
+                PRIMARY_COLOR,
+                HIGHLIGHT_COLOR,
+                SYSTEM_MENU_COLOR,
+                WARNING_COLOR,
+            ],
+            area: Vec2::new(360.0, 120.0),
+            remaining_bursts: None,
+            since_last_burst: 0.0,
+        }
+    }
+}
+
+#[derive(Component, Debug, Clone, Copy)]
+struct FireworkParticle {
+    velocity: Vec2,
+    age_secs: f32,
+    lifetime_secs: f32,
+}
+
+/// Launches a burst whenever an emitter's interval elapses.
+fn emit_firework_bursts(
+    mut commands: Commands,
+    time: Res<Time>,
+    dilation: Res<Dilation>,
+    mut rng: ResMut<GameRng>,
+    mut emitters: Query<(Entity, &mut Fireworks, &GlobalTransform)>,
+) {
+    let delta = dilation.scale(time.delta_secs());
+    for (emitter, mut fireworks, transform) in &mut emitters {
+        if fireworks.remaining_bursts == Some(0) {
+            commands.entity(emitter).despawn();
+            continue;
+        }
+        fireworks.since_last_burst += delta;
+        if fireworks.since_last_burst < fireworks.interval_secs {
+            continue;
+        }
+        fireworks.since_last_burst = 0.0;
+        if let Some(remaining) = &mut fireworks.remaining_bursts {
+            *remaining -= 1;
+        }
+        let origin = transform.translation().truncate()
+            + Vec2::new(
+                (rng.next_f32() - 0.5) * fireworks.area.x,
+                (rng.next_f32() - 0.5) * fireworks.area.y,
+            );
+        for _ in 0..fireworks.spawn_count {
+            let angle = rng.next_f32() * std::f32::consts::TAU;
+            let speed = BURST_SPEED * (0.4 + 0.6 * rng.next_f32());
+            let color = fireworks.palette[rng.next_usize(fireworks.palette.len().max(1))];
+            commands.spawn((
+                FireworkParticle {
+                    velocity: Vec2::from_angle(angle) * speed,
+                    age_secs: 0.0,
+                    lifetime_secs: PARTICLE_LIFETIME_SECS * (0.7 + 0.3 * rng.next_f32()),
+                },
+                Sprite {
+                    color,
+                    custom_size: Some(Vec2::splat(PARTICLE_SIZE)),
+                    ..default()
+                },
+                Transform::from_translation(origin.extend(PARTICLE_Z)),
+            ));
+        }
+    }
+}
+
+/// Integrates gravity, fades particles out over their lifetime and
+/// despawns them when it ends.
+fn update_firework_particles(
+    mut commands: Commands,
+    time: Res<Time>,
+    dilation: Res<Dilation>,
+    mut particles: Query<(Entity, &mut FireworkParticle, &mut Transform, &mut Sprite)>,
+) {
+    let delta = dilation.scale(time.delta_secs());
+    for (entity, mut particle, mut transform, mut sprite) in &mut particles {
+        particle.age_secs += delta;
+        if particle.age_secs >= particle.lifetime_secs {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        particle.velocity.y += GRAVITY * delta;
+        transform.translation += (particle.velocity * delta).extend(0.0);
+        let remaining = 1.0 - particle.age_secs / particle.lifetime_secs;
+        sprite.color.set_alpha(remaining);
+    }
+}
+
+/// Leftover particles must not outlive their emitter's scene.
+fn despawn_firework_particles(
+    mut commands: Commands,
+    particles: Query<Entity, With<FireworkParticle>>,
+) {
+    for entity in &particles {
+        commands.entity(entity).despawn();
+    }
+}
+
+pub struct FireworksPlugin;
+
+impl Plugin for FireworksPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (emit_firework_bursts, update_firework_particles).chain(),
+        )
+        .add_systems(
+            OnExit(crate::data::states::DilemmaPhase::Results),
+            despawn_firework_particles,
+        );
+    }
+}
@@ -0,0 +1,88 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::data::save::{read_ron, write_ron};
+
+pub mod consequence;
+pub mod decision;
+pub mod drift;
+pub mod fireworks;
+pub mod music;
+pub mod pause;
+pub mod rampage;
+pub mod replay;
+pub mod restart;
+pub mod results;
+pub mod selection;
+pub mod timer;
+pub mod train;
+
+const COMPLETED_DILEMMAS_FILE: &str = "completed_dilemmas.ron";
+
+/// Stable identifier for an authored dilemma definition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DilemmaId(pub &'static str);
+
+/// Which dilemma is currently loaded, if any.
+#[derive(Resource, Debug, Default)]
+pub struct CurrentDilemma {
+    pub id: Option<DilemmaId>,
+}
+
+/// Which dilemmas the player has finished at least once, persisted so
+/// the level selector survives restarts.
+#[derive(Resource, Debug, Clone, Serialize, Deserialize)]
+pub struct CompletedDilemmas {
+    completed: Vec<String>,
+}
+
+impl Default for CompletedDilemmas {
+    fn default() -> Self {
+        read_ron(COMPLETED_DILEMMAS_FILE).unwrap_or(Self {
+            completed: Vec::new(),
+        })
+    }
+}
+
+impl CompletedDilemmas {
+    pub fn is_completed(&self, id: DilemmaId) -> bool {
+        self.completed.iter().any(|name| name == id.0)
+    }
+
+    /// Whether the level selector offers this dilemma. Debug builds
+    /// unlock everything for testing.
+    pub fn is_unlocked(&self, id: DilemmaId) -> bool {
+        cfg!(debug_assertions) || self.is_completed(id)
+    }
+
+    pub fn mark_completed(&mut self, id: DilemmaId) {
+        if !self.is_completed(id) {
+            self.completed.push(id.0.to_string());
+            write_ron(COMPLETED_DILEMMAS_FILE, self);
+        }
+    }
+}
+
+pub struct DilemmaPlugin;
+
+impl Plugin for DilemmaPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CurrentDilemma>()
+            .init_resource::<CompletedDilemmas>()
+            .add_plugins((
+                consequence::ConsequencePlugin,
+                decision::DecisionPlugin,
+                drift::DriftPlugin,
+                fireworks::FireworksPlugin,
+                music::DilemmaMusicPlugin,
+                pause::PausePlugin,
+                rampage::RampagePlugin,
+                replay::ReplayPlugin,
+                restart::RestartPlugin,
+                results::ResultsPlugin,
+                selection::SelectionPlugin,
+                timer::DecisionTimerPlugin,
+                train::TrainPlugin,
+            ));
+    }
+}
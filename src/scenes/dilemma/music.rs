@@ -0,0 +1,134 @@
+use bevy::{audio::Volume, prelude::*};
+
+use crate::{
+    scenes::dilemma::timer::DecisionTimer,
+    systems::audio::{continuous_audio, AudioCategory, BaseVolume},
+    ui::menu::audio::AudioSettingsState,
+};
+
+/// Layered music for one dilemma. The base stem always plays; higher
+/// stems fade in as `intensity` rises towards 1. All stems start
+/// together (muted) so they stay sample-aligned when they surface.
+#[derive(Component, Debug, Clone)]
+pub struct DilemmaMusic {
+    pub layers: Vec<Handle<AudioSource>>,
+    pub intensity: f32,
+}
+
+impl DilemmaMusic {
+    pub fn new(layers: Vec<Handle<AudioSource>>) -> Self {
+        Self {
+            layers,
+            intensity: 0.0,
+        }
+    }
+}
+
+/// One spawned stem, child of its [`DilemmaMusic`] entity.
+#[derive(Component, Debug, Clone, Copy)]
+struct MusicStem {
+    index: usize,
+}
+
+/// Level of stem `index` out of `stem_count` at `intensity`. The base
+/// stem is always full; each further stem occupies an equal slice of
+/// the intensity range and fades linearly across it.
+pub fn stem_level(index: usize, stem_count: usize, intensity: f32) -> f32 {
+    if index == 0 || stem_count <= 1 {
+        return 1.0;
+    }
+    let slices = (stem_count - 1) as f32;
+    (intensity.clamp(0.0, 1.0) * slices - (index - 1) as f32).clamp(0.0, 1.0)
+}
+
+/// Starts every stem of a fresh [`DilemmaMusic`] in the same frame,
+/// with only the base stem audible.
+fn spawn_music_stems(
+    mut commands: Commands,
+    mixer: Res<AudioSettingsState>,
+    music: Query<(Entity, &DilemmaMusic), Added<DilemmaMusic>>,
+) {
+    for (entity, music) in &music {
+        for (index, layer) in music.layers.iter().enumerate() {
+            let level = stem_level(index, music.layers.len(), music.intensity);
+            let (playback, _, category) = continuous_audio(AudioCategory::Music, &mixer.settings);
+            commands.spawn((
+                MusicStem { index },
+                AudioPlayer::new(layer.clone()),
+                playback.with_volume(Volume::Linear(
+                    level * category.scale(&mixer.settings),
+                )),
+                BaseVolume(level),
+                category,
+                ChildOf(entity),
+            ));
+        }
+    }
+}
+
+/// Follows `intensity` changes, rewriting each stem's pre-mix level so
+/// mixer changes and the crossfade compose.
+fn sync_stem_levels(
+    mixer: Res<AudioSettingsState>,
+    music: Query<(&DilemmaMusic, &Children), Changed<DilemmaMusic>>,
+    mut stems: Query<(&MusicStem, &mut BaseVolume, &AudioCategory, &mut AudioSink)>,
+) {
+    for (music, children) in &music {
+        for child in children.iter() {
+            let Ok((stem, mut base, category, mut sink)) = stems.get_mut(child) else {
+                continue;
+            };
+            let level = stem_level(stem.index, music.layers.len(), music.intensity);
+            base.0 = level;
+            sink.set_volume(Volume::Linear(level * category.scale(&mixer.settings)));
+        }
+    }
+}
+
+/// Convenience wiring: a dilemma carrying both a decision timer and
+/// layered music ramps intensity as the window runs out.
+fn drive_intensity_from_timer(
+    mut music: Query<(&DecisionTimer, &mut DilemmaMusic)>,
+) {
+    for (timer, mut music) in &mut music {
+        let intensity = 1.0 - timer.fraction();
+        if (music.intensity - intensity).abs() > f32::EPSILON {
+            music.intensity = intensity;
+        }
+    }
+}
+
+pub struct DilemmaMusicPlugin;
+
+impl Plugin for DilemmaMusicPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (spawn_music_stems, drive_intensity_from_timer, sync_stem_levels).chain(),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn base_stem_is_always_full() {
+        assert_eq!(stem_level(0, 3, 0.0), 1.0);
+        assert_eq!(stem_level(0, 3, 1.0), 1.0);
+    }
+
+    #[test]
+    fn higher_stems_fade_in_across_the_intensity_range() {
+        assert_eq!(stem_level(1, 3, 0.0), 0.0);
+        assert_eq!(stem_level(1, 3, 0.5), 1.0);
+        assert_eq!(stem_level(2, 3, 0.5), 0.0);
+        assert_eq!(stem_level(2, 3, 1.0), 1.0);
+    }
+
+    #[test]
+    fn partial_intensity_gives_partial_levels() {
+        assert!((stem_level(1, 3, 0.25) - 0.5).abs() < 1e-6);
+    }
+}
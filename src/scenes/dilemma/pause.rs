@@ -0,0 +1,120 @@
+use bevy::prelude::*;
+
+use crate::{
+    data::states::{MainState, PauseState},
+    systems::interaction::{ConsumedInputs, InteractionSystem},
+    ui::{
+        menu::pages::{spawn_menu_root, MenuHost, MenuPage, MenuStackMemory},
+        window::SCENE_SPRITE_Z_MAX,
+    },
+};
+
+/// How strongly the paused scene is dimmed behind the pause menu. The
+/// scene keeps rendering underneath — its systems are merely frozen — so
+/// video settings preview against the real frame; the dim just pushes it
+/// back visually.
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct PauseDimConfig {
+    /// 0 leaves the scene untouched, 1 blacks it out.
+    pub dim_alpha: f32,
+}
+
+impl Default for PauseDimConfig {
+    fn default() -> Self {
+        Self { dim_alpha: 0.55 }
+    }
+}
+
+/// The full-screen dim sprite: above every scene sprite, below every
+/// window, so the pause menu floats over a darkened but visible scene.
+#[derive(Component)]
+struct PauseDimOverlay;
+
+const DIM_OVERLAY_SIZE: Vec2 = Vec2::new(4000.0, 4000.0);
+
+/// Escape toggles the pause menu during a dilemma, unless something
+/// finer-grained (an open dropdown) already consumed the press.
+fn toggle_pause(
+    keys: Res<ButtonInput<KeyCode>>,
+    consumed: Res<ConsumedInputs>,
+    state: Res<State<PauseState>>,
+    mut next: ResMut<NextState<PauseState>>,
+) {
+    if !keys.just_pressed(KeyCode::Escape) || consumed.escape {
+        return;
+    }
+    next.set(match state.get() {
+        PauseState::Running => PauseState::Paused,
+        PauseState::Paused => PauseState::Running,
+    });
+}
+
+fn open_pause_menu(
+    mut commands: Commands,
+    config: Res<PauseDimConfig>,
+    memory: Res<MenuStackMemory>,
+) {
+    spawn_menu_root(&mut commands, &memory, MenuHost::Pause, MenuPage::PauseRoot);
+    commands.spawn((
+        PauseDimOverlay,
+        Sprite {
+            color: Color::srgba(0.0, 0.0, 0.0, config.dim_alpha.clamp(0.0, 1.0)),
+            custom_size: Some(DIM_OVERLAY_SIZE),
+            ..default()
+        },
+        Transform::from_xyz(0.0, 0.0, SCENE_SPRITE_Z_MAX),
+    ));
+}
+
+fn close_pause_menu(
+    mut commands: Commands,
+    menus: Query<(Entity, &MenuHost)>,
+    overlays: Query<Entity, With<PauseDimOverlay>>,
+) {
+    for (entity, host) in &menus {
+        if *host == MenuHost::Pause {
+            commands.entity(entity).despawn();
+        }
+    }
+    for overlay in &overlays {
+        commands.entity(overlay).despawn();
+    }
+}
+
+/// Follows live edits to the dim level mid-pause.
+fn sync_pause_dim(
+    config: Res<PauseDimConfig>,
+    mut overlays: Query<&mut Sprite, With<PauseDimOverlay>>,
+) {
+    if !config.is_changed() {
+        return;
+    }
+    for mut sprite in &mut overlays {
+        sprite.color.set_alpha(config.dim_alpha.clamp(0.0, 1.0));
+    }
+}
+
+/// Leaving the dilemma flow always unpauses, so a menu left open cannot
+/// freeze the next scene.
+fn force_unpause(mut next: ResMut<NextState<PauseState>>) {
+    next.set(PauseState::Running);
+}
+
+pub struct PausePlugin;
+
+impl Plugin for PausePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PauseDimConfig>()
+            .add_systems(
+                Update,
+                (
+                    toggle_pause.in_set(InteractionSystem::React),
+                    sync_pause_dim,
+                )
+                    .run_if(in_state(MainState::Dilemma)),
+            )
+            .add_systems(OnEnter(PauseState::Paused), open_pause_menu)
+            .add_systems(OnExit(PauseState::Paused), close_pause_menu)
+            .add_systems(OnExit(MainState::Dilemma), force_unpause);
+    }
+}
@@ -0,0 +1,227 @@
+use bevy::prelude::*;
+
+use crate::{
+    data::{
+        save::SaveState,
+        states::{DilemmaPhase, MainState},
+        stats::RunStats,
+    },
+    scenes::dilemma::{timer::DecisionTimer, train::TrainMotion},
+    systems::interaction::Disabled,
+    ui::menu::pages::{
+        page_definition, MenuCommand, MenuCommandEvent, MenuOptionRow, MenuPage,
+        MenuPageContent,
+    },
+};
+
+/// Casualties a run must already have racked up for its restart to count
+/// as the ultra-violent false start.
+pub const RAMPAGE_UNLOCK_CASUALTIES: u32 = 10;
+
+/// Whether the current dilemma run is a rampage run.
+#[derive(Resource, Debug, Clone, Copy, Default)]
+pub struct RampageState {
+    pub active: bool,
+}
+
+/// Parameter overrides a rampage layers onto the normal dilemma and
+/// train systems — the systems themselves are shared, only these
+/// factors change. Identity outside a rampage.
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct RampageModifiers {
+    /// Multiplier on a fresh train's velocity.
+    pub train_speed_factor: f32,
+    /// Multiplier on a fresh decision window's length.
+    pub decision_window_factor: f32,
+}
+
+impl Default for RampageModifiers {
+    fn default() -> Self {
+        Self {
+            train_speed_factor: 1.0,
+            decision_window_factor: 1.0,
+        }
+    }
+}
+
+impl RampageModifiers {
+    /// The rampage tuning: faster trains, no timer mercy.
+    pub fn rampage() -> Self {
+        Self {
+            train_speed_factor: 2.5,
+            decision_window_factor: 0.4,
+        }
+    }
+}
+
+/// A scaled decision window, floored so even a rampage leaves a beat to
+/// react in.
+pub fn rampage_window_secs(total_secs: f32, factor: f32) -> f32 {
+    (total_secs * factor).max(0.5)
+}
+
+/// A restart issued mid-carnage is the ultra-violent false start: it
+/// permanently unlocks rampage mode.
+fn unlock_rampage_on_violent_false_start(
+    mut events: EventReader<MenuCommandEvent>,
+    stats: Res<RunStats>,
+    mut save: ResMut<SaveState>,
+) {
+    let restarted = events
+        .read()
+        .any(|event| matches!(event.command, MenuCommand::RestartDilemma));
+    if restarted
+        && stats.killed >= RAMPAGE_UNLOCK_CASUALTIES
+        && !save.suppress_writes
+        && !save.rampage_unlocked
+    {
+        save.rampage_unlocked = true;
+    }
+}
+
+/// Starts a rampage run: swap the modifiers in and enter the dilemma
+/// flow as normal.
+fn handle_open_rampage_command(
+    mut events: EventReader<MenuCommandEvent>,
+    save: Res<SaveState>,
+    mut state: ResMut<RampageState>,
+    mut modifiers: ResMut<RampageModifiers>,
+    mut next_main: ResMut<NextState<MainState>>,
+) {
+    let requested = events
+        .read()
+        .any(|event| matches!(event.command, MenuCommand::OpenRampage));
+    if !requested || !save.rampage_unlocked {
+        return;
+    }
+    state.active = true;
+    *modifiers = RampageModifiers::rampage();
+    next_main.set(MainState::Dilemma);
+}
+
+/// Leaving the dilemma flow ends any rampage and restores the identity
+/// parameters, whatever path led out.
+fn end_rampage(mut state: ResMut<RampageState>, mut modifiers: ResMut<RampageModifiers>) {
+    if state.active {
+        state.active = false;
+    }
+    *modifiers = RampageModifiers::default();
+}
+
+/// Applies the speed factor to trains as they spawn; identity modifiers
+/// leave them untouched.
+fn apply_rampage_to_new_trains(
+    modifiers: Res<RampageModifiers>,
+    mut trains: Query<&mut TrainMotion, Added<TrainMotion>>,
+) {
+    if modifiers.train_speed_factor == 1.0 {
+        return;
+    }
+    for mut motion in &mut trains {
+        motion.velocity *= modifiers.train_speed_factor;
+    }
+}
+
+/// Shrinks fresh decision windows by the rampage factor.
+fn apply_rampage_to_new_timers(
+    modifiers: Res<RampageModifiers>,
+    mut timers: Query<&mut DecisionTimer, Added<DecisionTimer>>,
+) {
+    if modifiers.decision_window_factor == 1.0 {
+        return;
+    }
+    for mut timer in &mut timers {
+        timer.total = rampage_window_secs(timer.total, modifiers.decision_window_factor);
+        timer.remaining = timer.remaining.min(timer.total);
+    }
+}
+
+/// Continuous action: a rampage never lingers on the results screen, it
+/// rolls straight into the next intro.
+fn chain_rampage_dilemmas(
+    state: Res<RampageState>,
+    mut next_phase: ResMut<NextState<DilemmaPhase>>,
+) {
+    if state.active {
+        next_phase.set(DilemmaPhase::Intro);
+    }
+}
+
+/// Dims and deactivates the main menu's RAMPAGE row until the unlock,
+/// mirroring the sandbox lock pattern.
+fn sync_rampage_lock(
+    mut commands: Commands,
+    save: Res<SaveState>,
+    contents: Query<(Entity, &MenuPageContent), Added<MenuPageContent>>,
+    all_contents: Query<(Entity, &MenuPageContent)>,
+    rows: Query<(Entity, &MenuOptionRow)>,
+) {
+    let refresh_all = save.is_changed() && !save.is_added();
+    let targets: Vec<Entity> = if refresh_all {
+        all_contents
+            .iter()
+            .filter(|(_, content)| content.page == MenuPage::MainRoot)
+            .map(|(entity, _)| entity)
+            .collect()
+    } else {
+        contents
+            .iter()
+            .filter(|(_, content)| content.page == MenuPage::MainRoot)
+            .map(|(entity, _)| entity)
+            .collect()
+    };
+    if targets.is_empty() {
+        return;
+    }
+    let options = page_definition(MenuPage::MainRoot).options;
+    for (entity, row) in &rows {
+        if !targets.contains(&row.content) {
+            continue;
+        }
+        let rampage = options
+            .get(row.index)
+            .is_some_and(|option| matches!(option.command, MenuCommand::OpenRampage));
+        if !rampage {
+            continue;
+        }
+        if save.rampage_unlocked {
+            commands.entity(entity).remove::<Disabled>();
+        } else {
+            commands.entity(entity).insert(Disabled);
+        }
+    }
+}
+
+pub struct RampagePlugin;
+
+impl Plugin for RampagePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RampageState>()
+            .init_resource::<RampageModifiers>()
+            .add_systems(
+                Update,
+                (
+                    unlock_rampage_on_violent_false_start,
+                    handle_open_rampage_command,
+                    sync_rampage_lock,
+                    apply_rampage_to_new_trains,
+                    apply_rampage_to_new_timers,
+                ),
+            )
+            .add_systems(OnEnter(DilemmaPhase::Results), chain_rampage_dilemmas)
+            .add_systems(OnExit(MainState::Dilemma), end_rampage);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_rampage_window_shrinks_but_keeps_a_floor() {
+        assert_eq!(rampage_window_secs(10.0, 0.4), 4.0);
+        assert_eq!(rampage_window_secs(1.0, 0.1), 0.5);
+        // Identity modifiers change nothing.
+        assert_eq!(rampage_window_secs(10.0, 1.0), 10.0);
+    }
+}
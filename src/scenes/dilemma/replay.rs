@@ -0,0 +1,172 @@
+use bevy::prelude::*;
+
+use crate::{
+    data::{
+        rng::GameRng,
+        save::SaveState,
+        stats::{DecisionLog, RunStats},
+    },
+    scenes::dilemma::{decision::DecisionEvent, CurrentDilemma},
+};
+
+/// Drives a recorded [`DecisionLog`] back through the live decision
+/// systems, reproducing a run for debugging or attract mode. Replays
+/// bypass live input and never write to [`SaveState`].
+#[derive(Resource, Debug, Default)]
+pub struct ReplayState {
+    pub log: DecisionLog,
+    pub playback_speed: f32,
+    pub active: bool,
+    cursor: usize,
+    elapsed_secs: f32,
+}
+
+impl ReplayState {
+    pub fn start(log: DecisionLog) -> Self {
+        Self {
+            log,
+            playback_speed: 1.0,
+            active: true,
+            cursor: 0,
+            elapsed_secs: 0.0,
+        }
+    }
+
+    pub fn finished(&self) -> bool {
+        self.cursor >= self.log.records.len()
+    }
+
+    /// Advances playback time and returns the indices of records now due.
+    fn advance(&mut self, delta_secs: f32) -> std::ops::Range<usize> {
+        self.elapsed_secs += delta_secs * self.playback_speed;
+        let start = self.cursor;
+        while self.cursor < self.log.records.len()
+            && self.log.records[self.cursor].at_secs <= self.elapsed_secs
+        {
+            self.cursor += 1;
+        }
+        start..self.cursor
+    }
+}
+
+fn begin_replay(
+    mut replay: ResMut<ReplayState>,
+    mut rng: ResMut<GameRng>,
+    mut stats: ResMut<RunStats>,
+    mut save: ResMut<SaveState>,
+) {
+    if !replay.active {
+        return;
+    }
+    rng.seed = replay.log.seed;
+    rng.reseed();
+    *stats = RunStats::default();
+    save.suppress_writes = true;
+    replay.cursor = 0;
+    replay.elapsed_secs = 0.0;
+}
+
+/// Feeds due recorded decisions into the decision event stream. Aborts
+/// gracefully if the run has diverged (the live dilemma no longer
+/// matches the recorded one), e.g. after a dilemma definition changed.
+fn drive_replay(
+    time: Res<Time>,
+    current: Res<CurrentDilemma>,
+    mut replay: ResMut<ReplayState>,
+    mut save: ResMut<SaveState>,
+    mut events: EventWriter<DecisionEvent>,
+) {
+    if !replay.active {
+        return;
+    }
+    if replay.finished() {
+        replay.active = false;
+        save.suppress_writes = false;
+        return;
+    }
+    let due = replay.advance(time.delta_secs());
+    for index in due {
+        let record = replay.log.records[index].clone();
+        if current.id != Some(record.dilemma) {
+            warn!(
+                "replay diverged at record {index}: expected dilemma {:?}, found {:?}; aborting",
+                record.dilemma, current.id
+            );
+            replay.active = false;
+            save.suppress_writes = false;
+            return;
+        }
+        events.write(DecisionEvent { kind: record.kind });
+    }
+}
+
+pub struct ReplayPlugin;
+
+impl Plugin for ReplayPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ReplayState>()
+            .add_systems(Update, drive_replay)
+            .add_systems(
+                OnEnter(crate::data::states::MainState::Dilemma),
+                begin_replay,
+            );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        data::stats::DecisionRecord,
+        scenes::dilemma::{decision::DecisionKind, DilemmaId},
+    };
+
+    fn short_log() -> DecisionLog {
+        DecisionLog {
+            seed: 99,
+            records: vec![
+                DecisionRecord {
+                    dilemma: DilemmaId("lab_rat"),
+                    kind: DecisionKind::Commit,
+                    at_secs: 0.5,
+                },
+                DecisionRecord {
+                    dilemma: DilemmaId("lab_rat"),
+                    kind: DecisionKind::Drift,
+                    at_secs: 1.5,
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn replay_reproduces_the_original_run_stats() {
+        let log = short_log();
+        // The original run's tallies.
+        let mut original = RunStats::default();
+        for record in &log.records {
+            original.record_decision(record.kind);
+        }
+
+        // Replay the log through the driver's scheduling.
+        let mut replay = ReplayState::start(log);
+        let mut replayed = RunStats::default();
+        for _ in 0..40 {
+            let due = replay.advance(0.05);
+            for index in due {
+                replayed.record_decision(replay.log.records[index].kind);
+            }
+        }
+        assert!(replay.finished());
+        assert_eq!(original, replayed);
+    }
+
+    #[test]
+    fn playback_speed_scales_delivery_time() {
+        let mut replay = ReplayState::start(short_log());
+        replay.playback_speed = 2.0;
+        // One second of wall time covers two seconds of recording.
+        let due = replay.advance(1.0);
+        assert_eq!(due.len(), 2);
+    }
+}
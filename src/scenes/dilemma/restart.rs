@@ -0,0 +1,112 @@
+use bevy::prelude::*;
+
+use crate::{
+    data::states::DilemmaPhase,
+    scenes::dilemma::{decision::BufferedDecisionInput, CurrentDilemma},
+    systems::{
+        achievements::{AchievementId, UnlockAchievement},
+        interaction::Disabled,
+    },
+    ui::menu::pages::{
+        page_definition, MenuCommand, MenuCommandEvent, MenuHost, MenuOptionRow, MenuPage,
+        MenuPageContent,
+    },
+};
+
+/// Attached to every top-level entity spawned as part of the live
+/// dilemma scene: track, lever, trains, HUD `Window` roots, scroll roots
+/// and looping audio emitters. Restart and teardown despawn by this
+/// marker, so the recursive despawn takes children (window content,
+/// scroll runtimes, audio sinks) with it.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct DilemmaScene;
+
+/// Tears the live dilemma scene down and re-enters the intro phase so
+/// it respawns fresh from its definition. Per-run state (decision
+/// timer, track and lever positions) lives on the scene entities, so
+/// the despawn is the reset; the buffered decision input is the one
+/// resource cleared explicitly.
+fn handle_restart_command(
+    mut commands: Commands,
+    mut events: EventReader<MenuCommandEvent>,
+    current: Res<CurrentDilemma>,
+    mut buffered: ResMut<BufferedDecisionInput>,
+    mut next_phase: ResMut<NextState<DilemmaPhase>>,
+    mut unlocks: EventWriter<UnlockAchievement>,
+    scene: Query<Entity, With<DilemmaScene>>,
+    menus: Query<(Entity, &MenuHost)>,
+) {
+    let requested = events
+        .read()
+        .any(|event| matches!(event.command, MenuCommand::RestartDilemma));
+    // Disabled rows never activate, but the keybind path still lands
+    // here, so the no-dilemma guard is repeated.
+    if !requested || current.id.is_none() {
+        return;
+    }
+    for entity in &scene {
+        commands.entity(entity).despawn();
+    }
+    // The pause menu that issued the restart closes with the scene.
+    for (entity, host) in &menus {
+        if *host == MenuHost::Pause {
+            commands.entity(entity).despawn();
+        }
+    }
+    buffered.clear();
+    next_phase.set(DilemmaPhase::Intro);
+    unlocks.write(UnlockAchievement(AchievementId::FirstRestart));
+}
+
+/// Dims and deactivates the pause menu's restart row while no dilemma
+/// is loaded, mirroring the level-selector lock pattern.
+fn sync_restart_lock(
+    mut commands: Commands,
+    current: Res<CurrentDilemma>,
+    contents: Query<(Entity, &MenuPageContent), Added<MenuPageContent>>,
+    all_contents: Query<(Entity, &MenuPageContent)>,
+    rows: Query<(Entity, &MenuOptionRow)>,
+) {
+    let refresh_all = current.is_changed() && !current.is_added();
+    let targets: Vec<Entity> = if refresh_all {
+        all_contents
+            .iter()
+            .filter(|(_, content)| content.page == MenuPage::PauseRoot)
+            .map(|(entity, _)| entity)
+            .collect()
+    } else {
+        contents
+            .iter()
+            .filter(|(_, content)| content.page == MenuPage::PauseRoot)
+            .map(|(entity, _)| entity)
+            .collect()
+    };
+    if targets.is_empty() {
+        return;
+    }
+    let options = page_definition(MenuPage::PauseRoot).options;
+    for (entity, row) in &rows {
+        if !targets.contains(&row.content) {
+            continue;
+        }
+        let restart = options
+            .get(row.index)
+            .is_some_and(|option| matches!(option.command, MenuCommand::RestartDilemma));
+        if !restart {
+            continue;
+        }
+        if current.id.is_some() {
+            commands.entity(entity).remove::<Disabled>();
+        } else {
+            commands.entity(entity).insert(Disabled);
+        }
+    }
+}
+
+pub struct RestartPlugin;
+
+impl Plugin for RestartPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, (sync_restart_lock, handle_restart_command));
+    }
+}
@@ -0,0 +1,187 @@
+use bevy::prelude::*;
+
+use crate::{
+    data::{states::DilemmaPhase, stats::RunStats},
+    scenes::dilemma::{fireworks::Fireworks, restart::DilemmaScene},
+    systems::colors::ColorScheme,
+    ui::table::{Cell, Column, Row, Table},
+};
+
+const RESULTS_TEXT_SIZE: f32 = 14.0;
+/// Kill counts at or above this render at full danger red; below it the
+/// colour ramps up from amber.
+const SEVERITY_CAP: u32 = 10;
+
+/// Colour for a casualty count: the scheme's good colour when nobody
+/// died, then its warning colour blending towards danger as the count
+/// grows. Reads the scheme rather than the palette constants so
+/// colorblind modes keep the outcomes tellable apart.
+pub fn casualty_color(scheme: &ColorScheme, killed: u32) -> Color {
+    if killed == 0 {
+        return scheme.primary();
+    }
+    let severity = (killed as f32 / SEVERITY_CAP as f32).min(1.0);
+    scheme.warning().mix(&scheme.danger(), severity)
+}
+
+/// A count cell with the severity ramp applied. Saved counts stay
+/// green regardless of magnitude; only deaths escalate.
+pub fn outcome_cell(scheme: &ColorScheme, count: u32, is_casualty: bool) -> Cell {
+    let mut cell = Cell::new(count.to_string());
+    cell.text_color = if is_casualty {
+        casualty_color(scheme, count)
+    } else {
+        scheme.primary()
+    };
+    cell
+}
+
+fn results_table_rows(scheme: &ColorScheme, stats: &RunStats) -> Vec<Row> {
+    vec![
+        Row::new(vec![
+            Cell::new("KILLED"),
+            outcome_cell(scheme, stats.killed, true),
+        ]),
+        Row::new(vec![
+            Cell::new("SAVED"),
+            outcome_cell(scheme, stats.saved, false),
+        ]),
+        Row::new(vec![
+            Cell::new("DECISIONS"),
+            outcome_cell(scheme, stats.decisions, false),
+        ]),
+        Row::new(vec![
+            Cell::new("DRIFTS"),
+            outcome_cell(scheme, stats.drifts, false),
+        ]),
+        Row::new(vec![
+            Cell::new("DIVERTED"),
+            outcome_cell(scheme, diverted_count(stats), false),
+        ]),
+        Row::new(vec![
+            Cell::new("SLOWED"),
+            outcome_cell(scheme, stats.slowed, false),
+        ]),
+    ]
+}
+
+/// Commits that actually diverted the train: everything that wasn't a
+/// drift or a slow-death stop.
+fn diverted_count(stats: &RunStats) -> u32 {
+    stats
+        .decisions
+        .saturating_sub(stats.drifts)
+        .saturating_sub(stats.slowed)
+}
+
+/// The results screen's outcome table.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct ResultsTable;
+
+fn spawn_results_screen(
+    mut commands: Commands,
+    stats: Res<RunStats>,
+    scheme: Res<ColorScheme>,
+) {
+    let mut table = Table::new(
+        vec![Column::new("OUTCOME", 140.0), Column::new("COUNT", 80.0)],
+        RESULTS_TEXT_SIZE,
+    );
+    table.rows = results_table_rows(&scheme, &stats);
+    commands.spawn((
+        DilemmaScene,
+        ResultsTable,
+        table,
+        Transform::from_xyz(0.0, 0.0, 500.0),
+    ));
+    // Celebration bursts above the panel; the scene marker ties their
+    // emitter to the usual teardown.
+    commands.spawn((
+        DilemmaScene,
+        ResultsTable,
+        Fireworks::default(),
+        Transform::from_xyz(0.0, 160.0, 0.0),
+    ));
+}
+
+/// Late tallies (death animations still resolving) update the open
+/// table in place.
+fn refresh_results_table(
+    stats: Res<RunStats>,
+    scheme: Res<ColorScheme>,
+    mut tables: Query<&mut Table, With<ResultsTable>>,
+) {
+    if !stats.is_changed() && !scheme.is_changed() {
+        return;
+    }
+    for mut table in &mut tables {
+        table.rows = results_table_rows(&scheme, &stats);
+    }
+}
+
+fn despawn_results_screen(mut commands: Commands, tables: Query<Entity, With<ResultsTable>>) {
+    for entity in &tables {
+        commands.entity(entity).despawn();
+    }
+}
+
+pub struct ResultsPlugin;
+
+impl Plugin for ResultsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(DilemmaPhase::Results), spawn_results_screen)
+            .add_systems(
+                Update,
+                refresh_results_table.run_if(in_state(DilemmaPhase::Results)),
+            )
+            .add_systems(OnExit(DilemmaPhase::Results), despawn_results_screen);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::settings::ColorblindMode;
+
+    fn base_scheme() -> ColorScheme {
+        ColorScheme {
+            mode: ColorblindMode::None,
+        }
+    }
+
+    #[test]
+    fn zero_casualties_stay_green() {
+        let scheme = base_scheme();
+        assert_eq!(casualty_color(&scheme, 0), scheme.primary());
+    }
+
+    #[test]
+    fn ramp_saturates_at_the_severity_cap() {
+        let scheme = base_scheme();
+        assert_eq!(
+            casualty_color(&scheme, SEVERITY_CAP),
+            casualty_color(&scheme, SEVERITY_CAP * 10)
+        );
+    }
+
+    #[test]
+    fn saved_counts_ignore_the_ramp() {
+        let scheme = base_scheme();
+        assert_eq!(
+            outcome_cell(&scheme, 50, false).text_color,
+            scheme.primary()
+        );
+    }
+
+    #[test]
+    fn remapped_outcomes_never_collapse() {
+        for mode in ColorblindMode::ALL {
+            let scheme = ColorScheme { mode };
+            assert_ne!(
+                casualty_color(&scheme, 0),
+                casualty_color(&scheme, SEVERITY_CAP),
+                "{mode:?}"
+            );
+        }
+    }
+}
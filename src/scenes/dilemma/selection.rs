@@ -0,0 +1,189 @@
+use bevy::prelude::*;
+
+use crate::{
+    data::states::{DilemmaPhase, PauseState},
+    scenes::dilemma::drift::TRACK_SPACING,
+    systems::{colors::HIGHLIGHT_COLOR, time::Dilation},
+    ui::shapes::HollowRectangle,
+};
+
+/// Fraction of the remaining distance the selector covers per second
+/// while snapping to a new track.
+const SELECTOR_SNAP_RATE: f32 = 14.0;
+const SELECTOR_SIZE: Vec2 = Vec2::new(90.0, 34.0);
+const SELECTOR_Z: f32 = 0.25;
+
+/// Which of the dilemma's tracks the player currently has selected.
+/// Two-track dilemmas are the common case, but the index wraps over
+/// whatever `count` the loaded dilemma declares.
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct TrackSelection {
+    pub index: usize,
+    pub count: usize,
+}
+
+impl Default for TrackSelection {
+    fn default() -> Self {
+        Self { index: 0, count: 2 }
+    }
+}
+
+impl TrackSelection {
+    /// Steps the selection, wrapping across the track count.
+    pub fn step(&mut self, delta: i32) {
+        let count = self.count.max(1) as i32;
+        self.index = (self.index as i32 + delta).rem_euclid(count) as usize;
+    }
+}
+
+/// Vertical centre of track `index`, counting up from track zero.
+pub fn track_offset_y(index: usize) -> f32 {
+    index as f32 * TRACK_SPACING
+}
+
+/// The flashy pulse on the active selector: a scale oscillation whose
+/// clock runs through [`Dilation`], so slow-motion slows the flash with
+/// the rest of the sim.
+#[derive(Component, Debug, Clone)]
+pub struct SelectorAnimation {
+    /// Pulse cycles per second.
+    pub pulse_speed: f32,
+    /// Peak scale gain at the top of a pulse.
+    pub amplitude: f32,
+    elapsed_secs: f32,
+}
+
+impl Default for SelectorAnimation {
+    fn default() -> Self {
+        Self {
+            pulse_speed: 2.0,
+            amplitude: 0.12,
+            elapsed_secs: 0.0,
+        }
+    }
+}
+
+/// Scale multiplier `elapsed_secs` into a pulse: rests at 1, peaks at
+/// `1 + amplitude`.
+pub fn pulse_scale(animation: &SelectorAnimation, elapsed_secs: f32) -> f32 {
+    let phase = elapsed_secs * animation.pulse_speed * std::f32::consts::TAU;
+    1.0 + animation.amplitude * 0.5 * (1.0 - phase.cos())
+}
+
+/// Exponential approach of the selector towards its target track.
+pub fn eased_selector_y(current: f32, target: f32, delta_secs: f32) -> f32 {
+    current + (target - current) * (1.0 - (-SELECTOR_SNAP_RATE * delta_secs).exp())
+}
+
+/// The outline marking the selected track.
+#[derive(Component)]
+struct TrackSelector;
+
+fn spawn_track_selector(mut commands: Commands, selection: Res<TrackSelection>) {
+    commands.spawn((
+        TrackSelector,
+        SelectorAnimation::default(),
+        HollowRectangle {
+            dimensions: SELECTOR_SIZE,
+            thickness: 2.0,
+            color: HIGHLIGHT_COLOR,
+        },
+        Transform::from_xyz(0.0, track_offset_y(selection.index), SELECTOR_Z),
+    ));
+}
+
+/// Steps the selection with the arrow keys; W/S stay free for menus.
+fn handle_track_selection_input(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut selection: ResMut<TrackSelection>,
+) {
+    if keys.just_pressed(KeyCode::ArrowUp) {
+        selection.step(1);
+    }
+    if keys.just_pressed(KeyCode::ArrowDown) {
+        selection.step(-1);
+    }
+}
+
+/// Eases the selector onto the selected track and pulses it while it
+/// rests there.
+fn animate_track_selector(
+    time: Res<Time>,
+    dilation: Res<Dilation>,
+    selection: Res<TrackSelection>,
+    mut selectors: Query<(&mut SelectorAnimation, &mut Transform), With<TrackSelector>>,
+) {
+    let delta = dilation.scale(time.delta_secs());
+    let target = track_offset_y(selection.index.min(selection.count.saturating_sub(1)));
+    for (mut animation, mut transform) in &mut selectors {
+        animation.elapsed_secs += delta;
+        transform.translation.y = eased_selector_y(transform.translation.y, target, delta);
+        let scale = pulse_scale(&animation, animation.elapsed_secs);
+        transform.scale = Vec3::new(scale, scale, 1.0);
+    }
+}
+
+fn despawn_track_selector(
+    mut commands: Commands,
+    mut selection: ResMut<TrackSelection>,
+    selectors: Query<Entity, With<TrackSelector>>,
+) {
+    *selection = TrackSelection::default();
+    for selector in &selectors {
+        commands.entity(selector).despawn();
+    }
+}
+
+pub struct SelectionPlugin;
+
+impl Plugin for SelectionPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TrackSelection>()
+            .add_systems(OnEnter(DilemmaPhase::Decision), spawn_track_selector)
+            .add_systems(
+                Update,
+                (handle_track_selection_input, animate_track_selector)
+                    .chain()
+                    .run_if(in_state(DilemmaPhase::Decision))
+                    .run_if(in_state(PauseState::Running)),
+            )
+            .add_systems(OnExit(DilemmaPhase::Decision), despawn_track_selector);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn selection_wraps_over_any_track_count() {
+        let mut selection = TrackSelection { index: 0, count: 3 };
+        selection.step(-1);
+        assert_eq!(selection.index, 2);
+        selection.step(1);
+        assert_eq!(selection.index, 0);
+        selection.step(4);
+        assert_eq!(selection.index, 1);
+    }
+
+    #[test]
+    fn the_pulse_rests_at_one_and_peaks_at_the_amplitude() {
+        let animation = SelectorAnimation {
+            pulse_speed: 1.0,
+            amplitude: 0.2,
+            elapsed_secs: 0.0,
+        };
+        assert!((pulse_scale(&animation, 0.0) - 1.0).abs() < 1e-5);
+        assert!((pulse_scale(&animation, 0.5) - 1.2).abs() < 1e-5);
+    }
+
+    #[test]
+    fn the_selector_eases_towards_but_never_overshoots_the_target() {
+        let mut y = 0.0;
+        for _ in 0..60 {
+            y = eased_selector_y(y, TRACK_SPACING, 1.0 / 60.0);
+            assert!(y <= TRACK_SPACING);
+        }
+        assert!((y - TRACK_SPACING).abs() < 1.0);
+    }
+}
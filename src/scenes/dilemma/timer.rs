@@ -0,0 +1,200 @@
+use bevy::prelude::*;
+
+use crate::{
+    data::states::{DilemmaPhase, PauseState},
+    scenes::dilemma::decision::{DecisionEvent, DecisionKind},
+    systems::{
+        colors::{DANGER_COLOR, PRIMARY_COLOR, WARNING_COLOR},
+        time::Dilation,
+    },
+    ui::shapes::{BorderedRectangle, HollowRectangle},
+};
+
+const BAR_WIDTH: f32 = 220.0;
+const BAR_HEIGHT: f32 = 12.0;
+const BAR_BORDER: f32 = 1.0;
+/// Below this fraction the bar turns amber, below half of it red.
+const WARNING_FRACTION: f32 = 0.5;
+
+/// What happens when the decision clock runs out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeoutOutcome {
+    /// The decision resolves as if the player had chosen this.
+    Resolve(DecisionKind),
+    /// No selection, no mercy: the bomb ending.
+    Detonate,
+}
+
+/// Counts down the decision window on a dilemma scene entity. Lives on
+/// the scene, so `RestartDilemma`'s teardown resets it along with
+/// everything else; pausing freezes it because the tick system only
+/// runs while gameplay does.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct DecisionTimer {
+    pub remaining: f32,
+    pub total: f32,
+    pub on_expiry: TimeoutOutcome,
+    expired: bool,
+}
+
+impl DecisionTimer {
+    pub fn new(total_secs: f32, on_expiry: TimeoutOutcome) -> Self {
+        Self {
+            remaining: total_secs,
+            total: total_secs,
+            on_expiry,
+            expired: false,
+        }
+    }
+
+    /// Fraction of the window still left, for the bar.
+    pub fn fraction(&self) -> f32 {
+        (self.remaining / self.total).clamp(0.0, 1.0)
+    }
+
+    /// Advances the clock; returns true exactly once, on expiry.
+    pub fn tick(&mut self, delta_secs: f32) -> bool {
+        if self.expired {
+            return false;
+        }
+        self.remaining -= delta_secs;
+        if self.remaining <= 0.0 {
+            self.remaining = 0.0;
+            self.expired = true;
+            return true;
+        }
+        false
+    }
+}
+
+/// Fired when a decision window closes with no input. Resolving
+/// outcomes also feed the normal [`DecisionEvent`] stream; detonations
+/// are left to the ending flow listening for this event.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct DecisionTimerExpired {
+    pub outcome: TimeoutOutcome,
+}
+
+#[derive(Component)]
+struct TimerBarFill;
+
+/// Spawns the shrinking-bar display under a fresh timer: a hollow frame
+/// with a filled rectangle that the sync system trims from the right.
+fn spawn_decision_timer_bars(
+    mut commands: Commands,
+    timers: Query<Entity, Added<DecisionTimer>>,
+) {
+    for entity in &timers {
+        commands.entity(entity).with_children(|parent| {
+            parent.spawn((HollowRectangle {
+                dimensions: Vec2::new(BAR_WIDTH, BAR_HEIGHT),
+                thickness: BAR_BORDER,
+                color: PRIMARY_COLOR,
+            },));
+            parent.spawn((
+                TimerBarFill,
+                BorderedRectangle {
+                    dimensions: Vec2::new(BAR_WIDTH - BAR_BORDER * 4.0, BAR_HEIGHT - BAR_BORDER * 4.0),
+                    border_thickness: 0.0,
+                    border_color: PRIMARY_COLOR,
+                    fill_color: PRIMARY_COLOR,
+                },
+                Transform::from_xyz(0.0, 0.0, 0.1),
+            ));
+        });
+    }
+}
+
+/// Ticks decision timers with dilated time and fires the configured
+/// outcome once per window. Gated on the decision phase and on gameplay
+/// actually running, so pause menus freeze the clock.
+fn tick_decision_timers(
+    time: Res<Time>,
+    dilation: Res<Dilation>,
+    mut timers: Query<&mut DecisionTimer>,
+    mut expirations: EventWriter<DecisionTimerExpired>,
+    mut decisions: EventWriter<DecisionEvent>,
+) {
+    let delta = dilation.scale(time.delta_secs());
+    for mut timer in &mut timers {
+        if !timer.tick(delta) {
+            continue;
+        }
+        expirations.write(DecisionTimerExpired {
+            outcome: timer.on_expiry,
+        });
+        if let TimeoutOutcome::Resolve(kind) = timer.on_expiry {
+            decisions.write(DecisionEvent { kind });
+        }
+    }
+}
+
+/// Shrinks each bar's fill from the right and shifts its colour as the
+/// window runs out.
+fn sync_decision_timer_bars(
+    timers: Query<(&DecisionTimer, &Children), Changed<DecisionTimer>>,
+    mut fills: Query<(&mut BorderedRectangle, &mut Transform), With<TimerBarFill>>,
+) {
+    for (timer, children) in &timers {
+        let fraction = timer.fraction();
+        let full_width = BAR_WIDTH - BAR_BORDER * 4.0;
+        for child in children.iter() {
+            let Ok((mut fill, mut transform)) = fills.get_mut(child) else {
+                continue;
+            };
+            let width = full_width * fraction;
+            fill.dimensions.x = width;
+            fill.fill_color = if fraction < WARNING_FRACTION * 0.5 {
+                DANGER_COLOR
+            } else if fraction < WARNING_FRACTION {
+                WARNING_COLOR
+            } else {
+                PRIMARY_COLOR
+            };
+            // Anchor the fill to the left edge of the frame.
+            transform.translation.x = -(full_width - width) * 0.5;
+        }
+    }
+}
+
+pub struct DecisionTimerPlugin;
+
+impl Plugin for DecisionTimerPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<DecisionTimerExpired>().add_systems(
+            Update,
+            (
+                spawn_decision_timer_bars,
+                tick_decision_timers
+                    .run_if(in_state(DilemmaPhase::Decision))
+                    .run_if(in_state(PauseState::Running)),
+                sync_decision_timer_bars,
+            )
+                .chain(),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn timer_expires_exactly_once() {
+        let mut timer = DecisionTimer::new(1.0, TimeoutOutcome::Detonate);
+        assert!(!timer.tick(0.6));
+        assert!(timer.tick(0.6));
+        assert!(!timer.tick(0.6));
+        assert_eq!(timer.remaining, 0.0);
+    }
+
+    #[test]
+    fn fraction_tracks_remaining_time() {
+        let mut timer = DecisionTimer::new(4.0, TimeoutOutcome::Resolve(DecisionKind::Drift));
+        assert_eq!(timer.fraction(), 1.0);
+        timer.tick(1.0);
+        assert_eq!(timer.fraction(), 0.75);
+        timer.tick(10.0);
+        assert_eq!(timer.fraction(), 0.0);
+    }
+}
@@ -0,0 +1,373 @@
+use bevy::prelude::*;
+
+use crate::{
+    data::{
+        rng::GameRng,
+        states::{DilemmaPhase, PauseState},
+        stats::RunStats,
+    },
+    scenes::dilemma::decision::{DecisionEvent, DecisionKind},
+    systems::{
+        colors::{DANGER_COLOR, HIGHLIGHT_COLOR, WARNING_COLOR},
+        interaction::{Clickable, Hoverable},
+        time::Dilation,
+    },
+    ui::{shapes::HollowRectangle, text::FadeOut},
+};
+
+/// Splatters render just above the train body sprite.
+const SPLATTER_Z: f32 = 0.2;
+const SPLATTER_SIZE: f32 = 5.0;
+/// Scale range a fresh splatter is drawn from.
+const SPLATTER_SCALE_MIN: f32 = 0.6;
+const SPLATTER_SCALE_MAX: f32 = 1.8;
+
+/// Marks a train root in the live dilemma scene. Trains are spawned as
+/// `DilemmaScene` entities, so restart's recursive despawn is what ends
+/// a train's life.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct Train;
+
+/// Forward motion of a train along the track, in world units per
+/// second. All train movement integrates through this one component.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct TrainMotion {
+    pub velocity: f32,
+}
+
+/// Steady braking applied on top of [`TrainMotion`]: the slow-death
+/// lever doesn't divert the train, it bleeds its speed away.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct TrainDeceleration {
+    /// Speed lost per second, in world units per second squared.
+    pub rate: f32,
+}
+
+/// Fired once when a decelerating train's velocity reaches zero.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct TrainStopped {
+    pub train: Entity,
+}
+
+/// Whether the loaded dilemma is the slow-death variant, where the
+/// lever brakes the train instead of diverting it.
+#[derive(Resource, Debug, Clone, Copy, Default)]
+pub struct SlowDeathMode {
+    pub enabled: bool,
+}
+
+/// Braking applied by the slow-death lever.
+const SLOW_DEATH_BRAKE_RATE: f32 = 60.0;
+
+/// Hover and click hit region around a train root.
+const TRAIN_HIT_REGION: Vec2 = Vec2::new(64.0, 28.0);
+/// What the train has to say about your expectations.
+pub const TRAIN_WARNING_TEXT: &str = "THIS TRAIN WILL NOT STOP.";
+const TRAIN_WARNING_FADE_SECS: f32 = 1.4;
+const TRAIN_WARNING_OFFSET_Y: f32 = 28.0;
+
+/// The hover outline child of an interactive train.
+#[derive(Component)]
+struct TrainHoverOutline;
+
+/// The warning blurb floating over a clicked train. One per train:
+/// rapid clicks restart its fade rather than stacking copies.
+#[derive(Component)]
+struct TrainWarningMessage {
+    train: Entity,
+}
+
+/// Makes every fresh train hoverable and clickable, with a hidden
+/// outline child ready to light up under the cursor.
+fn make_trains_interactive(mut commands: Commands, trains: Query<Entity, Added<Train>>) {
+    for train in &trains {
+        commands
+            .entity(train)
+            .insert((Hoverable::new(TRAIN_HIT_REGION), Clickable::new(TRAIN_HIT_REGION)));
+        commands.spawn((
+            TrainHoverOutline,
+            HollowRectangle {
+                dimensions: TRAIN_HIT_REGION,
+                thickness: 1.0,
+                color: HIGHLIGHT_COLOR,
+            },
+            Transform::from_xyz(0.0, 0.0, 0.3),
+            Visibility::Hidden,
+            ChildOf(train),
+        ));
+    }
+}
+
+/// Shows each train's outline only while the cursor rests on it.
+fn sync_train_hover_outlines(
+    trains: Query<(&Hoverable, &Children), With<Train>>,
+    mut outlines: Query<&mut Visibility, With<TrainHoverOutline>>,
+) {
+    for (hoverable, children) in &trains {
+        for child in children.iter() {
+            let Ok(mut visibility) = outlines.get_mut(child) else {
+                continue;
+            };
+            *visibility = if hoverable.hovered {
+                Visibility::Inherited
+            } else {
+                Visibility::Hidden
+            };
+        }
+    }
+}
+
+/// Clicking a train spawns its warning over the roof; a click landing
+/// while the warning is still fading restarts the fade in place.
+fn handle_train_clicks(
+    mut commands: Commands,
+    trains: Query<(Entity, &Clickable), With<Train>>,
+    messages: Query<(Entity, &TrainWarningMessage)>,
+) {
+    for (train, clickable) in &trains {
+        if !clickable.triggered {
+            continue;
+        }
+        if let Some((message, _)) = messages.iter().find(|(_, message)| message.train == train) {
+            commands
+                .entity(message)
+                .insert(FadeOut::new(TRAIN_WARNING_FADE_SECS));
+            continue;
+        }
+        commands.spawn((
+            TrainWarningMessage { train },
+            Text2d::new(TRAIN_WARNING_TEXT),
+            TextFont::from_font_size(12.0),
+            TextColor(WARNING_COLOR),
+            FadeOut::new(TRAIN_WARNING_FADE_SECS),
+            Transform::from_xyz(0.0, TRAIN_WARNING_OFFSET_Y, 0.3),
+            ChildOf(train),
+        ));
+    }
+}
+
+/// Velocity after `delta_secs` of braking; never reverses.
+fn decelerated_velocity(velocity: f32, rate: f32, delta_secs: f32) -> f32 {
+    let braked = velocity.abs() - rate * delta_secs;
+    braked.max(0.0) * velocity.signum()
+}
+
+/// Blood accumulated on a train over its lifetime. Splatters are
+/// children of the train, so they ride along with it, survive phase
+/// transitions inside the dilemma, and disappear only when the train
+/// itself despawns on restart or a new dilemma. The oldest splatters
+/// are recycled past `max_splatters` so a long rampage stays cheap.
+#[derive(Component, Debug, Clone)]
+pub struct TrainBlood {
+    pub max_splatters: usize,
+    /// Oldest first.
+    splatters: Vec<Entity>,
+}
+
+impl Default for TrainBlood {
+    fn default() -> Self {
+        Self {
+            max_splatters: 64,
+            splatters: Vec::new(),
+        }
+    }
+}
+
+impl TrainBlood {
+    pub fn splatter_count(&self) -> usize {
+        self.splatters.len()
+    }
+}
+
+/// Records `splatter` as the newest entry, returning the evicted oldest
+/// once the cap is reached.
+fn push_splatter(splatters: &mut Vec<Entity>, splatter: Entity, max: usize) -> Option<Entity> {
+    splatters.push(splatter);
+    if splatters.len() > max.max(1) {
+        Some(splatters.remove(0))
+    } else {
+        None
+    }
+}
+
+/// Asks for a splatter at a world point on the given train; impacts and
+/// casualties send this instead of touching the train's children.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct TrainBloodSplatter {
+    pub train: Entity,
+    /// World position of the impact.
+    pub position: Vec2,
+}
+
+/// Spawns requested splatters as train children with a random rotation
+/// and scale, evicting the oldest past the cap.
+fn apply_blood_splatters(
+    mut commands: Commands,
+    mut events: EventReader<TrainBloodSplatter>,
+    mut rng: ResMut<GameRng>,
+    mut trains: Query<(&mut TrainBlood, &GlobalTransform), With<Train>>,
+) {
+    for event in events.read() {
+        let Ok((mut blood, transform)) = trains.get_mut(event.train) else {
+            continue;
+        };
+        let local = event.position - transform.translation().truncate();
+        let angle = rng.next_f32() * std::f32::consts::TAU;
+        let scale = SPLATTER_SCALE_MIN + (SPLATTER_SCALE_MAX - SPLATTER_SCALE_MIN) * rng.next_f32();
+        let splatter = commands
+            .spawn((
+                Sprite {
+                    color: DANGER_COLOR,
+                    custom_size: Some(Vec2::splat(SPLATTER_SIZE)),
+                    ..default()
+                },
+                Transform::from_translation(local.extend(SPLATTER_Z))
+                    .with_rotation(Quat::from_rotation_z(angle))
+                    .with_scale(Vec3::splat(scale)),
+                ChildOf(event.train),
+            ))
+            .id();
+        let max = blood.max_splatters;
+        if let Some(oldest) = push_splatter(&mut blood.splatters, splatter, max) {
+            commands.entity(oldest).despawn();
+        }
+    }
+}
+
+/// The one integrator for train movement: brakes bleed velocity first,
+/// then the remaining velocity advances the train. Pausing freezes it
+/// via the run condition; dilation slows it with the rest of the sim.
+fn move_trains(
+    mut commands: Commands,
+    time: Res<Time>,
+    dilation: Res<Dilation>,
+    mut stopped: EventWriter<TrainStopped>,
+    mut trains: Query<
+        (
+            Entity,
+            &mut TrainMotion,
+            &mut Transform,
+            Option<&TrainDeceleration>,
+        ),
+        With<Train>,
+    >,
+) {
+    let delta = dilation.scale(time.delta_secs());
+    for (entity, mut motion, mut transform, brake) in &mut trains {
+        if let Some(brake) = brake {
+            let braked = decelerated_velocity(motion.velocity, brake.rate, delta);
+            if braked == 0.0 && motion.velocity != 0.0 {
+                stopped.write(TrainStopped { train: entity });
+                commands.entity(entity).remove::<TrainDeceleration>();
+            }
+            motion.velocity = braked;
+        }
+        transform.translation.x += motion.velocity * delta;
+    }
+}
+
+/// In the slow-death variant the commit input is the lever pull, and
+/// the lever only brakes: every moving train starts decelerating
+/// instead of diverting.
+fn handle_slow_death_lever(
+    mut commands: Commands,
+    mode: Res<SlowDeathMode>,
+    mut events: EventReader<DecisionEvent>,
+    trains: Query<Entity, (With<Train>, With<TrainMotion>, Without<TrainDeceleration>)>,
+) {
+    let pulled = events
+        .read()
+        .any(|event| event.kind == DecisionKind::Commit);
+    if !pulled || !mode.enabled {
+        return;
+    }
+    for train in &trains {
+        commands.entity(train).insert(TrainDeceleration {
+            rate: SLOW_DEATH_BRAKE_RATE,
+        });
+    }
+}
+
+/// A braked train coming to rest is the slow-death resolution: tally it
+/// as a slowed outcome and move the dilemma on.
+fn resolve_slow_death_stops(
+    mut events: EventReader<TrainStopped>,
+    mode: Res<SlowDeathMode>,
+    mut stats: ResMut<RunStats>,
+    mut next_phase: ResMut<NextState<DilemmaPhase>>,
+) {
+    let mut any = false;
+    for _ in events.read() {
+        any = true;
+        stats.slowed += 1;
+    }
+    if any && mode.enabled {
+        next_phase.set(DilemmaPhase::Resolution);
+    }
+}
+
+pub struct TrainPlugin;
+
+impl Plugin for TrainPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SlowDeathMode>()
+            .add_event::<TrainBloodSplatter>()
+            .add_event::<TrainStopped>()
+            .add_systems(
+                Update,
+                (
+                    handle_slow_death_lever.run_if(in_state(DilemmaPhase::Decision)),
+                    move_trains.run_if(in_state(PauseState::Running)),
+                    resolve_slow_death_stops,
+                )
+                    .chain(),
+            )
+            .add_systems(
+                Update,
+                (
+                    apply_blood_splatters,
+                    make_trains_interactive,
+                    sync_train_hover_outlines,
+                    handle_train_clicks,
+                ),
+            );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn braking_reaches_zero_without_reversing() {
+        let slowing = decelerated_velocity(100.0, 60.0, 1.0);
+        assert_eq!(slowing, 40.0);
+        assert_eq!(decelerated_velocity(slowing, 60.0, 1.0), 0.0);
+        // Leftward trains brake toward zero from the other side.
+        assert_eq!(decelerated_velocity(-30.0, 60.0, 1.0), 0.0);
+    }
+
+    #[test]
+    fn the_cap_evicts_the_oldest_splatter() {
+        let mut splatters = Vec::new();
+        let entities: Vec<Entity> = (0..4).map(Entity::from_raw).collect();
+        assert_eq!(push_splatter(&mut splatters, entities[0], 3), None);
+        assert_eq!(push_splatter(&mut splatters, entities[1], 3), None);
+        assert_eq!(push_splatter(&mut splatters, entities[2], 3), None);
+        assert_eq!(
+            push_splatter(&mut splatters, entities[3], 3),
+            Some(entities[0])
+        );
+        assert_eq!(splatters, entities[1..]);
+    }
+
+    #[test]
+    fn a_zero_cap_still_keeps_the_newest_splatter() {
+        let mut splatters = Vec::new();
+        let first = Entity::from_raw(1);
+        let second = Entity::from_raw(2);
+        assert_eq!(push_splatter(&mut splatters, first, 0), None);
+        assert_eq!(push_splatter(&mut splatters, second, 0), Some(first));
+        assert_eq!(splatters, vec![second]);
+    }
+}
@@ -0,0 +1,149 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    data::{
+        save::{read_ron, write_ron},
+        states::MainState,
+    },
+    systems::audio::{
+        bitcrush::{BitcrushParams, BitcrushToggle},
+        SystemMenuAudio, SystemMenuSounds,
+    },
+    ui::menu::audio::AudioSettingsState,
+};
+
+const ENDINGS_FILE: &str = "endings.ron";
+
+/// The distinct endings a run can reach.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Ending {
+    /// Pulled the lever and lived with it.
+    LeverPulled,
+    /// Stood by and let the track decide.
+    Inaction,
+    /// Put themselves on the track instead.
+    SelfSacrifice,
+    /// Finished calibration without a single casualty.
+    PerfectCalibration,
+}
+
+impl Ending {
+    /// Every ending, in gallery order.
+    pub const ALL: [Ending; 4] = [
+        Ending::LeverPulled,
+        Ending::Inaction,
+        Ending::SelfSacrifice,
+        Ending::PerfectCalibration,
+    ];
+
+    pub fn title(self) -> &'static str {
+        match self {
+            Ending::LeverPulled => "THE OPERATOR",
+            Ending::Inaction => "THE BYSTANDER",
+            Ending::SelfSacrifice => "THE VOLUNTEER",
+            Ending::PerfectCalibration => "THE CALIBRATOR",
+        }
+    }
+
+    pub fn description(self) -> &'static str {
+        match self {
+            Ending::LeverPulled => "You pulled the lever.",
+            Ending::Inaction => "You did nothing at all.",
+            Ending::SelfSacrifice => "You chose yourself.",
+            Ending::PerfectCalibration => "A flawless calibration run.",
+        }
+    }
+}
+
+/// Which endings have been reached, persisted so the gallery survives
+/// restarts.
+#[derive(Resource, Debug, Clone, Serialize, Deserialize)]
+pub struct EndingsCollection {
+    unlocked: Vec<Ending>,
+}
+
+impl Default for EndingsCollection {
+    fn default() -> Self {
+        read_ron(ENDINGS_FILE).unwrap_or(Self {
+            unlocked: Vec::new(),
+        })
+    }
+}
+
+impl EndingsCollection {
+    pub fn is_unlocked(&self, ending: Ending) -> bool {
+        self.unlocked.contains(&ending)
+    }
+
+    /// Records an ending; true (and persists) only when it is new.
+    pub fn unlock(&mut self, ending: Ending) -> bool {
+        if self.is_unlocked(ending) {
+            return false;
+        }
+        self.unlocked.push(ending);
+        write_ron(ENDINGS_FILE, self);
+        true
+    }
+}
+
+/// Fired by resolution logic whenever a run lands on an ending.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct EndingReached(pub Ending);
+
+/// Inserts newly reached endings into the collection and plays the
+/// unlock jingle for first-time unlocks only.
+fn record_reached_endings(
+    mut commands: Commands,
+    mut events: EventReader<EndingReached>,
+    mut collection: ResMut<EndingsCollection>,
+    audio: Option<Res<SystemMenuAudio>>,
+    mixer: Res<AudioSettingsState>,
+    mut bitcrush: ResMut<BitcrushToggle>,
+) {
+    for event in events.read() {
+        // Self-sacrifice warps the soundtrack through the bitcrusher;
+        // the planned vengeance ending will share this trigger.
+        if event.0 == Ending::SelfSacrifice {
+            bitcrush.engage(BitcrushParams::default());
+        }
+        if collection.unlock(event.0) {
+            if let Some(audio) = audio.as_ref() {
+                audio.play(&mut commands, SystemMenuSounds::Unlock, &mixer.settings);
+            }
+        }
+    }
+}
+
+/// The crushed soundtrack is an ending-screen treatment only; normal
+/// play resumes clean.
+fn disengage_bitcrush(mut toggle: ResMut<BitcrushToggle>) {
+    toggle.disengage();
+}
+
+pub struct EndingPlugin;
+
+impl Plugin for EndingPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<EndingsCollection>()
+            .add_event::<EndingReached>()
+            .add_systems(Update, record_reached_endings)
+            .add_systems(OnExit(MainState::Ending), disengage_bitcrush);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unlocking_is_idempotent() {
+        let mut collection = EndingsCollection {
+            unlocked: Vec::new(),
+        };
+        assert!(collection.unlock(Ending::Inaction));
+        assert!(!collection.unlock(Ending::Inaction));
+        assert!(collection.is_unlocked(Ending::Inaction));
+        assert!(!collection.is_unlocked(Ending::SelfSacrifice));
+    }
+}
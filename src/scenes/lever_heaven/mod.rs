@@ -0,0 +1,236 @@
+use bevy::prelude::*;
+
+use crate::{
+    data::{rng::GameRng, save::SaveState, states::MainState},
+    systems::{
+        audio::{SystemMenuAudio, SystemMenuSounds},
+        colors::{HIGHLIGHT_COLOR, PRIMARY_COLOR},
+        interaction::{Clickable, Disabled},
+        time::Dilation,
+    },
+    ui::menu::{
+        audio::AudioSettingsState,
+        pages::{
+            page_definition, MenuCommand, MenuCommandEvent, MenuOptionRow, MenuPage,
+            MenuPageContent,
+        },
+    },
+};
+
+/// Seconds between lever spawns.
+const LEVER_SPAWN_INTERVAL: f32 = 0.45;
+/// Downward acceleration, world units per second squared.
+const LEVER_GRAVITY: f32 = 240.0;
+/// Horizontal band levers rain across.
+const LEVER_SPAWN_HALF_WIDTH: f32 = 320.0;
+const LEVER_SPAWN_Y: f32 = 360.0;
+/// Below this the lever has left the screen for good.
+const LEVER_KILL_Y: f32 = -380.0;
+const LEVER_FONT_SIZE: f32 = 28.0;
+const LEVER_HIT_REGION: Vec2 = Vec2::new(26.0, 30.0);
+
+/// A falling lever. Pulled levers keep falling — heaven has no floor —
+/// but flip their glyph and stop responding.
+#[derive(Component, Debug, Clone, Copy)]
+struct FallingLever {
+    velocity: f32,
+    pulled: bool,
+}
+
+/// Everything spawned by the toy, for the exit teardown.
+#[derive(Component)]
+struct LeverHeavenScene;
+
+/// Spawn pacing clock; dilated like everything else here.
+#[derive(Resource, Debug, Default)]
+struct LeverSpawnClock {
+    elapsed_secs: f32,
+}
+
+/// Levers due after `delta` on a clock carrying `elapsed`; the
+/// remainder stays on the clock.
+fn due_lever_spawns(elapsed_secs: &mut f32, delta_secs: f32) -> usize {
+    *elapsed_secs += delta_secs;
+    let due = (*elapsed_secs / LEVER_SPAWN_INTERVAL).floor() as usize;
+    *elapsed_secs -= due as f32 * LEVER_SPAWN_INTERVAL;
+    due
+}
+
+fn handle_open_lever_heaven_command(
+    mut events: EventReader<MenuCommandEvent>,
+    save: Res<SaveState>,
+    mut next_main: ResMut<NextState<MainState>>,
+) {
+    let requested = events
+        .read()
+        .any(|event| matches!(event.command, MenuCommand::OpenLeverHeaven));
+    if requested && save.lever_ending_reached {
+        next_main.set(MainState::LeverHeaven);
+    }
+}
+
+/// Rains levers down at the spawn interval, scattered across the band.
+fn spawn_falling_levers(
+    mut commands: Commands,
+    time: Res<Time>,
+    dilation: Res<Dilation>,
+    mut clock: ResMut<LeverSpawnClock>,
+    mut rng: ResMut<GameRng>,
+) {
+    let due = due_lever_spawns(&mut clock.elapsed_secs, dilation.scale(time.delta_secs()));
+    for _ in 0..due {
+        let x = (rng.next_f32() * 2.0 - 1.0) * LEVER_SPAWN_HALF_WIDTH;
+        commands.spawn((
+            LeverHeavenScene,
+            FallingLever {
+                velocity: 0.0,
+                pulled: false,
+            },
+            Text2d::new("/"),
+            TextFont::from_font_size(LEVER_FONT_SIZE),
+            TextColor(PRIMARY_COLOR),
+            Clickable::new(LEVER_HIT_REGION),
+            Transform::from_xyz(x, LEVER_SPAWN_Y, 0.2),
+        ));
+    }
+}
+
+/// Integrates gravity and retires levers that have fallen off screen.
+fn fall_levers(
+    mut commands: Commands,
+    time: Res<Time>,
+    dilation: Res<Dilation>,
+    mut levers: Query<(Entity, &mut FallingLever, &mut Transform)>,
+) {
+    let delta = dilation.scale(time.delta_secs());
+    for (entity, mut lever, mut transform) in &mut levers {
+        lever.velocity += LEVER_GRAVITY * delta;
+        transform.translation.y -= lever.velocity * delta;
+        if transform.translation.y < LEVER_KILL_Y {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+/// Clicking a lever pulls it: flip the glyph, light it up, click the
+/// select sound. Already-pulled levers ignore further clicks.
+fn pull_levers_on_click(
+    mut commands: Commands,
+    audio: Option<Res<SystemMenuAudio>>,
+    mixer: Res<AudioSettingsState>,
+    mut levers: Query<(&mut FallingLever, &Clickable, &mut Text2d, &mut TextColor)>,
+) {
+    for (mut lever, clickable, mut text, mut color) in &mut levers {
+        if !clickable.triggered || lever.pulled {
+            continue;
+        }
+        lever.pulled = true;
+        text.0 = "\\".to_string();
+        color.0 = HIGHLIGHT_COLOR;
+        if let Some(audio) = &audio {
+            audio.play(&mut commands, SystemMenuSounds::Select, &mixer.settings);
+        }
+    }
+}
+
+/// Escape leaves heaven.
+fn handle_lever_heaven_exit(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut next_main: ResMut<NextState<MainState>>,
+) {
+    if keys.just_pressed(KeyCode::Escape) {
+        next_main.set(MainState::Menu);
+    }
+}
+
+fn despawn_lever_heaven(
+    mut commands: Commands,
+    mut clock: ResMut<LeverSpawnClock>,
+    scene: Query<Entity, With<LeverHeavenScene>>,
+) {
+    clock.elapsed_secs = 0.0;
+    for entity in &scene {
+        commands.entity(entity).despawn();
+    }
+}
+
+/// Dims the main menu's LEVER HEAVEN row until the lever ending has
+/// been reached, mirroring the sandbox lock pattern.
+fn sync_lever_heaven_lock(
+    mut commands: Commands,
+    save: Res<SaveState>,
+    contents: Query<(Entity, &MenuPageContent), Added<MenuPageContent>>,
+    all_contents: Query<(Entity, &MenuPageContent)>,
+    rows: Query<(Entity, &MenuOptionRow)>,
+) {
+    let refresh_all = save.is_changed() && !save.is_added();
+    let targets: Vec<Entity> = if refresh_all {
+        all_contents
+            .iter()
+            .filter(|(_, content)| content.page == MenuPage::MainRoot)
+            .map(|(entity, _)| entity)
+            .collect()
+    } else {
+        contents
+            .iter()
+            .filter(|(_, content)| content.page == MenuPage::MainRoot)
+            .map(|(entity, _)| entity)
+            .collect()
+    };
+    if targets.is_empty() {
+        return;
+    }
+    let options = page_definition(MenuPage::MainRoot).options;
+    for (entity, row) in &rows {
+        if !targets.contains(&row.content) {
+            continue;
+        }
+        let heaven = options
+            .get(row.index)
+            .is_some_and(|option| matches!(option.command, MenuCommand::OpenLeverHeaven));
+        if !heaven {
+            continue;
+        }
+        if save.lever_ending_reached {
+            commands.entity(entity).remove::<Disabled>();
+        } else {
+            commands.entity(entity).insert(Disabled);
+        }
+    }
+}
+
+pub struct LeverHeavenPlugin;
+
+impl Plugin for LeverHeavenPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<LeverSpawnClock>()
+            .add_systems(
+                Update,
+                (handle_open_lever_heaven_command, sync_lever_heaven_lock),
+            )
+            .add_systems(
+                Update,
+                (
+                    spawn_falling_levers,
+                    fall_levers,
+                    pull_levers_on_click,
+                    handle_lever_heaven_exit,
+                )
+                    .run_if(in_state(MainState::LeverHeaven)),
+            )
+            .add_systems(OnExit(MainState::LeverHeaven), despawn_lever_heaven);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_spawn_clock_keeps_its_remainder() {
+        let mut elapsed = 0.0;
+        assert_eq!(due_lever_spawns(&mut elapsed, LEVER_SPAWN_INTERVAL * 2.5), 2);
+        assert!((elapsed - LEVER_SPAWN_INTERVAL * 0.5).abs() < 1e-5);
+        assert_eq!(due_lever_spawns(&mut elapsed, 0.0), 0);
+    }
+}
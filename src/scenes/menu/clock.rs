@@ -0,0 +1,82 @@
+use bevy::{prelude::*, window::PrimaryWindow};
+
+use crate::{
+    data::states::MainState,
+    systems::{colors::PRIMARY_COLOR, time::GameClock},
+};
+
+/// Deepest the night tint gets at midnight.
+const MAX_NIGHT_ALPHA: f32 = 0.55;
+/// Tint sits above the menu scene but below window chrome (z >= 600) so
+/// open windows stay readable at night.
+const OVERLAY_Z: f32 = 560.0;
+const NIGHT_TINT: Color = Color::srgb(0.0, 0.02, 0.08);
+const CLOCK_TEXT_SIZE: f32 = 14.0;
+
+/// The full-screen day/night tint sprite.
+#[derive(Component, Debug, Clone, Copy)]
+struct DayNightOverlay;
+
+/// The HUD clock readout.
+#[derive(Component, Debug, Clone, Copy)]
+struct HudClockText;
+
+fn spawn_clock_hud(mut commands: Commands, windows: Query<&Window, With<PrimaryWindow>>) {
+    let size = windows
+        .single()
+        .map(|window| Vec2::new(window.width(), window.height()))
+        .unwrap_or(Vec2::new(1280.0, 720.0));
+    commands.spawn((
+        DayNightOverlay,
+        Sprite {
+            color: NIGHT_TINT.with_alpha(0.0),
+            custom_size: Some(size * 1.5),
+            ..default()
+        },
+        Transform::from_xyz(0.0, 0.0, OVERLAY_Z),
+    ));
+    commands.spawn((
+        HudClockText,
+        Text2d::new(String::new()),
+        TextFont::from_font_size(CLOCK_TEXT_SIZE),
+        TextColor(PRIMARY_COLOR),
+        Transform::from_xyz(size.x * 0.5 - 60.0, size.y * 0.5 - 24.0, OVERLAY_Z + 1.0),
+    ));
+}
+
+/// Deepens the tint as daylight fades and keeps the readout current.
+fn sync_clock_hud(
+    clock: Res<GameClock>,
+    mut overlays: Query<&mut Sprite, With<DayNightOverlay>>,
+    mut readouts: Query<&mut Text2d, With<HudClockText>>,
+) {
+    let alpha = (1.0 - clock.daylight()) * MAX_NIGHT_ALPHA;
+    for mut sprite in &mut overlays {
+        sprite.color = NIGHT_TINT.with_alpha(alpha);
+    }
+    let formatted = clock.formatted();
+    for mut text in &mut readouts {
+        if text.0 != formatted {
+            text.0 = formatted.clone();
+        }
+    }
+}
+
+fn despawn_clock_hud(
+    mut commands: Commands,
+    hud: Query<Entity, Or<(With<DayNightOverlay>, With<HudClockText>)>>,
+) {
+    for entity in &hud {
+        commands.entity(entity).despawn();
+    }
+}
+
+pub struct MenuClockPlugin;
+
+impl Plugin for MenuClockPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(MainState::Menu), spawn_clock_hud)
+            .add_systems(Update, sync_clock_hud.run_if(in_state(MainState::Menu)))
+            .add_systems(OnExit(MainState::Menu), despawn_clock_hud);
+    }
+}
@@ -0,0 +1,108 @@
+pub mod clock;
+pub mod title;
+
+use bevy::prelude::*;
+
+use crate::{
+    data::states::MainState,
+    systems::interaction::UiInteractionState,
+    ui::{
+        hold_confirm::{HoldProgressRing, HoldToConfirm},
+        menu::pages::{spawn_menu_root, MenuHost, MenuPage, MenuStackMemory},
+    },
+};
+
+/// Deliberate-exit safety on the title screen: quitting requires holding
+/// Escape rather than a single accidental tap.
+#[derive(Resource, Debug, Clone)]
+pub struct HoldEscapeQuitConfig {
+    pub enabled: bool,
+    pub hold_secs: f32,
+}
+
+impl Default for HoldEscapeQuitConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            hold_secs: 1.2,
+        }
+    }
+}
+
+#[derive(Component)]
+struct EscapeQuitRing;
+
+/// Sends the application exit request. Single funnel so every exit path
+/// (menu option, hold-to-quit) behaves identically.
+pub fn request_application_exit(exit: &mut EventWriter<AppExit>) {
+    exit.write(AppExit::Success);
+}
+
+/// Holding Escape on the title screen fills a progress ring; completing
+/// the hold quits, releasing early cancels. Inactive while a text field
+/// is focused so Escape can serve its editing role.
+fn hold_escape_to_quit(
+    mut commands: Commands,
+    time: Res<Time>,
+    keys: Res<ButtonInput<KeyCode>>,
+    config: Res<HoldEscapeQuitConfig>,
+    state: Res<UiInteractionState>,
+    mut exit: EventWriter<AppExit>,
+    mut rings: Query<(Entity, &mut HoldToConfirm), With<EscapeQuitRing>>,
+) {
+    if !config.enabled || state.text_input_focus.is_some() {
+        return;
+    }
+    let held = keys.pressed(KeyCode::Escape);
+    if held && rings.is_empty() {
+        commands.spawn((
+            EscapeQuitRing,
+            HoldProgressRing,
+            HoldToConfirm::new(config.hold_secs),
+            Transform::from_xyz(0.0, 0.0, 700.0),
+            Visibility::Inherited,
+        ));
+        return;
+    }
+    for (entity, mut hold) in &mut rings {
+        if hold.advance(held, time.delta_secs()) {
+            request_application_exit(&mut exit);
+        }
+        if !held {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+fn despawn_quit_ring(mut commands: Commands, rings: Query<Entity, With<EscapeQuitRing>>) {
+    for ring in &rings {
+        commands.entity(ring).despawn();
+    }
+}
+
+fn spawn_main_menu(mut commands: Commands, memory: Res<MenuStackMemory>) {
+    spawn_menu_root(&mut commands, &memory, MenuHost::Main, MenuPage::MainRoot);
+}
+
+fn despawn_main_menu(mut commands: Commands, menus: Query<(Entity, &MenuHost)>) {
+    for (entity, host) in &menus {
+        if *host == MenuHost::Main {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+pub struct MenuScenePlugin;
+
+impl Plugin for MenuScenePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<HoldEscapeQuitConfig>()
+            .add_plugins((clock::MenuClockPlugin, title::TitlePlugin))
+            .add_systems(
+                Update,
+                hold_escape_to_quit.run_if(in_state(MainState::Menu)),
+            )
+            .add_systems(OnEnter(MainState::Menu), spawn_main_menu)
+            .add_systems(OnExit(MainState::Menu), (despawn_quit_ring, despawn_main_menu));
+    }
+}
@@ -0,0 +1,183 @@
+use bevy::{color::Mix, prelude::*};
+
+use crate::{
+    data::{states::MainState, stats::PathChoices},
+    scenes::dilemma::fireworks::Fireworks,
+    systems::{
+        colors::{DANGER_COLOR, PRIMARY_COLOR},
+        interaction::{Clickable, CustomCursor},
+    },
+};
+
+const TITLE_TEXT: &str = "MORALITY ENGINE";
+const TITLE_FONT_SIZE: f32 = 48.0;
+const TITLE_LETTER_SPACING: f32 = TITLE_FONT_SIZE * 0.7;
+const TITLE_Y: f32 = 240.0;
+/// Letters sit well under window chrome (z >= 600).
+const TITLE_Z: f32 = 200.0;
+
+/// Cursor distance inside which letters start fleeing.
+const FLEE_RADIUS: f32 = 90.0;
+/// Peak flee speed, at zero cursor distance.
+const FLEE_SPEED: f32 = 420.0;
+/// Letters never stray further than this from home.
+const MAX_FLEE_DISTANCE: f32 = 70.0;
+/// Exponential rate letters ease home at once the cursor leaves.
+const RETURN_RATE: f32 = 6.0;
+
+const EXPLOSION_PARTICLES: usize = 18;
+
+/// One glyph of the menu title: flees the cursor, tints with the blood
+/// of the paths taken, and can be clicked to pieces.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct TitleLetter {
+    /// Rest position the letter eases back to.
+    pub home: Vec2,
+}
+
+/// Where `current` moves this frame: pushed radially away from the
+/// cursor inside the flee radius (harder the closer it is, clamped to
+/// the max stray distance), otherwise eased exponentially back home.
+fn step_letter_position(current: Vec2, home: Vec2, cursor: Vec2, delta_secs: f32) -> Vec2 {
+    let from_cursor = current - cursor;
+    let distance = from_cursor.length();
+    if distance < FLEE_RADIUS {
+        let push = from_cursor.normalize_or(Vec2::Y)
+            * FLEE_SPEED
+            * (1.0 - distance / FLEE_RADIUS)
+            * delta_secs;
+        let fled = current + push;
+        home + (fled - home).clamp_length_max(MAX_FLEE_DISTANCE)
+    } else {
+        let ease = 1.0 - (-RETURN_RATE * delta_secs).exp();
+        current + (home - current) * ease
+    }
+}
+
+/// The title colour at a given blood level: terminal green when clean,
+/// fully red when every path taken was violent.
+fn blood_tint(level: f32) -> Color {
+    PRIMARY_COLOR.mix(&DANGER_COLOR, level.clamp(0.0, 1.0))
+}
+
+fn spawn_title(mut commands: Commands, choices: Res<PathChoices>) {
+    let width = (TITLE_TEXT.chars().count() - 1) as f32 * TITLE_LETTER_SPACING;
+    for (index, letter) in TITLE_TEXT.chars().enumerate() {
+        if letter == ' ' {
+            continue;
+        }
+        let home = Vec2::new(index as f32 * TITLE_LETTER_SPACING - width * 0.5, TITLE_Y);
+        commands.spawn((
+            TitleLetter { home },
+            Text2d::new(letter.to_string()),
+            TextFont::from_font_size(TITLE_FONT_SIZE),
+            TextColor(blood_tint(choices.blood_level())),
+            Clickable::new(Vec2::new(TITLE_LETTER_SPACING, TITLE_FONT_SIZE)),
+            Transform::from_translation(home.extend(TITLE_Z)),
+        ));
+    }
+}
+
+fn despawn_title(mut commands: Commands, letters: Query<Entity, With<TitleLetter>>) {
+    for letter in &letters {
+        commands.entity(letter).despawn();
+    }
+}
+
+/// Pushes letters away from the cursor and eases the rest home.
+fn flee_cursor(
+    time: Res<Time>,
+    cursor: Res<CustomCursor>,
+    mut letters: Query<(&TitleLetter, &mut Transform)>,
+) {
+    for (letter, mut transform) in &mut letters {
+        let current = transform.translation.truncate();
+        let next = step_letter_position(current, letter.home, cursor.position, time.delta_secs());
+        transform.translation = next.extend(TITLE_Z);
+    }
+}
+
+/// Re-tints the title whenever the path record changes.
+fn tint_title_blood(
+    choices: Res<PathChoices>,
+    mut letters: Query<&mut TextColor, With<TitleLetter>>,
+) {
+    if !choices.is_changed() {
+        return;
+    }
+    let tint = blood_tint(choices.blood_level());
+    for mut color in &mut letters {
+        color.0 = tint;
+    }
+}
+
+/// Clicked letters burst into red particles and are gone for the rest
+/// of the menu visit; the full title returns next time it spawns.
+fn explode_title_letters(
+    mut commands: Commands,
+    choices: Res<PathChoices>,
+    letters: Query<(Entity, &Clickable, &Transform), With<TitleLetter>>,
+) {
+    for (entity, clickable, transform) in &letters {
+        if !clickable.triggered {
+            continue;
+        }
+        commands.spawn((
+            Fireworks {
+                spawn_count: EXPLOSION_PARTICLES,
+                interval_secs: 0.0,
+                palette: vec![DANGER_COLOR, blood_tint(choices.blood_level())],
+                area: Vec2::ZERO,
+                remaining_bursts: Some(1),
+                ..default()
+            },
+            Transform::from_translation(transform.translation),
+        ));
+        commands.entity(entity).despawn();
+    }
+}
+
+pub struct TitlePlugin;
+
+impl Plugin for TitlePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(MainState::Menu), spawn_title)
+            .add_systems(OnExit(MainState::Menu), despawn_title)
+            .add_systems(
+                Update,
+                (flee_cursor, tint_title_blood, explode_title_letters)
+                    .run_if(in_state(MainState::Menu)),
+            );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn letters_flee_the_cursor_and_ease_home() {
+        let home = Vec2::ZERO;
+        // Cursor just beside the letter: it moves away, but stays leashed.
+        let fled = step_letter_position(home, home, Vec2::new(10.0, 0.0), 0.1);
+        assert!(fled.x < 0.0);
+        assert!(fled.length() <= MAX_FLEE_DISTANCE + 1e-3);
+        // Cursor far away: the letter eases back without overshooting.
+        let far_cursor = Vec2::new(1000.0, 0.0);
+        let returning = step_letter_position(fled, home, far_cursor, 0.1);
+        assert!(returning.length() < fled.length());
+        assert!(returning.x < 0.0 || returning.length() < 1e-3);
+    }
+
+    #[test]
+    fn blood_tint_tracks_the_violent_fraction() {
+        assert_eq!(PathChoices::default().blood_level(), 0.0);
+        let mixed = PathChoices {
+            violent: 1,
+            merciful: 3,
+        };
+        assert_eq!(mixed.blood_level(), 0.25);
+        assert_eq!(blood_tint(0.0), PRIMARY_COLOR.mix(&DANGER_COLOR, 0.0));
+        assert_eq!(blood_tint(2.0), blood_tint(1.0));
+    }
+}
@@ -0,0 +1,6 @@
+pub mod boot;
+pub mod dilemma;
+pub mod sandbox;
+pub mod ending;
+pub mod lever_heaven;
+pub mod menu;
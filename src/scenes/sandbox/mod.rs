@@ -0,0 +1,370 @@
+use bevy::prelude::*;
+
+use crate::{
+    data::{save::SaveState, states::MainState},
+    scenes::dilemma::{CurrentDilemma, DilemmaId},
+    systems::interaction::{Disabled, UiInteractionState},
+    ui::{
+        menu::{
+            dropdown::{Dropdown, DropdownSelection},
+            pages::{page_definition, MenuCommand, MenuOptionRow, MenuPage, MenuPageContent},
+        },
+        table::{Cell, Column, Row, SelectableTable, Table},
+        window::{Window, WindowBoundary, WindowContent, WindowTitle},
+    },
+};
+
+/// The id the sandbox runs under. The dilemma spawner builds this one
+/// from [`SandboxState`] instead of an authored definition; everything
+/// downstream (decision, resolution, results) is the shared path.
+pub const SANDBOX_DILEMMA_ID: DilemmaId = DilemmaId("sandbox");
+
+const SANDBOX_TEXT_SIZE: f32 = 13.0;
+/// Per-track cap keeps the casualty readout (and the carnage) bounded.
+pub const MAX_TRACK_CASUALTIES: u32 = 99;
+
+/// Who is tied to a sandbox track.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OccupantKind {
+    Workers,
+    Tourists,
+    Philosophers,
+    Convicts,
+}
+
+impl OccupantKind {
+    pub const ALL: [OccupantKind; 4] = [
+        OccupantKind::Workers,
+        OccupantKind::Tourists,
+        OccupantKind::Philosophers,
+        OccupantKind::Convicts,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            OccupantKind::Workers => "WORKERS",
+            OccupantKind::Tourists => "TOURISTS",
+            OccupantKind::Philosophers => "PHILOSOPHERS",
+            OccupantKind::Convicts => "CONVICTS",
+        }
+    }
+}
+
+/// One configurable track of the custom dilemma.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TrackConfig {
+    pub occupants: OccupantKind,
+    pub casualties: u32,
+}
+
+/// The player-built dilemma. Lives as a resource so it survives the
+/// trip into `MainState::Dilemma`, where the spawner reads it when the
+/// current id is [`SANDBOX_DILEMMA_ID`].
+#[derive(Resource, Debug, Clone, PartialEq, Eq)]
+pub struct SandboxState {
+    pub tracks: [TrackConfig; 2],
+}
+
+impl Default for SandboxState {
+    fn default() -> Self {
+        Self {
+            tracks: [
+                TrackConfig {
+                    occupants: OccupantKind::Workers,
+                    casualties: 1,
+                },
+                TrackConfig {
+                    occupants: OccupantKind::Tourists,
+                    casualties: 5,
+                },
+            ],
+        }
+    }
+}
+
+/// A casualty count nudged by `delta`, clamped to the track cap.
+fn adjusted_casualties(current: u32, delta: i32) -> u32 {
+    current
+        .saturating_add_signed(delta)
+        .min(MAX_TRACK_CASUALTIES)
+}
+
+fn sandbox_table_rows(state: &SandboxState) -> Vec<Row> {
+    state
+        .tracks
+        .iter()
+        .enumerate()
+        .map(|(index, track)| {
+            Row::new(vec![
+                Cell::new(format!("TRACK {}", (b'A' + index as u8) as char)),
+                Cell::new(track.occupants.label()),
+                Cell::new(track.casualties.to_string()),
+            ])
+        })
+        .collect()
+}
+
+/// Marks every top-level entity of the builder screen for teardown.
+#[derive(Component, Debug, Clone, Copy)]
+struct SandboxUi;
+
+#[derive(Component, Debug, Clone, Copy)]
+struct SandboxTable;
+
+#[derive(Component, Debug, Clone, Copy)]
+struct SandboxOccupantDropdown;
+
+fn spawn_sandbox_builder(mut commands: Commands, state: Res<SandboxState>) {
+    let root = commands
+        .spawn((
+            SandboxUi,
+            Window {
+                boundary: WindowBoundary {
+                    dimensions: Vec2::new(420.0, 260.0),
+                },
+                ..default()
+            },
+            WindowTitle {
+                text: String::from("SANDBOX"),
+            },
+            Transform::from_xyz(0.0, 20.0, 0.0),
+        ))
+        .id();
+    let mut table = Table::new(
+        vec![
+            Column::new("TRACK", 90.0),
+            Column::new("OCCUPANTS", 170.0),
+            Column::new("CASUALTIES", 120.0),
+        ],
+        SANDBOX_TEXT_SIZE,
+    );
+    table.rows = sandbox_table_rows(&state);
+    commands.spawn((
+        SandboxUi,
+        SandboxTable,
+        SelectableTable { selected: Some(0) },
+        table,
+        WindowContent::new(root),
+        Transform::from_xyz(0.0, 40.0, 0.2),
+    ));
+    commands.spawn((
+        SandboxUi,
+        SandboxOccupantDropdown,
+        Dropdown::new(
+            OccupantKind::ALL
+                .iter()
+                .map(|kind| kind.label().to_string())
+                .collect(),
+        ),
+        WindowContent::new(root),
+        Transform::from_xyz(-60.0, -50.0, 0.3),
+    ));
+    commands.spawn((
+        SandboxUi,
+        Text2d::new("ARROWS ADJUST COUNT / ENTER RUNS / ESC BACK"),
+        TextFont::from_font_size(SANDBOX_TEXT_SIZE * 0.8),
+        WindowContent::new(root),
+        Transform::from_xyz(0.0, -100.0, 0.2),
+    ));
+}
+
+fn despawn_sandbox_builder(mut commands: Commands, roots: Query<Entity, With<SandboxUi>>) {
+    for entity in &roots {
+        commands.entity(entity).despawn();
+    }
+}
+
+/// Keeps the table mirroring the edited state.
+fn refresh_sandbox_table(
+    state: Res<SandboxState>,
+    mut tables: Query<&mut Table, With<SandboxTable>>,
+) {
+    if !state.is_changed() {
+        return;
+    }
+    for mut table in &mut tables {
+        table.rows = sandbox_table_rows(&state);
+    }
+}
+
+/// Left/Right nudges the selected track's casualty count.
+fn adjust_sandbox_casualties(
+    keys: Res<ButtonInput<KeyCode>>,
+    ui_state: Res<UiInteractionState>,
+    mut state: ResMut<SandboxState>,
+    tables: Query<&SelectableTable, With<SandboxTable>>,
+) {
+    if ui_state.text_input_focus.is_some() {
+        return;
+    }
+    let delta = keys.just_pressed(KeyCode::ArrowRight) as i32
+        - keys.just_pressed(KeyCode::ArrowLeft) as i32;
+    if delta == 0 {
+        return;
+    }
+    let Some(selected) = tables.iter().find_map(|table| table.selected) else {
+        return;
+    };
+    let Some(track) = state.tracks.get_mut(selected) else {
+        return;
+    };
+    track.casualties = adjusted_casualties(track.casualties, delta);
+}
+
+/// Two-way sync between the occupant dropdown and the selected track:
+/// changing rows loads that track's occupant into the dropdown,
+/// picking an item writes it back.
+fn sync_occupant_dropdown(
+    mut state: ResMut<SandboxState>,
+    tables: Query<&SelectableTable, With<SandboxTable>>,
+    mut dropdowns: Query<&mut Dropdown, With<SandboxOccupantDropdown>>,
+    mut last_row: Local<Option<usize>>,
+) {
+    let Some(selected) = tables.iter().find_map(|table| table.selected) else {
+        return;
+    };
+    let Some(track) = state.tracks.get(selected).copied() else {
+        return;
+    };
+    let current_index = OccupantKind::ALL
+        .iter()
+        .position(|kind| *kind == track.occupants)
+        .unwrap_or(0);
+    for mut dropdown in &mut dropdowns {
+        if *last_row != Some(selected) {
+            dropdown.selection = DropdownSelection::Single(current_index);
+            continue;
+        }
+        let DropdownSelection::Single(picked) = dropdown.selection else {
+            continue;
+        };
+        if picked != current_index {
+            if let Some(kind) = OccupantKind::ALL.get(picked) {
+                state.tracks[selected].occupants = *kind;
+            }
+        }
+    }
+    *last_row = Some(selected);
+}
+
+/// Enter runs the built dilemma through the normal load path; Escape
+/// backs out to the menu.
+fn handle_sandbox_launch(
+    keys: Res<ButtonInput<KeyCode>>,
+    ui_state: Res<UiInteractionState>,
+    mut current: ResMut<CurrentDilemma>,
+    mut next_main: ResMut<NextState<MainState>>,
+) {
+    if ui_state.text_input_focus.is_some() {
+        return;
+    }
+    if keys.just_pressed(KeyCode::Enter) {
+        current.id = Some(SANDBOX_DILEMMA_ID);
+        next_main.set(MainState::Dilemma);
+    } else if keys.just_pressed(KeyCode::Escape) {
+        next_main.set(MainState::Menu);
+    }
+}
+
+/// Opens the builder; issued by the main menu's SANDBOX row.
+fn handle_open_sandbox_command(
+    mut events: EventReader<crate::ui::menu::pages::MenuCommandEvent>,
+    mut next_main: ResMut<NextState<MainState>>,
+) {
+    if events
+        .read()
+        .any(|event| matches!(event.command, MenuCommand::OpenSandbox))
+    {
+        next_main.set(MainState::Sandbox);
+    }
+}
+
+/// Dims and deactivates the main menu's SANDBOX row until calibration
+/// has been completed, mirroring the level-selector lock pattern.
+fn sync_sandbox_lock(
+    mut commands: Commands,
+    save: Res<SaveState>,
+    contents: Query<(Entity, &MenuPageContent), Added<MenuPageContent>>,
+    all_contents: Query<(Entity, &MenuPageContent)>,
+    rows: Query<(Entity, &MenuOptionRow)>,
+) {
+    let refresh_all = save.is_changed() && !save.is_added();
+    let targets: Vec<Entity> = if refresh_all {
+        all_contents
+            .iter()
+            .filter(|(_, content)| content.page == MenuPage::MainRoot)
+            .map(|(entity, _)| entity)
+            .collect()
+    } else {
+        contents
+            .iter()
+            .filter(|(_, content)| content.page == MenuPage::MainRoot)
+            .map(|(entity, _)| entity)
+            .collect()
+    };
+    if targets.is_empty() {
+        return;
+    }
+    let options = page_definition(MenuPage::MainRoot).options;
+    for (entity, row) in &rows {
+        if !targets.contains(&row.content) {
+            continue;
+        }
+        let sandbox = options
+            .get(row.index)
+            .is_some_and(|option| matches!(option.command, MenuCommand::OpenSandbox));
+        if !sandbox {
+            continue;
+        }
+        if save.calibration_complete {
+            commands.entity(entity).remove::<Disabled>();
+        } else {
+            commands.entity(entity).insert(Disabled);
+        }
+    }
+}
+
+pub struct SandboxPlugin;
+
+impl Plugin for SandboxPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SandboxState>()
+            .add_systems(OnEnter(MainState::Sandbox), spawn_sandbox_builder)
+            .add_systems(OnExit(MainState::Sandbox), despawn_sandbox_builder)
+            .add_systems(
+                Update,
+                (handle_open_sandbox_command, sync_sandbox_lock),
+            )
+            .add_systems(
+                Update,
+                (
+                    adjust_sandbox_casualties,
+                    sync_occupant_dropdown,
+                    refresh_sandbox_table,
+                    handle_sandbox_launch,
+                )
+                    .run_if(in_state(MainState::Sandbox)),
+            );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn casualty_adjustment_clamps_at_both_ends() {
+        assert_eq!(adjusted_casualties(0, -1), 0);
+        assert_eq!(adjusted_casualties(0, 1), 1);
+        assert_eq!(adjusted_casualties(MAX_TRACK_CASUALTIES, 1), MAX_TRACK_CASUALTIES);
+    }
+
+    #[test]
+    fn table_rows_mirror_the_track_configs() {
+        let state = SandboxState::default();
+        let rows = sandbox_table_rows(&state);
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].cells[1].text, OccupantKind::Workers.label());
+        assert_eq!(rows[1].cells[2].text, "5");
+    }
+}
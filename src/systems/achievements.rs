@@ -0,0 +1,158 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    data::save::{read_ron, write_ron},
+    scenes::ending::{Ending, EndingReached, EndingsCollection},
+    systems::audio::{SystemMenuAudio, SystemMenuSounds},
+    ui::{
+        menu::audio::AudioSettingsState,
+        notifications::{NotificationSeverity, Notifications},
+    },
+};
+
+const ACHIEVEMENTS_FILE: &str = "achievements.ron";
+
+/// The achievements a player can earn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum AchievementId {
+    /// Reached any ending for the first time.
+    FirstEnding,
+    /// Restarted a dilemma mid-run.
+    FirstRestart,
+    /// Collected every ending in the gallery.
+    AllEndings,
+}
+
+impl AchievementId {
+    pub fn title(self) -> &'static str {
+        match self {
+            AchievementId::FirstEnding => "HOW IT ENDS",
+            AchievementId::FirstRestart => "SECOND THOUGHTS",
+            AchievementId::AllEndings => "COMPLETIONIST",
+        }
+    }
+}
+
+/// Which achievements have been earned, persisted so unlocks survive
+/// restarts. Other systems check via [`Achievements::is_unlocked`] and
+/// unlock by sending [`UnlockAchievement`]; the persistence write stays
+/// inside this module.
+#[derive(Resource, Debug, Clone, Serialize, Deserialize)]
+pub struct Achievements {
+    unlocked: Vec<AchievementId>,
+}
+
+impl Default for Achievements {
+    fn default() -> Self {
+        read_ron(ACHIEVEMENTS_FILE).unwrap_or(Self {
+            unlocked: Vec::new(),
+        })
+    }
+}
+
+impl Achievements {
+    pub fn is_unlocked(&self, id: AchievementId) -> bool {
+        self.unlocked.contains(&id)
+    }
+
+    /// Records an achievement; true (and persists) only when it is new.
+    fn unlock(&mut self, id: AchievementId) -> bool {
+        if self.is_unlocked(id) {
+            return false;
+        }
+        self.unlocked.push(id);
+        write_ron(ACHIEVEMENTS_FILE, self);
+        true
+    }
+}
+
+/// Request an unlock from anywhere; repeats are ignored centrally, so
+/// senders don't need to check first.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct UnlockAchievement(pub AchievementId);
+
+/// The single unlock funnel: records first-time unlocks, raises the
+/// toast and plays the unlock jingle.
+fn process_achievement_unlocks(
+    mut commands: Commands,
+    time: Res<Time<Real>>,
+    mut events: EventReader<UnlockAchievement>,
+    mut achievements: ResMut<Achievements>,
+    mut notifications: ResMut<Notifications>,
+    audio: Option<Res<SystemMenuAudio>>,
+    mixer: Res<AudioSettingsState>,
+) {
+    for UnlockAchievement(id) in events.read() {
+        if !achievements.unlock(*id) {
+            continue;
+        }
+        notifications.post(
+            NotificationSeverity::Info,
+            format!("ACHIEVEMENT UNLOCKED: {}", id.title()),
+            time.elapsed_secs_f64(),
+        );
+        if let Some(audio) = audio.as_ref() {
+            audio.play(&mut commands, SystemMenuSounds::Unlock, &mixer.settings);
+        }
+    }
+}
+
+/// Any reached ending earns the first-ending achievement.
+fn award_ending_achievements(
+    mut endings: EventReader<EndingReached>,
+    mut unlocks: EventWriter<UnlockAchievement>,
+) {
+    for _ in endings.read() {
+        unlocks.write(UnlockAchievement(AchievementId::FirstEnding));
+    }
+}
+
+/// A full ending gallery earns the completionist achievement. Runs off
+/// the persisted collection rather than the event so ordering against
+/// the ending recorder doesn't matter.
+fn award_collection_achievements(
+    collection: Res<EndingsCollection>,
+    mut unlocks: EventWriter<UnlockAchievement>,
+) {
+    if !collection.is_changed() || collection.is_added() {
+        return;
+    }
+    if Ending::ALL.iter().all(|&ending| collection.is_unlocked(ending)) {
+        unlocks.write(UnlockAchievement(AchievementId::AllEndings));
+    }
+}
+
+pub struct AchievementsPlugin;
+
+impl Plugin for AchievementsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Achievements>()
+            .add_event::<UnlockAchievement>()
+            .add_systems(
+                Update,
+                (
+                    award_ending_achievements,
+                    award_collection_achievements,
+                    process_achievement_unlocks,
+                )
+                    .chain(),
+            );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unlocking_records_only_the_first_time() {
+        let mut achievements = Achievements {
+            unlocked: Vec::new(),
+        };
+        assert!(achievements.unlock(AchievementId::FirstEnding));
+        assert!(!achievements.unlock(AchievementId::FirstEnding));
+        assert!(achievements.is_unlocked(AchievementId::FirstEnding));
+        assert!(!achievements.is_unlocked(AchievementId::FirstRestart));
+    }
+}
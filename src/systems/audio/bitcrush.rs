@@ -0,0 +1,158 @@
+use bevy::{
+    audio::{AudioSource, Decodable},
+    prelude::*,
+};
+use rodio::Source;
+
+/// Bit-depth and sample-rate reduction settings. Defaults sound like a
+/// dying speech chip without being pure noise.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BitcrushParams {
+    /// Bits of amplitude resolution kept, `1..=16`.
+    pub bit_depth: u32,
+    /// Each decoded sample is held for this many output samples,
+    /// dividing the effective sample rate.
+    pub sample_hold: u32,
+}
+
+impl Default for BitcrushParams {
+    fn default() -> Self {
+        Self {
+            bit_depth: 6,
+            sample_hold: 4,
+        }
+    }
+}
+
+/// Quantises a sample down to `bit_depth` bits of resolution.
+pub fn quantize_sample(sample: i16, bit_depth: u32) -> i16 {
+    let shift = 16u32.saturating_sub(bit_depth.clamp(1, 16));
+    (sample >> shift) << shift
+}
+
+/// A lo-fi wrapper around any sample source: quantises amplitude and
+/// holds samples to drop the effective rate. The hold runs across
+/// interleaved channels, which collapses stereo detail — part of the
+/// intended grit.
+pub struct Bitcrusher<S> {
+    inner: S,
+    params: BitcrushParams,
+    held: i16,
+    hold_remaining: u32,
+}
+
+impl<S> Bitcrusher<S> {
+    pub fn new(inner: S, params: BitcrushParams) -> Self {
+        Self {
+            inner,
+            params,
+            held: 0,
+            hold_remaining: 0,
+        }
+    }
+}
+
+impl<S: Iterator<Item = i16>> Iterator for Bitcrusher<S> {
+    type Item = i16;
+
+    fn next(&mut self) -> Option<i16> {
+        if self.hold_remaining > 0 {
+            self.hold_remaining -= 1;
+            return Some(self.held);
+        }
+        let sample = quantize_sample(self.inner.next()?, self.params.bit_depth);
+        self.held = sample;
+        self.hold_remaining = self.params.sample_hold.saturating_sub(1);
+        Some(sample)
+    }
+}
+
+impl<S: Source<Item = i16>> Source for Bitcrusher<S> {
+    fn current_span_len(&self) -> Option<usize> {
+        self.inner.current_span_len()
+    }
+
+    fn channels(&self) -> u16 {
+        self.inner.channels()
+    }
+
+    fn sample_rate(&self) -> u32 {
+        self.inner.sample_rate()
+    }
+
+    fn total_duration(&self) -> Option<std::time::Duration> {
+        self.inner.total_duration()
+    }
+}
+
+/// A regular audio source played through the bitcrusher. Build one from
+/// a loaded [`AudioSource`] and play it with
+/// `AudioPlayer::<BitcrushedAudio>` like any other source; category
+/// tagging and [`super::BaseVolume`] apply unchanged, so the mixer
+/// treats crushed playback like clean playback.
+#[derive(Asset, TypePath, Clone)]
+pub struct BitcrushedAudio {
+    pub source: AudioSource,
+    pub params: BitcrushParams,
+}
+
+impl Decodable for BitcrushedAudio {
+    type DecoderItem = i16;
+    type Decoder = Bitcrusher<<AudioSource as Decodable>::Decoder>;
+
+    fn decoder(&self) -> Self::Decoder {
+        Bitcrusher::new(self.source.decoder(), self.params)
+    }
+}
+
+/// Whether special endings have engaged the bitcrusher. Systems that
+/// start ending audio consult this and route through
+/// [`BitcrushedAudio`] while it is on.
+#[derive(Resource, Debug, Clone, Copy, Default)]
+pub struct BitcrushToggle {
+    pub enabled: bool,
+    pub params: BitcrushParams,
+}
+
+impl BitcrushToggle {
+    pub fn engage(&mut self, params: BitcrushParams) {
+        self.enabled = true;
+        self.params = params;
+    }
+
+    pub fn disengage(&mut self) {
+        self.enabled = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quantisation_drops_low_bits_and_keeps_sign() {
+        let crushed = quantize_sample(12345, 8);
+        assert_eq!(crushed & 0xff, 0);
+        assert!(crushed > 0);
+        assert!(quantize_sample(-12345, 8) < 0);
+    }
+
+    #[test]
+    fn full_depth_is_a_pass_through() {
+        assert_eq!(quantize_sample(12345, 16), 12345);
+    }
+
+    #[test]
+    fn sample_hold_repeats_each_kept_sample() {
+        let samples = vec![100i16, 200, 300, 400];
+        let crushed: Vec<i16> = Bitcrusher::new(
+            samples.into_iter(),
+            BitcrushParams {
+                bit_depth: 16,
+                sample_hold: 2,
+            },
+        )
+        .collect();
+        assert_eq!(crushed, vec![100, 100, 300, 300]);
+    }
+}
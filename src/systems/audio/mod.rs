@@ -0,0 +1,301 @@
+pub mod bitcrush;
+pub mod music;
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use bevy::{
+    audio::{AddAudioSource, Volume},
+    prelude::*,
+};
+
+use crate::{
+    data::settings::{AudioChannel, AudioSettings},
+    ui::window::OffscreenCamera,
+};
+
+/// Which mixer bus a playing sound belongs to. Master is applied
+/// globally via [`GlobalVolume`]; the other categories pre-scale each
+/// player and let the mixer rescale live sinks when levels change.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AudioCategory {
+    Master,
+    Music,
+    Sfx,
+    Ui,
+}
+
+impl AudioCategory {
+    /// The persisted mixer channel controlling this category.
+    pub fn channel(self) -> AudioChannel {
+        match self {
+            AudioCategory::Master => AudioChannel::Master,
+            AudioCategory::Music => AudioChannel::Music,
+            AudioCategory::Sfx => AudioChannel::Sfx,
+            AudioCategory::Ui => AudioChannel::Ui,
+        }
+    }
+
+    /// Category scale to apply on top of a sound's own volume. Master
+    /// is excluded here because [`GlobalVolume`] already applies it.
+    pub fn scale(self, settings: &AudioSettings) -> f32 {
+        match self {
+            AudioCategory::Master => 1.0,
+            category => settings.volume(category.channel()),
+        }
+    }
+}
+
+/// A one-shot sound that can be fired repeatedly from a pallet.
+#[derive(Debug, Clone)]
+pub struct TransientAudio {
+    pub source: Handle<AudioSource>,
+    pub volume: f32,
+    /// World position of the emitter. Positioned sounds pan and
+    /// attenuate relative to the listener; `None` plays centred at full
+    /// level as before.
+    pub position: Option<Vec2>,
+    /// Playback rate; values other than 1.0 pitch-shift the sample.
+    pub speed: f32,
+}
+
+impl TransientAudio {
+    pub fn new(source: Handle<AudioSource>, volume: f32) -> Self {
+        Self {
+            source,
+            volume,
+            position: None,
+            speed: 1.0,
+        }
+    }
+
+    /// The same sound emitted from a world position, e.g. a train
+    /// approaching on the left track.
+    pub fn at(mut self, position: Vec2) -> Self {
+        self.position = Some(position);
+        self
+    }
+
+    /// The same sound pitched by a playback-rate multiplier.
+    pub fn with_speed(mut self, speed: f32) -> Self {
+        self.speed = speed;
+        self
+    }
+}
+
+/// Distance model for positioned one-shots. The listener sits on the
+/// [`crate::ui::window::OffscreenCamera`].
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct SpatialAudioConfig {
+    /// World distance beyond which a sound is inaudible.
+    pub max_distance: f32,
+    /// Curve exponent: 1 is linear falloff, higher hugs the emitter.
+    pub rolloff: f32,
+    /// World units to listener-space metres, controlling pan width.
+    pub spatial_scale: f32,
+}
+
+impl Default for SpatialAudioConfig {
+    fn default() -> Self {
+        Self {
+            max_distance: 1200.0,
+            rolloff: 1.5,
+            spatial_scale: 1.0 / 300.0,
+        }
+    }
+}
+
+impl SpatialAudioConfig {
+    /// Volume multiplier at `distance` from the listener.
+    pub fn attenuation(&self, distance: f32) -> f32 {
+        let normalised = (distance / self.max_distance).clamp(0.0, 1.0);
+        (1.0 - normalised).powf(self.rolloff.max(f32::EPSILON))
+    }
+}
+
+/// Pre-mix volume of a playing sound, kept so mixer changes can rescale
+/// live sinks without losing the sound's own level.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct BaseVolume(pub f32);
+
+/// Spawns a despawn-on-finish player for a transient sound, pre-scaled
+/// by its category's current level. Positioned sounds attenuate with
+/// the configured distance model and pan through the spatial listener
+/// on the camera; the scene's distances aren't acoustic metres, so the
+/// falloff is computed here rather than left to the ear model.
+pub fn play_transient_audio(
+    commands: &mut Commands,
+    audio: &TransientAudio,
+    category: AudioCategory,
+    settings: &AudioSettings,
+    spatial: &SpatialAudioConfig,
+) {
+    let mixed = audio.volume * category.scale(settings);
+    match audio.position {
+        None => {
+            commands.spawn((
+                AudioPlayer::new(audio.source.clone()),
+                PlaybackSettings::DESPAWN
+                    .with_volume(Volume::Linear(mixed))
+                    .with_speed(audio.speed),
+                BaseVolume(audio.volume),
+                category,
+            ));
+        }
+        Some(position) => {
+            let attenuated = mixed * spatial.attenuation(position.length());
+            commands.spawn((
+                AudioPlayer::new(audio.source.clone()),
+                PlaybackSettings::DESPAWN
+                    .with_volume(Volume::Linear(attenuated))
+                    .with_speed(audio.speed)
+                    .with_spatial(true),
+                BaseVolume(audio.volume),
+                category,
+                Transform::from_translation((position * spatial.spatial_scale).extend(0.0)),
+            ));
+        }
+    }
+}
+
+/// Components for looping background audio on the given bus. The
+/// category tag keeps the sink under mixer control after it starts.
+pub fn continuous_audio(
+    category: AudioCategory,
+    settings: &AudioSettings,
+) -> (PlaybackSettings, BaseVolume, AudioCategory) {
+    (
+        PlaybackSettings::LOOP.with_volume(Volume::Linear(category.scale(settings))),
+        BaseVolume(1.0),
+        category,
+    )
+}
+
+/// A keyed set of transient sounds owned by an entity or resource.
+#[derive(Component, Debug, Clone)]
+pub struct TransientAudioPallet<K: Eq + Hash + Send + Sync + 'static> {
+    category: AudioCategory,
+    sounds: HashMap<K, TransientAudio>,
+}
+
+impl<K: Eq + Hash + Send + Sync + 'static> TransientAudioPallet<K> {
+    pub fn new(
+        category: AudioCategory,
+        sounds: impl IntoIterator<Item = (K, TransientAudio)>,
+    ) -> Self {
+        Self {
+            category,
+            sounds: sounds.into_iter().collect(),
+        }
+    }
+
+    pub fn play_transient_audio(
+        &self,
+        commands: &mut Commands,
+        key: &K,
+        settings: &AudioSettings,
+    ) {
+        if let Some(audio) = self.sounds.get(key) {
+            play_transient_audio(
+                commands,
+                audio,
+                self.category,
+                settings,
+                &SpatialAudioConfig::default(),
+            );
+        }
+    }
+}
+
+/// Stock sounds for system-level menus and chrome.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SystemMenuSounds {
+    /// Focus/selection moved.
+    Switch,
+    /// An option was committed.
+    Select,
+    /// Navigated back / dismissed.
+    Back,
+    /// Something new was added to a collection.
+    Unlock,
+}
+
+/// Globally available pallet for the `SystemMenuSounds` set.
+#[derive(Resource, Debug, Clone)]
+pub struct SystemMenuAudio {
+    pub pallet: TransientAudioPallet<SystemMenuSounds>,
+}
+
+impl SystemMenuAudio {
+    pub fn play(&self, commands: &mut Commands, key: SystemMenuSounds, settings: &AudioSettings) {
+        self.pallet.play_transient_audio(commands, &key, settings);
+    }
+}
+
+fn load_system_menu_audio(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(SystemMenuAudio {
+        pallet: TransientAudioPallet::new(AudioCategory::Ui, [
+            (
+                SystemMenuSounds::Switch,
+                TransientAudio::new(asset_server.load("sounds/menu_switch.ogg"), 0.4),
+            ),
+            (
+                SystemMenuSounds::Select,
+                TransientAudio::new(asset_server.load("sounds/menu_select.ogg"), 0.5),
+            ),
+            (
+                SystemMenuSounds::Back,
+                TransientAudio::new(asset_server.load("sounds/menu_back.ogg"), 0.5),
+            ),
+            (
+                SystemMenuSounds::Unlock,
+                TransientAudio::new(asset_server.load("sounds/unlock_jingle.ogg"), 0.5),
+            ),
+        ]),
+    });
+}
+
+/// Positioned playback needs ears: the offscreen camera doubles as the
+/// spatial listener.
+fn attach_spatial_listener(
+    mut commands: Commands,
+    cameras: Query<Entity, Added<OffscreenCamera>>,
+) {
+    for camera in &cameras {
+        commands.entity(camera).insert(SpatialListener::default());
+    }
+}
+
+pub struct AudioSystemsPlugin;
+
+impl Plugin for AudioSystemsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(music::MusicPlugin)
+            .add_audio_source::<bitcrush::BitcrushedAudio>()
+            .init_resource::<bitcrush::BitcrushToggle>()
+            .init_resource::<SpatialAudioConfig>()
+            .add_systems(Startup, load_system_menu_audio)
+            .add_systems(Update, attach_spatial_listener);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn attenuation_is_full_at_the_listener_and_silent_at_range() {
+        let config = SpatialAudioConfig::default();
+        assert_eq!(config.attenuation(0.0), 1.0);
+        assert_eq!(config.attenuation(config.max_distance), 0.0);
+        assert_eq!(config.attenuation(config.max_distance * 2.0), 0.0);
+    }
+
+    #[test]
+    fn attenuation_falls_off_monotonically() {
+        let config = SpatialAudioConfig::default();
+        let near = config.attenuation(100.0);
+        let far = config.attenuation(800.0);
+        assert!(near > far);
+    }
+}
@@ -0,0 +1,174 @@
+use bevy::{audio::Volume, prelude::*};
+
+use crate::{
+    systems::{
+        audio::{continuous_audio, AudioCategory, BaseVolume},
+        time::Dilation,
+    },
+    ui::menu::audio::AudioSettingsState,
+};
+
+/// Looping track behind the pause menu. Routed through the director so
+/// resuming gameplay crossfades instead of popping.
+pub const PAUSE_MENU_MUSIC_PATH: &str = "music/pause_menu.ogg";
+
+pub const DEFAULT_MUSIC_FADE_SECS: f32 = 1.5;
+
+/// An in-flight volume ramp on a music player. Fade-outs despawn their
+/// player when they finish.
+#[derive(Component, Debug, Clone, Copy)]
+struct MusicFade {
+    from: f32,
+    to: f32,
+    elapsed_secs: f32,
+    duration_secs: f32,
+}
+
+impl MusicFade {
+    fn level(&self) -> f32 {
+        let t = if self.duration_secs <= 0.0 {
+            1.0
+        } else {
+            (self.elapsed_secs / self.duration_secs).clamp(0.0, 1.0)
+        };
+        self.from + (self.to - self.from) * t
+    }
+
+    fn finished(&self) -> bool {
+        self.elapsed_secs >= self.duration_secs
+    }
+}
+
+/// Owns the single continuous music track and crossfades between
+/// requests. Callers only name a track; spawning, fading and despawning
+/// happen in this module's systems.
+#[derive(Resource, Debug, Default)]
+pub struct MusicDirector {
+    current: Option<(String, Entity)>,
+    pending: Option<(String, f32)>,
+}
+
+impl MusicDirector {
+    /// Requests a crossfade to `track` over `fade_secs`. Requesting the
+    /// track already playing (or already queued) is a no-op, so scene
+    /// transitions can call this unconditionally.
+    pub fn play(&mut self, track: impl Into<String>, fade_secs: f32) {
+        let track = track.into();
+        let queued = self
+            .pending
+            .as_ref()
+            .map(|(path, _)| path.as_str())
+            .or_else(|| {
+                self.current
+                    .as_ref()
+                    .map(|(path, _)| path.as_str())
+            });
+        if queued == Some(track.as_str()) {
+            return;
+        }
+        self.pending = Some((track, fade_secs));
+    }
+}
+
+/// Starts each requested track at silence fading in, and puts the
+/// outgoing player on a matching fade-out.
+fn start_pending_tracks(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mixer: Res<AudioSettingsState>,
+    mut director: ResMut<MusicDirector>,
+) {
+    let Some((path, fade_secs)) = director.pending.take() else {
+        return;
+    };
+    if let Some((_, outgoing)) = director.current.take() {
+        if let Ok(mut entity) = commands.get_entity(outgoing) {
+            entity.insert(MusicFade {
+                from: 1.0,
+                to: 0.0,
+                elapsed_secs: 0.0,
+                duration_secs: fade_secs,
+            });
+        }
+    }
+    let (playback, base, category) = continuous_audio(AudioCategory::Music, &mixer.settings);
+    let entity = commands
+        .spawn((
+            AudioPlayer::new(asset_server.load(&path)),
+            // Fade-in starts silent; the ramp restores the mixed level.
+            playback.with_volume(Volume::Linear(0.0)),
+            base,
+            category,
+            MusicFade {
+                from: 0.0,
+                to: 1.0,
+                elapsed_secs: 0.0,
+                duration_secs: fade_secs,
+            },
+        ))
+        .id();
+    director.current = Some((path, entity));
+}
+
+/// Advances fades in dilated time, keeping each ramp on top of the
+/// mixer's current music level. Completed fade-outs despawn their
+/// player; completed fade-ins shed the ramp and return to mixer control.
+fn advance_music_fades(
+    mut commands: Commands,
+    time: Res<Time>,
+    dilation: Res<Dilation>,
+    mixer: Res<AudioSettingsState>,
+    mut fades: Query<(Entity, &mut MusicFade, &BaseVolume, &AudioCategory, &mut AudioSink)>,
+) {
+    let delta = dilation.scale(time.delta_secs());
+    for (entity, mut fade, base, category, mut sink) in &mut fades {
+        fade.elapsed_secs += delta;
+        let mixed = base.0 * category.scale(&mixer.settings);
+        sink.set_volume(Volume::Linear(mixed * fade.level()));
+        if fade.finished() {
+            if fade.to == 0.0 {
+                commands.entity(entity).despawn();
+            } else {
+                commands.entity(entity).remove::<MusicFade>();
+            }
+        }
+    }
+}
+
+pub struct MusicPlugin;
+
+impl Plugin for MusicPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<MusicDirector>()
+            .add_systems(Update, (start_pending_tracks, advance_music_fades).chain());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn requesting_the_queued_track_again_is_a_no_op() {
+        let mut director = MusicDirector::default();
+        director.play(PAUSE_MENU_MUSIC_PATH, 1.0);
+        director.play(PAUSE_MENU_MUSIC_PATH, 5.0);
+        let (_, fade_secs) = director.pending.clone().unwrap();
+        assert_eq!(fade_secs, 1.0);
+    }
+
+    #[test]
+    fn fade_level_ramps_and_clamps() {
+        let mut fade = MusicFade {
+            from: 0.0,
+            to: 1.0,
+            elapsed_secs: 0.0,
+            duration_secs: 2.0,
+        };
+        fade.elapsed_secs = 1.0;
+        assert_eq!(fade.level(), 0.5);
+        fade.elapsed_secs = 5.0;
+        assert_eq!(fade.level(), 1.0);
+        assert!(fade.finished());
+    }
+}
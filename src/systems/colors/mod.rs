@@ -0,0 +1,132 @@
+use bevy::prelude::*;
+
+use crate::data::settings::{ColorblindMode, UserSettings};
+
+/// Terminal-green primary used for almost all body text and chrome.
+pub const PRIMARY_COLOR: Color = Color::srgb(0.0, 0.85, 0.25);
+/// Brighter variant for highlighted/selected elements.
+pub const HIGHLIGHT_COLOR: Color = Color::srgb(0.55, 1.0, 0.55);
+/// Dimmed variant for de-emphasised chrome (unfocused windows, separators).
+pub const DIM_COLOR: Color = Color::srgb(0.0, 0.45, 0.15);
+/// Cyan accent used by system-level menus (pause, options, debug).
+pub const SYSTEM_MENU_COLOR: Color = Color::srgb(0.2, 0.85, 0.9);
+/// Red for casualties, destructive actions and warnings that matter.
+pub const DANGER_COLOR: Color = Color::srgb(0.9, 0.15, 0.1);
+/// Amber for recoverable warnings.
+pub const WARNING_COLOR: Color = Color::srgb(0.95, 0.75, 0.1);
+/// Near-black backdrop behind everything.
+pub const BACKGROUND_COLOR: Color = Color::srgb(0.01, 0.03, 0.01);
+/// Window body fill, slightly lifted from the backdrop.
+pub const WINDOW_BODY_COLOR: Color = Color::srgb(0.02, 0.07, 0.03);
+
+/// The live palette. The `const`s above stay the base (no remap)
+/// values; systems that signal meaning through colour — above all the
+/// red/green casualty outcomes — should read the matching accessor here
+/// so colorblind modes actually reach them.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ColorScheme {
+    pub mode: ColorblindMode,
+}
+
+impl Default for ColorScheme {
+    fn default() -> Self {
+        Self {
+            mode: UserSettings::load().interface.colorblind_mode,
+        }
+    }
+}
+
+impl ColorScheme {
+    /// The good/safe colour: green normally, blue under the red-green
+    /// modes where green is the colour being lost.
+    pub fn primary(&self) -> Color {
+        match self.mode {
+            ColorblindMode::None | ColorblindMode::Tritanopia => PRIMARY_COLOR,
+            ColorblindMode::Protanopia | ColorblindMode::Deuteranopia => {
+                Color::srgb(0.15, 0.55, 1.0)
+            }
+        }
+    }
+
+    /// Brighter variant of [`Self::primary`] for selection.
+    pub fn highlight(&self) -> Color {
+        match self.mode {
+            ColorblindMode::None | ColorblindMode::Tritanopia => HIGHLIGHT_COLOR,
+            ColorblindMode::Protanopia | ColorblindMode::Deuteranopia => {
+                Color::srgb(0.6, 0.8, 1.0)
+            }
+        }
+    }
+
+    /// Dimmed variant of [`Self::primary`] for de-emphasised chrome.
+    pub fn dim(&self) -> Color {
+        match self.mode {
+            ColorblindMode::None | ColorblindMode::Tritanopia => DIM_COLOR,
+            ColorblindMode::Protanopia | ColorblindMode::Deuteranopia => {
+                Color::srgb(0.1, 0.3, 0.55)
+            }
+        }
+    }
+
+    /// System-menu accent. The base cyan sits too close to the remapped
+    /// blue primary, so the red-green modes shift it towards white; the
+    /// tritan mode trades it for magenta, clear of the blue axis.
+    pub fn system_menu(&self) -> Color {
+        match self.mode {
+            ColorblindMode::None => SYSTEM_MENU_COLOR,
+            ColorblindMode::Protanopia | ColorblindMode::Deuteranopia => {
+                Color::srgb(0.85, 0.9, 0.95)
+            }
+            ColorblindMode::Tritanopia => Color::srgb(0.9, 0.35, 0.9),
+        }
+    }
+
+    /// The bad/destructive colour: pure red reads as near-black to a
+    /// protanope, so the red-green modes brighten it towards orange.
+    pub fn danger(&self) -> Color {
+        match self.mode {
+            ColorblindMode::None | ColorblindMode::Tritanopia => DANGER_COLOR,
+            ColorblindMode::Protanopia | ColorblindMode::Deuteranopia => {
+                Color::srgb(1.0, 0.55, 0.05)
+            }
+        }
+    }
+
+    /// Recoverable-warning colour, kept off the danger hue per mode.
+    pub fn warning(&self) -> Color {
+        match self.mode {
+            ColorblindMode::None => WARNING_COLOR,
+            ColorblindMode::Protanopia | ColorblindMode::Deuteranopia => {
+                Color::srgb(0.95, 0.9, 0.3)
+            }
+            ColorblindMode::Tritanopia => Color::srgb(1.0, 0.55, 0.65),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_base_mode_matches_the_constants() {
+        let scheme = ColorScheme {
+            mode: ColorblindMode::None,
+        };
+        assert_eq!(scheme.primary(), PRIMARY_COLOR);
+        assert_eq!(scheme.danger(), DANGER_COLOR);
+        assert_eq!(scheme.warning(), WARNING_COLOR);
+    }
+
+    #[test]
+    fn good_and_bad_stay_distinct_in_every_mode() {
+        // The whole point of the remaps: the outcome pair must never
+        // collapse, and warnings must not impersonate danger.
+        for mode in ColorblindMode::ALL {
+            let scheme = ColorScheme { mode };
+            assert_ne!(scheme.primary(), scheme.danger(), "{mode:?}");
+            assert_ne!(scheme.warning(), scheme.danger(), "{mode:?}");
+            assert_ne!(scheme.system_menu(), scheme.primary(), "{mode:?}");
+        }
+    }
+}
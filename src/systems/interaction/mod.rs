@@ -0,0 +1,521 @@
+use bevy::prelude::*;
+
+use crate::data::states::MainState;
+
+/// World-space cursor position, updated once per frame before any
+/// interaction system runs.
+#[derive(Resource, Debug, Default, Clone, Copy)]
+pub struct CustomCursor {
+    pub position: Vec2,
+}
+
+/// Who currently owns pointer/keyboard interaction, and whether a text
+/// field has captured typing. `focused_owner` is always a root entity
+/// (usually a window root).
+#[derive(Resource, Debug, Default)]
+pub struct UiInteractionState {
+    pub focused_owner: Option<Entity>,
+    pub text_input_focus: Option<Entity>,
+}
+
+/// Returns whether `owner` currently holds interaction focus.
+pub fn scoped_owner_has_focus(state: &UiInteractionState, owner: Entity) -> bool {
+    state.focused_owner == Some(owner)
+}
+
+/// Controls when an entity responds to input. `Global` entities always
+/// respond; `ScopedTo` entities only respond while their owner root holds
+/// interaction focus.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub enum UiInputPolicy {
+    #[default]
+    Global,
+    ScopedTo(Entity),
+}
+
+impl UiInputPolicy {
+    pub fn allows(&self, state: &UiInteractionState) -> bool {
+        match self {
+            UiInputPolicy::Global => true,
+            UiInputPolicy::ScopedTo(owner) => scoped_owner_has_focus(state, *owner),
+        }
+    }
+}
+
+/// Deactivates an interactive entity in place: its `Clickable` never
+/// triggers and hover never reports, while the entity stays visible so
+/// menus can gray options out rather than despawn them.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct Disabled;
+
+/// Colours an interactive entity swaps between as its state changes.
+/// Systems without a palette fall back to their own defaults.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct InteractionVisualPalette {
+    pub normal: Color,
+    pub highlighted: Color,
+    pub disabled: Color,
+}
+
+/// Axis-aligned hit region centred on the entity's global translation.
+fn cursor_in_region(cursor: Vec2, centre: Vec2, dimensions: Vec2, offset: Vec2) -> bool {
+    let centre = centre + offset;
+    let half = dimensions * 0.5;
+    (cursor.x - centre.x).abs() <= half.x && (cursor.y - centre.y).abs() <= half.y
+}
+
+/// Click hit detection. Each flag is true for exactly the frame its
+/// button's press lands inside the region; `triggered` stays the
+/// left-button flag so existing handlers keep their meaning, with
+/// `right_triggered` alongside for context actions.
+#[derive(Component, Debug, Clone)]
+pub struct Clickable {
+    pub region: Vec2,
+    pub triggered: bool,
+    pub right_triggered: bool,
+}
+
+impl Clickable {
+    pub fn new(region: Vec2) -> Self {
+        Self {
+            region,
+            triggered: false,
+            right_triggered: false,
+        }
+    }
+}
+
+/// Default time window in which two clicks count as a double click.
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct DoubleClickConfig {
+    pub window_secs: f32,
+}
+
+impl Default for DoubleClickConfig {
+    fn default() -> Self {
+        Self { window_secs: 0.35 }
+    }
+}
+
+/// Double-click tracking layered over `Clickable`. `double_triggered` is
+/// true for exactly the frame a second activation lands within the
+/// window; the pending first click is forgotten when the cursor leaves
+/// the region.
+#[derive(Component, Debug, Clone, Default)]
+pub struct DoubleClickable {
+    /// Overrides [`DoubleClickConfig`] when set.
+    pub window_secs: Option<f32>,
+    pub double_triggered: bool,
+    /// Seconds since the pending first click, if one is armed.
+    since_first_click: Option<f32>,
+}
+
+impl DoubleClickable {
+    /// Records an activation; true when it completes a double click.
+    pub fn register_click(&mut self, window_secs: f32) -> bool {
+        if self.since_first_click.is_some_and(|since| since <= window_secs) {
+            self.since_first_click = None;
+            true
+        } else {
+            self.since_first_click = Some(0.0);
+            false
+        }
+    }
+
+    pub fn advance(&mut self, delta_secs: f32) {
+        if let Some(since) = self.since_first_click.as_mut() {
+            *since += delta_secs;
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.since_first_click = None;
+    }
+}
+
+/// Hover state, refreshed every frame from the cursor position.
+#[derive(Component, Debug, Clone)]
+pub struct Hoverable {
+    pub region: Vec2,
+    pub hovered: bool,
+    /// Seconds the cursor has been continuously inside the region.
+    pub hovered_secs: f32,
+}
+
+impl Hoverable {
+    pub fn new(region: Vec2) -> Self {
+        Self {
+            region,
+            hovered: false,
+            hovered_secs: 0.0,
+        }
+    }
+}
+
+/// Region (relative to the entity) in which a drag may begin. Windows use
+/// this for their header bar; the region is kept in sync with the window
+/// dimensions by `sync_root_drag_bounds`.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct DraggableRegion {
+    pub dimensions: Vec2,
+    pub offset: Vec2,
+}
+
+/// Drag state. While `grab_offset` is `Some`, the entity follows the
+/// cursor, preserving the grab point.
+#[derive(Component, Debug, Default, Clone, Copy)]
+pub struct Draggable {
+    pub grab_offset: Option<Vec2>,
+}
+
+impl Draggable {
+    pub fn dragging(&self) -> bool {
+        self.grab_offset.is_some()
+    }
+}
+
+/// Declarative side effects fired when a `Clickable` triggers.
+#[derive(Debug, Clone)]
+pub enum InputAction {
+    Despawn(Entity),
+    ChangeMainState(MainState),
+}
+
+/// Set by [`InputAction::Despawn`] instead of despawning outright, so
+/// exit animations (window fade-outs) can intercept the entity before
+/// [`finalize_requested_despawns`] removes it at the end of the frame.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct DespawnRequested;
+
+/// Despawns whatever is still marked once the frame's interceptors have
+/// had their say. Runs in `PostUpdate` so every `Update` system sees
+/// the request first.
+pub fn finalize_requested_despawns(
+    mut commands: Commands,
+    requested: Query<Entity, With<DespawnRequested>>,
+) {
+    for entity in &requested {
+        commands.entity(entity).despawn();
+    }
+}
+
+/// Actions attached to a clickable entity, executed in order on trigger.
+#[derive(Component, Debug, Clone, Default)]
+pub struct ActionPallet(pub Vec<InputAction>);
+
+/// Actions executed when the entity is right-clicked instead.
+#[derive(Component, Debug, Clone, Default)]
+pub struct RightClickActions(pub Vec<InputAction>);
+
+/// Delay-then-repeat timer for held inputs, shared by menu navigation and
+/// window keyboard nudging so everything repeats at the same cadence.
+#[derive(Debug, Clone)]
+pub struct RepeatTimer {
+    pub delay: f32,
+    pub interval: f32,
+    elapsed: f32,
+    repeating: bool,
+}
+
+pub const NAV_REPEAT_DELAY: f32 = 0.35;
+pub const NAV_REPEAT_INTERVAL: f32 = 0.06;
+
+impl RepeatTimer {
+    pub fn new(delay: f32, interval: f32) -> Self {
+        Self {
+            delay,
+            interval,
+            elapsed: 0.0,
+            repeating: false,
+        }
+    }
+
+    /// Standard menu-navigation cadence.
+    pub fn menu_nav() -> Self {
+        Self::new(NAV_REPEAT_DELAY, NAV_REPEAT_INTERVAL)
+    }
+
+    /// Advances the timer while the input is held. Returns true on the
+    /// initial press and on every repeat thereafter.
+    pub fn tick(&mut self, held: bool, just_pressed: bool, delta_secs: f32) -> bool {
+        if just_pressed {
+            self.elapsed = 0.0;
+            self.repeating = false;
+            return true;
+        }
+        if !held {
+            self.elapsed = 0.0;
+            self.repeating = false;
+            return false;
+        }
+        self.elapsed += delta_secs;
+        let threshold = if self.repeating {
+            self.interval
+        } else {
+            self.delay
+        };
+        if self.elapsed >= threshold {
+            self.elapsed -= threshold;
+            self.repeating = true;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+fn update_custom_cursor(
+    mut cursor: ResMut<CustomCursor>,
+    windows: Query<&bevy::window::Window, With<bevy::window::PrimaryWindow>>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+) {
+    let Ok(window) = windows.single() else {
+        return;
+    };
+    let Some(screen) = window.cursor_position() else {
+        return;
+    };
+    for (camera, transform) in &cameras {
+        if let Ok(world) = camera.viewport_to_world_2d(transform, screen) {
+            cursor.position = world;
+            return;
+        }
+    }
+}
+
+fn update_hoverables(
+    time: Res<Time>,
+    cursor: Res<CustomCursor>,
+    state: Res<UiInteractionState>,
+    mut hoverables: Query<(
+        &mut Hoverable,
+        &GlobalTransform,
+        Option<&UiInputPolicy>,
+        Has<Disabled>,
+    )>,
+) {
+    for (mut hoverable, transform, policy, disabled) in &mut hoverables {
+        let allowed = !disabled && policy.is_none_or(|p| p.allows(&state));
+        let inside = allowed
+            && cursor_in_region(
+                cursor.position,
+                transform.translation().truncate(),
+                hoverable.region,
+                Vec2::ZERO,
+            );
+        if inside {
+            hoverable.hovered_secs += time.delta_secs();
+        } else {
+            hoverable.hovered_secs = 0.0;
+        }
+        hoverable.hovered = inside;
+    }
+}
+
+fn update_clickables(
+    buttons: Res<ButtonInput<MouseButton>>,
+    cursor: Res<CustomCursor>,
+    state: Res<UiInteractionState>,
+    mut clickables: Query<(
+        &mut Clickable,
+        &GlobalTransform,
+        Option<&UiInputPolicy>,
+        Has<Disabled>,
+    )>,
+) {
+    let pressed = buttons.just_pressed(MouseButton::Left);
+    let right_pressed = buttons.just_pressed(MouseButton::Right);
+    for (mut clickable, transform, policy, disabled) in &mut clickables {
+        let allowed = !disabled && policy.is_none_or(|p| p.allows(&state));
+        let inside = allowed
+            && cursor_in_region(
+                cursor.position,
+                transform.translation().truncate(),
+                clickable.region,
+                Vec2::ZERO,
+            );
+        clickable.triggered = pressed && inside;
+        clickable.right_triggered = right_pressed && inside;
+    }
+}
+
+fn update_double_clicks(
+    time: Res<Time>,
+    cursor: Res<CustomCursor>,
+    config: Res<DoubleClickConfig>,
+    mut double_clickables: Query<(&mut DoubleClickable, &Clickable, &GlobalTransform)>,
+) {
+    for (mut double, clickable, transform) in &mut double_clickables {
+        double.double_triggered = false;
+        let inside = cursor_in_region(
+            cursor.position,
+            transform.translation().truncate(),
+            clickable.region,
+            Vec2::ZERO,
+        );
+        if !inside {
+            double.reset();
+            continue;
+        }
+        double.advance(time.delta_secs());
+        if clickable.triggered {
+            let window = double.window_secs.unwrap_or(config.window_secs);
+            let completed = double.register_click(window);
+            double.double_triggered = completed;
+        }
+    }
+}
+
+fn update_draggables(
+    buttons: Res<ButtonInput<MouseButton>>,
+    cursor: Res<CustomCursor>,
+    mut draggables: Query<(&mut Draggable, &DraggableRegion, &mut Transform, &GlobalTransform)>,
+) {
+    for (mut draggable, region, mut transform, global) in &mut draggables {
+        if buttons.just_pressed(MouseButton::Left) {
+            let centre = global.translation().truncate();
+            if cursor_in_region(cursor.position, centre, region.dimensions, region.offset) {
+                draggable.grab_offset = Some(centre - cursor.position);
+            }
+        }
+        if !buttons.pressed(MouseButton::Left) {
+            draggable.grab_offset = None;
+        }
+        if let Some(offset) = draggable.grab_offset {
+            let target = cursor.position + offset;
+            transform.translation.x = target.x;
+            transform.translation.y = target.y;
+        }
+    }
+}
+
+/// Executes a slice of actions the same way a triggered `Clickable`
+/// would, so keyboard paths can share the pointer paths' side effects.
+pub fn run_input_actions(
+    commands: &mut Commands,
+    next_main: &mut NextState<MainState>,
+    actions: &[InputAction],
+) {
+    for action in actions {
+        match action {
+            InputAction::Despawn(entity) => {
+                commands.entity(*entity).insert(DespawnRequested);
+            }
+            InputAction::ChangeMainState(state) => next_main.set(*state),
+        }
+    }
+}
+
+fn execute_action_pallets(
+    mut commands: Commands,
+    mut next_main: ResMut<NextState<MainState>>,
+    clickables: Query<(&Clickable, Option<&ActionPallet>, Option<&RightClickActions>)>,
+) {
+    for (clickable, pallet, right_actions) in &clickables {
+        if clickable.triggered {
+            if let Some(pallet) = pallet {
+                run_input_actions(&mut commands, &mut next_main, &pallet.0);
+            }
+        }
+        if clickable.right_triggered {
+            if let Some(actions) = right_actions {
+                run_input_actions(&mut commands, &mut next_main, &actions.0);
+            }
+        }
+    }
+}
+
+/// Ordering anchors for interaction processing within `Update`.
+#[derive(SystemSet, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InteractionSystem {
+    /// Cursor + raw hit state refresh.
+    Sense,
+    /// Anything consuming `Clickable::triggered` etc.
+    React,
+}
+
+/// One-frame flags set by a system that swallows a key press, so later
+/// coarser handlers skip it — e.g. Escape closing an open dropdown must
+/// not also close the menu behind it. Cleared every frame before the
+/// `Sense` set; consumers set flags in `Sense`, observers check them in
+/// `React`.
+#[derive(Resource, Debug, Default, Clone, Copy)]
+pub struct ConsumedInputs {
+    pub escape: bool,
+}
+
+fn clear_consumed_inputs(mut consumed: ResMut<ConsumedInputs>) {
+    *consumed = ConsumedInputs::default();
+}
+
+pub struct InteractionPlugin;
+
+impl Plugin for InteractionPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CustomCursor>()
+            .init_resource::<UiInteractionState>()
+            .init_resource::<DoubleClickConfig>()
+            .init_resource::<ConsumedInputs>()
+            .add_systems(Update, clear_consumed_inputs.before(InteractionSystem::Sense))
+            .configure_sets(
+                Update,
+                (InteractionSystem::Sense, InteractionSystem::React).chain(),
+            )
+            .add_systems(
+                Update,
+                (
+                    update_custom_cursor,
+                    update_hoverables,
+                    update_clickables,
+                    update_double_clicks,
+                    update_draggables,
+                )
+                    .chain()
+                    .in_set(InteractionSystem::Sense),
+            )
+            .add_systems(
+                Update,
+                execute_action_pallets.in_set(InteractionSystem::React),
+            )
+            .add_systems(PostUpdate, finalize_requested_despawns);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn repeat_timer_fires_on_press_then_after_delay() {
+        let mut timer = RepeatTimer::new(0.3, 0.1);
+        assert!(timer.tick(true, true, 0.016));
+        assert!(!timer.tick(true, false, 0.1));
+        assert!(!timer.tick(true, false, 0.1));
+        assert!(timer.tick(true, false, 0.15));
+        // Now repeating at the faster interval.
+        assert!(timer.tick(true, false, 0.1));
+    }
+
+    #[test]
+    fn repeat_timer_resets_on_release() {
+        let mut timer = RepeatTimer::new(0.3, 0.1);
+        assert!(timer.tick(true, true, 0.016));
+        timer.tick(true, false, 0.2);
+        assert!(!timer.tick(false, false, 0.016));
+        // A fresh press starts the delay again.
+        assert!(timer.tick(true, true, 0.016));
+        assert!(!timer.tick(true, false, 0.2));
+    }
+
+    #[test]
+    fn double_click_requires_two_clicks_within_the_window() {
+        let mut double = DoubleClickable::default();
+        assert!(!double.register_click(0.35));
+        double.advance(0.2);
+        assert!(double.register_click(0.35));
+        // The pair is consumed: a third click starts over.
+        assert!(!double.register_click(0.35));
+        double.advance(0.5);
+        assert!(!double.register_click(0.35));
+    }
+}
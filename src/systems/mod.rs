@@ -0,0 +1,6 @@
+pub mod achievements;
+pub mod audio;
+pub mod colors;
+pub mod interaction;
+pub mod scheduling;
+pub mod time;
@@ -0,0 +1,37 @@
+use bevy::prelude::*;
+
+/// Despawns the entity after a real-time delay. Used by toasts and other
+/// transient chrome that should not depend on gameplay dilation.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct AutoDespawn {
+    pub remaining_secs: f32,
+}
+
+impl AutoDespawn {
+    pub fn after(secs: f32) -> Self {
+        Self {
+            remaining_secs: secs,
+        }
+    }
+}
+
+fn tick_auto_despawn(
+    mut commands: Commands,
+    time: Res<Time<Real>>,
+    mut entities: Query<(Entity, &mut AutoDespawn)>,
+) {
+    for (entity, mut auto) in &mut entities {
+        auto.remaining_secs -= time.delta_secs();
+        if auto.remaining_secs <= 0.0 {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+pub struct SchedulingPlugin;
+
+impl Plugin for SchedulingPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, tick_auto_despawn);
+    }
+}
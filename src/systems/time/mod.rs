@@ -0,0 +1,157 @@
+use std::time::{Duration, Instant};
+
+use bevy::prelude::*;
+
+use crate::data::settings::{FrameLimit, UserSettings};
+
+/// Global gameplay time dilation. UI systems generally ignore this and use
+/// real time; anything happening "in the simulation" should scale by it.
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct Dilation(pub f32);
+
+impl Default for Dilation {
+    fn default() -> Self {
+        Self(1.0)
+    }
+}
+
+impl Dilation {
+    /// Scales a frame delta by the current dilation factor.
+    pub fn scale(&self, delta_secs: f32) -> f32 {
+        delta_secs * self.0
+    }
+}
+
+/// In-world wall clock driving the day/night cycle. `time_of_day` is a
+/// fraction of a day in `[0, 1)` with 0 at midnight, so readers (menu
+/// tint, music selection, dilemma mood) can derive whatever they need.
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct GameClock {
+    pub time_of_day: f32,
+    /// Real seconds per full in-world day.
+    pub cycle_secs: f32,
+    /// When false the clock holds still, e.g. for authored moods.
+    pub auto_advance: bool,
+}
+
+impl Default for GameClock {
+    fn default() -> Self {
+        Self {
+            // Start mid-morning so first launch reads as daytime.
+            time_of_day: 0.4,
+            cycle_secs: 600.0,
+            auto_advance: true,
+        }
+    }
+}
+
+impl GameClock {
+    /// Advances by real elapsed time, wrapping at midnight.
+    pub fn advance(&mut self, delta_secs: f32) {
+        if !self.auto_advance || self.cycle_secs <= 0.0 {
+            return;
+        }
+        self.time_of_day = (self.time_of_day + delta_secs / self.cycle_secs).rem_euclid(1.0);
+    }
+
+    /// How bright it is: 0 at midnight, 1 at noon.
+    pub fn daylight(&self) -> f32 {
+        0.5 - 0.5 * (std::f32::consts::TAU * self.time_of_day).cos()
+    }
+
+    /// The clock as "HH:MM", 24-hour.
+    pub fn formatted(&self) -> String {
+        let minutes_of_day = (self.time_of_day.rem_euclid(1.0) * 24.0 * 60.0) as u32;
+        format!("{:02}:{:02}", minutes_of_day / 60, minutes_of_day % 60)
+    }
+}
+
+fn advance_game_clock(time: Res<Time<Real>>, mut clock: ResMut<GameClock>) {
+    clock.advance(time.delta_secs());
+}
+
+/// Optional FPS cap, honoured by sleeping out the remainder of each
+/// frame's budget at the very end of the schedule. Vsync still applies
+/// on top of this when enabled.
+#[derive(Resource, Debug)]
+pub struct FrameLimiter {
+    pub limit: FrameLimit,
+    last_frame: Option<Instant>,
+}
+
+impl Default for FrameLimiter {
+    fn default() -> Self {
+        Self {
+            limit: UserSettings::load().video.frame_limit,
+            last_frame: None,
+        }
+    }
+}
+
+/// Sleeps until the current frame has used its full budget, when a cap
+/// is set. Runs in `Last` so the sleep covers whatever time the frame's
+/// own work left over.
+fn limit_frame_rate(mut limiter: ResMut<FrameLimiter>) {
+    let now = Instant::now();
+    if let (Some(last), Some(fps)) = (limiter.last_frame, limiter.limit.max_fps()) {
+        let budget = Duration::from_secs_f64(1.0 / fps as f64);
+        let elapsed = now.duration_since(last);
+        if elapsed < budget {
+            std::thread::sleep(budget - elapsed);
+        }
+    }
+    limiter.last_frame = Some(Instant::now());
+}
+
+pub struct TimePlugin;
+
+impl Plugin for TimePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Dilation>()
+            .init_resource::<FrameLimiter>()
+            .init_resource::<GameClock>()
+            .add_systems(Update, advance_game_clock)
+            .add_systems(Last, limit_frame_rate);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clock_wraps_at_midnight() {
+        let mut clock = GameClock {
+            time_of_day: 0.9,
+            cycle_secs: 100.0,
+            auto_advance: true,
+        };
+        clock.advance(20.0);
+        assert!((clock.time_of_day - 0.1).abs() < 1e-5);
+    }
+
+    #[test]
+    fn frozen_clock_holds_still() {
+        let mut clock = GameClock {
+            auto_advance: false,
+            ..default()
+        };
+        let before = clock.time_of_day;
+        clock.advance(100.0);
+        assert_eq!(clock.time_of_day, before);
+    }
+
+    #[test]
+    fn formats_as_24_hour_time() {
+        let clock = GameClock {
+            time_of_day: 0.5,
+            ..default()
+        };
+        assert_eq!(clock.formatted(), "12:00");
+        let midnight = GameClock {
+            time_of_day: 0.0,
+            ..default()
+        };
+        assert_eq!(midnight.formatted(), "00:00");
+    }
+}
@@ -0,0 +1,190 @@
+use bevy::prelude::*;
+
+use crate::{
+    systems::{
+        colors::{HIGHLIGHT_COLOR, PRIMARY_COLOR},
+        interaction::{
+            ActionPallet, Clickable, CustomCursor, Hoverable, InputAction, InteractionSystem,
+        },
+    },
+    ui::window::{
+        clamp_to_viewport, viewport_world_bounds, OffscreenCamera, Window, WindowBoundary,
+        WindowContent,
+    },
+};
+
+pub const CONTEXT_MENU_ENTRY_HEIGHT: f32 = 20.0;
+pub const CONTEXT_MENU_FONT_SIZE: f32 = 12.0;
+pub const CONTEXT_MENU_WIDTH: f32 = 140.0;
+pub const CONTEXT_MENU_Z: f32 = 650.0;
+
+/// One line of a context menu, with the actions a click fires.
+#[derive(Debug, Clone)]
+pub struct ContextMenuEntry {
+    pub label: String,
+    pub actions: Vec<InputAction>,
+}
+
+impl ContextMenuEntry {
+    pub fn new(label: impl Into<String>, actions: Vec<InputAction>) -> Self {
+        Self {
+            label: label.into(),
+            actions,
+        }
+    }
+}
+
+/// Root marker of an open context menu. At most one is open at a time;
+/// it closes on Escape, on any press outside it, or after an entry
+/// fires.
+#[derive(Component, Debug)]
+pub struct ContextMenu {
+    pub size: Vec2,
+}
+
+#[derive(Component)]
+struct ContextMenuItem {
+    menu: Entity,
+}
+
+/// Spawns a headerless window at `position` listing `entries`, clamped
+/// into the viewport; returns the menu root. Any context menu already
+/// open should be closed by the caller first (or left to the
+/// outside-click rule, since opening implies a press elsewhere).
+pub fn spawn_context_menu(
+    commands: &mut Commands,
+    position: Vec2,
+    entries: &[ContextMenuEntry],
+    bounds: Option<Rect>,
+) -> Entity {
+    let size = Vec2::new(
+        CONTEXT_MENU_WIDTH,
+        entries.len().max(1) as f32 * CONTEXT_MENU_ENTRY_HEIGHT,
+    );
+    let mut translation = (position + size * Vec2::new(0.5, -0.5)).extend(CONTEXT_MENU_Z);
+    if let Some(bounds) = bounds {
+        clamp_to_viewport(&mut translation, size, bounds);
+    }
+    let root = commands
+        .spawn((
+            ContextMenu { size },
+            Window {
+                boundary: WindowBoundary { dimensions: size },
+                header_height: 0.0,
+                has_close_button: false,
+                resizable: false,
+                ..default()
+            },
+            Transform::from_translation(translation),
+        ))
+        .id();
+    for (index, entry) in entries.iter().enumerate() {
+        commands.spawn((
+            ContextMenuItem { menu: root },
+            WindowContent::new(root),
+            ActionPallet(entry.actions.clone()),
+            Text2d::new(entry.label.clone()),
+            TextFont::from_font_size(CONTEXT_MENU_FONT_SIZE),
+            TextColor(PRIMARY_COLOR),
+            Clickable::new(Vec2::new(CONTEXT_MENU_WIDTH, CONTEXT_MENU_ENTRY_HEIGHT)),
+            Hoverable::new(Vec2::new(CONTEXT_MENU_WIDTH, CONTEXT_MENU_ENTRY_HEIGHT)),
+            Transform::from_xyz(
+                0.0,
+                size.y * 0.5 - (index as f32 + 0.5) * CONTEXT_MENU_ENTRY_HEIGHT,
+                0.5,
+            ),
+            Visibility::Inherited,
+        ));
+    }
+    root
+}
+
+/// Highlights the hovered entry.
+fn sync_context_menu_entry_visuals(mut items: Query<(&Hoverable, &mut TextColor), With<ContextMenuItem>>) {
+    for (hoverable, mut color) in &mut items {
+        let wanted = if hoverable.hovered {
+            HIGHLIGHT_COLOR
+        } else {
+            PRIMARY_COLOR
+        };
+        if color.0 != wanted {
+            color.0 = wanted;
+        }
+    }
+}
+
+/// Closes menus on Escape, on a press landing outside the menu rect, or
+/// once one of their entries has fired. Same dismissal pattern as the
+/// dropdown lists.
+fn close_context_menus(
+    mut commands: Commands,
+    keys: Res<ButtonInput<KeyCode>>,
+    buttons: Res<ButtonInput<MouseButton>>,
+    cursor: Res<CustomCursor>,
+    menus: Query<(Entity, &ContextMenu, &GlobalTransform)>,
+    items: Query<(&ContextMenuItem, &Clickable)>,
+) {
+    let escape = keys.just_pressed(KeyCode::Escape);
+    let pressed =
+        buttons.just_pressed(MouseButton::Left) || buttons.just_pressed(MouseButton::Right);
+    for (entity, menu, transform) in &menus {
+        let fired = items
+            .iter()
+            .any(|(item, clickable)| item.menu == entity && clickable.triggered);
+        let outside = pressed && {
+            let extent =
+                Rect::from_center_size(transform.translation().truncate(), menu.size);
+            !extent.contains(cursor.position)
+        };
+        if escape || outside || fired {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+/// Despawns orphaned entries once their menu root is gone.
+fn clear_dead_context_menu_items(
+    mut commands: Commands,
+    items: Query<(Entity, &ContextMenuItem)>,
+    menus: Query<(), With<ContextMenu>>,
+) {
+    for (entity, item) in &items {
+        if menus.get(item.menu).is_err() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+/// Convenience: opens a context menu at the current cursor position,
+/// clamped to the offscreen camera's viewport.
+pub fn open_context_menu_at_cursor(
+    commands: &mut Commands,
+    cursor: &CustomCursor,
+    cameras: &Query<(&Camera, &GlobalTransform), With<OffscreenCamera>>,
+    entries: &[ContextMenuEntry],
+) -> Entity {
+    let bounds = cameras
+        .iter()
+        .next()
+        .and_then(|(camera, transform)| viewport_world_bounds(camera, transform));
+    spawn_context_menu(commands, cursor.position, entries, bounds)
+}
+
+pub struct ContextMenuPlugin;
+
+impl Plugin for ContextMenuPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                close_context_menus,
+                clear_dead_context_menu_items,
+                sync_context_menu_entry_visuals,
+            )
+                .chain()
+                // After React so an entry's actions run before the close
+                // triggered by that same click despawns it.
+                .after(InteractionSystem::React),
+        );
+    }
+}
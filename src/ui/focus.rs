@@ -0,0 +1,160 @@
+use bevy::prelude::*;
+
+use crate::{
+    systems::{
+        audio::{SystemMenuAudio, SystemMenuSounds},
+        colors::HIGHLIGHT_COLOR,
+    },
+    ui::{menu::audio::AudioSettingsState, shapes::HollowRectangle},
+};
+
+/// Duration of the focus outline's travel tween.
+pub const FOCUS_TWEEN_SECS: f32 = 0.08;
+
+/// Accessibility toggle: when set, focus feedback snaps instead of
+/// tweening.
+#[derive(Resource, Debug, Default, Clone, Copy)]
+pub struct ReduceMotion(pub bool);
+
+/// Anything the shared focus outline can land on: menu options, dropdown
+/// items, modal buttons, window focusable children.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct Focusable {
+    pub rect: Vec2,
+}
+
+/// Which focusable currently holds navigation focus. Navigation systems
+/// (keyboard and gamepad alike) write here; feedback is driven once per
+/// frame from the final value, so simultaneous inputs cannot double-fire
+/// the switch sound.
+#[derive(Resource, Debug, Default)]
+pub struct NavigationFocus {
+    pub current: Option<Entity>,
+    last_seen: Option<Entity>,
+}
+
+/// The single shared focus outline entity.
+#[derive(Component)]
+pub struct FocusOutline;
+
+/// In-flight tween of the outline between two focus rects.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct FocusOutlineTween {
+    pub from_centre: Vec2,
+    pub from_rect: Vec2,
+    pub to_centre: Vec2,
+    pub to_rect: Vec2,
+    pub elapsed: f32,
+}
+
+const FOCUS_OUTLINE_MARGIN: f32 = 4.0;
+const FOCUS_OUTLINE_Z: f32 = 500.0;
+
+fn ensure_focus_outline(mut commands: Commands, outlines: Query<Entity, With<FocusOutline>>) {
+    if outlines.is_empty() {
+        commands.spawn((
+            FocusOutline,
+            HollowRectangle {
+                dimensions: Vec2::ZERO,
+                thickness: 1.5,
+                color: HIGHLIGHT_COLOR,
+            },
+            Transform::from_xyz(0.0, 0.0, FOCUS_OUTLINE_Z),
+            Visibility::Hidden,
+        ));
+    }
+}
+
+/// Reacts to focus landing on a new element: starts the outline tween
+/// from the previous rect and plays the switch sound exactly once.
+fn handle_focus_changes(
+    mut commands: Commands,
+    mut focus: ResMut<NavigationFocus>,
+    audio: Option<Res<SystemMenuAudio>>,
+    mixer: Res<AudioSettingsState>,
+    focusables: Query<(&Focusable, &GlobalTransform)>,
+    mut outlines: Query<
+        (Entity, &HollowRectangle, &Transform, &mut Visibility),
+        With<FocusOutline>,
+    >,
+) {
+    if focus.current == focus.last_seen {
+        return;
+    }
+    focus.last_seen = focus.current;
+    let Ok((outline, rectangle, transform, mut visibility)) = outlines.single_mut() else {
+        return;
+    };
+    let Some(target) = focus.current else {
+        *visibility = Visibility::Hidden;
+        return;
+    };
+    let Ok((focusable, global)) = focusables.get(target) else {
+        *visibility = Visibility::Hidden;
+        return;
+    };
+    let to_centre = global.translation().truncate();
+    let to_rect = focusable.rect + Vec2::splat(FOCUS_OUTLINE_MARGIN);
+    let was_hidden = *visibility == Visibility::Hidden;
+    *visibility = Visibility::Inherited;
+    commands.entity(outline).insert(FocusOutlineTween {
+        // A freshly shown outline has no meaningful origin to travel
+        // from; start the tween at the destination.
+        from_centre: if was_hidden {
+            to_centre
+        } else {
+            transform.translation.truncate()
+        },
+        from_rect: if was_hidden {
+            to_rect
+        } else {
+            rectangle.dimensions
+        },
+        to_centre,
+        to_rect,
+        elapsed: 0.0,
+    });
+    if let Some(audio) = audio {
+        audio.play(&mut commands, SystemMenuSounds::Switch, &mixer.settings);
+    }
+}
+
+/// Advances the outline tween; `ReduceMotion` snaps straight to the end.
+fn animate_focus_outline(
+    mut commands: Commands,
+    time: Res<Time>,
+    reduce_motion: Res<ReduceMotion>,
+    mut outlines: Query<
+        (Entity, &mut FocusOutlineTween, &mut HollowRectangle, &mut Transform),
+        With<FocusOutline>,
+    >,
+) {
+    for (entity, mut tween, mut rectangle, mut transform) in &mut outlines {
+        tween.elapsed += time.delta_secs();
+        let progress = if reduce_motion.0 {
+            1.0
+        } else {
+            (tween.elapsed / FOCUS_TWEEN_SECS).clamp(0.0, 1.0)
+        };
+        let centre = tween.from_centre.lerp(tween.to_centre, progress);
+        transform.translation.x = centre.x;
+        transform.translation.y = centre.y;
+        rectangle.dimensions = tween.from_rect.lerp(tween.to_rect, progress);
+        if progress >= 1.0 {
+            commands.entity(entity).remove::<FocusOutlineTween>();
+        }
+    }
+}
+
+pub struct FocusPlugin;
+
+impl Plugin for FocusPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<NavigationFocus>()
+            .init_resource::<ReduceMotion>()
+            .add_systems(
+                Update,
+                (ensure_focus_outline, handle_focus_changes, animate_focus_outline).chain(),
+            );
+    }
+}
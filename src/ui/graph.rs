@@ -0,0 +1,191 @@
+use bevy::prelude::*;
+
+use crate::{
+    systems::{
+        audio::{
+            play_transient_audio, AudioCategory, SpatialAudioConfig, TransientAudio,
+        },
+        colors::{DIM_COLOR, PRIMARY_COLOR},
+        interaction::Clickable,
+    },
+    ui::{menu::audio::AudioSettingsState, shapes::HollowRectangle},
+};
+
+/// Pitch of the recorded node-tone sample; playback speed is derived
+/// relative to this so a `NodeTone` base frequency of 220 plays the
+/// sample untouched.
+const NODE_TONE_SAMPLE_FREQ: f32 = 220.0;
+
+/// Half-extent of a node's outline and click region.
+pub const GRAPH_NODE_RADIUS: f32 = 12.0;
+const GRAPH_NODE_THICKNESS: f32 = 2.0;
+const GRAPH_EDGE_THICKNESS: f32 = 1.5;
+const NODE_TONE_VOLUME: f32 = 0.4;
+
+/// Playback-rate multiplier for a pitch `semitones` above (or, when
+/// negative, below) the reference.
+pub fn semitone_ratio(semitones: i32) -> f32 {
+    2f32.powf(semitones as f32 / 12.0)
+}
+
+/// A clickable node in the decision graph. `index` is the node's
+/// position along its path and drives the pitch of the click tone, so
+/// clicking along a path plays an ascending scale.
+#[derive(Component, Debug, Clone, Copy)]
+#[require(Transform, Visibility)]
+pub struct GraphNode {
+    pub index: usize,
+}
+
+impl GraphNode {
+    pub fn new(index: usize) -> Self {
+        Self { index }
+    }
+}
+
+/// The tone a node emits when clicked. The pitch climbs one semitone
+/// per step of the node's index from `base_freq`.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct NodeTone {
+    pub base_freq: f32,
+}
+
+impl Default for NodeTone {
+    fn default() -> Self {
+        Self {
+            base_freq: NODE_TONE_SAMPLE_FREQ,
+        }
+    }
+}
+
+impl NodeTone {
+    /// Playback speed for the node at `index`, relative to the sample.
+    pub fn playback_speed(&self, index: usize) -> f32 {
+        (self.base_freq / NODE_TONE_SAMPLE_FREQ) * semitone_ratio(index as i32)
+    }
+}
+
+/// A line drawn between two graph nodes. The sprite is restretched
+/// every frame so edges follow nodes that move.
+#[derive(Component, Debug, Clone, Copy)]
+#[require(Transform, Visibility)]
+pub struct GraphEdge {
+    pub from: Entity,
+    pub to: Entity,
+}
+
+/// The shared node-tone sample, pitched per node on playback.
+#[derive(Resource, Debug, Clone)]
+struct NodeToneAudio {
+    tone: TransientAudio,
+}
+
+fn load_node_tone_audio(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(NodeToneAudio {
+        tone: TransientAudio::new(asset_server.load("sounds/node_tone.ogg"), NODE_TONE_VOLUME),
+    });
+}
+
+/// Gives fresh nodes their outline and click region. Nodes stay
+/// `UiInputPolicy`-gated because `update_clickables` only raises
+/// `triggered` for entities the active policy allows.
+fn spawn_graph_node_visuals(mut commands: Commands, nodes: Query<Entity, Added<GraphNode>>) {
+    for entity in &nodes {
+        commands.entity(entity).insert((
+            HollowRectangle {
+                dimensions: Vec2::splat(GRAPH_NODE_RADIUS * 2.0),
+                thickness: GRAPH_NODE_THICKNESS,
+                color: PRIMARY_COLOR,
+            },
+            Clickable::new(Vec2::splat(GRAPH_NODE_RADIUS * 2.0)),
+        ));
+    }
+}
+
+/// Stretches each edge sprite between its endpoints.
+fn sync_graph_edges(
+    mut commands: Commands,
+    mut edges: Query<(Entity, &GraphEdge, &mut Transform, Option<&mut Sprite>)>,
+    nodes: Query<&GlobalTransform, With<GraphNode>>,
+) {
+    for (entity, edge, mut transform, sprite) in &mut edges {
+        let (Ok(from), Ok(to)) = (nodes.get(edge.from), nodes.get(edge.to)) else {
+            continue;
+        };
+        let from = from.translation().truncate();
+        let to = to.translation().truncate();
+        let span = to - from;
+        transform.translation = ((from + to) * 0.5).extend(transform.translation.z);
+        transform.rotation = Quat::from_rotation_z(span.y.atan2(span.x));
+        let size = Vec2::new(span.length(), GRAPH_EDGE_THICKNESS);
+        match sprite {
+            Some(mut sprite) => sprite.custom_size = Some(size),
+            None => {
+                commands.entity(entity).insert(Sprite {
+                    color: DIM_COLOR,
+                    custom_size: Some(size),
+                    ..default()
+                });
+            }
+        }
+    }
+}
+
+/// Plays the pitched tone for clicked nodes.
+fn play_node_tones(
+    mut commands: Commands,
+    audio: Option<Res<NodeToneAudio>>,
+    mixer: Res<AudioSettingsState>,
+    spatial: Res<SpatialAudioConfig>,
+    nodes: Query<(&GraphNode, &Clickable, Option<&NodeTone>)>,
+) {
+    let Some(audio) = audio else {
+        return;
+    };
+    for (node, clickable, tone) in &nodes {
+        if !clickable.triggered {
+            continue;
+        }
+        let speed = tone.copied().unwrap_or_default().playback_speed(node.index);
+        play_transient_audio(
+            &mut commands,
+            &audio.tone.clone().with_speed(speed),
+            AudioCategory::Ui,
+            &mixer.settings,
+            &spatial,
+        );
+    }
+}
+
+pub struct GraphPlugin;
+
+impl Plugin for GraphPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, load_node_tone_audio).add_systems(
+            Update,
+            (spawn_graph_node_visuals, sync_graph_edges, play_node_tones),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn an_octave_doubles_the_playback_speed() {
+        assert!((semitone_ratio(12) - 2.0).abs() < 1e-5);
+        assert!((semitone_ratio(0) - 1.0).abs() < 1e-5);
+        assert!((semitone_ratio(-12) - 0.5).abs() < 1e-5);
+    }
+
+    #[test]
+    fn clicking_along_a_path_ascends() {
+        let tone = NodeTone::default();
+        let speeds: Vec<f32> = (0..5).map(|index| tone.playback_speed(index)).collect();
+        assert!(speeds.windows(2).all(|pair| pair[1] > pair[0]));
+        // A higher base frequency shifts the whole sequence up.
+        let raised = NodeTone { base_freq: 440.0 };
+        assert!(raised.playback_speed(0) > tone.playback_speed(0));
+    }
+}
@@ -0,0 +1,136 @@
+use bevy::prelude::*;
+
+use crate::systems::colors::HIGHLIGHT_COLOR;
+
+/// Number of tick marks around a progress ring.
+pub const HOLD_RING_TICKS: usize = 16;
+pub const HOLD_RING_RADIUS: f32 = 26.0;
+
+/// Hold-to-confirm progress. Call [`HoldToConfirm::advance`] every frame
+/// with whether the input is held; releasing early cancels all progress.
+#[derive(Component, Debug, Clone)]
+pub struct HoldToConfirm {
+    pub required_secs: f32,
+    pub progress_secs: f32,
+}
+
+impl HoldToConfirm {
+    pub fn new(required_secs: f32) -> Self {
+        Self {
+            required_secs,
+            progress_secs: 0.0,
+        }
+    }
+
+    /// Returns true exactly once, on the frame the hold completes.
+    pub fn advance(&mut self, held: bool, delta_secs: f32) -> bool {
+        if !held {
+            self.progress_secs = 0.0;
+            return false;
+        }
+        let was_complete = self.progress_secs >= self.required_secs;
+        self.progress_secs += delta_secs;
+        !was_complete && self.progress_secs >= self.required_secs
+    }
+
+    pub fn fraction(&self) -> f32 {
+        if self.required_secs <= 0.0 {
+            1.0
+        } else {
+            (self.progress_secs / self.required_secs).clamp(0.0, 1.0)
+        }
+    }
+}
+
+/// A ring of tick sprites that fills clockwise with hold progress.
+#[derive(Component)]
+pub struct HoldProgressRing;
+
+#[derive(Component)]
+struct HoldRingTick {
+    index: usize,
+}
+
+fn spawn_hold_rings(mut commands: Commands, rings: Query<Entity, Added<HoldProgressRing>>) {
+    for ring in &rings {
+        commands.entity(ring).with_children(|parent| {
+            for index in 0..HOLD_RING_TICKS {
+                let angle = std::f32::consts::TAU * index as f32 / HOLD_RING_TICKS as f32;
+                parent.spawn((
+                    HoldRingTick { index },
+                    Sprite::from_color(HIGHLIGHT_COLOR, Vec2::new(2.0, 6.0)),
+                    Transform {
+                        translation: Vec3::new(
+                            angle.sin() * HOLD_RING_RADIUS,
+                            angle.cos() * HOLD_RING_RADIUS,
+                            0.0,
+                        ),
+                        rotation: Quat::from_rotation_z(-angle),
+                        ..default()
+                    },
+                    Visibility::Hidden,
+                ));
+            }
+        });
+    }
+}
+
+fn sync_hold_rings(
+    rings: Query<(&HoldToConfirm, &Children), With<HoldProgressRing>>,
+    mut ticks: Query<(&HoldRingTick, &mut Visibility)>,
+) {
+    for (hold, children) in &rings {
+        let lit = (hold.fraction() * HOLD_RING_TICKS as f32).floor() as usize;
+        for child in children.iter() {
+            if let Ok((tick, mut visibility)) = ticks.get_mut(child) {
+                *visibility = if tick.index < lit {
+                    Visibility::Inherited
+                } else {
+                    Visibility::Hidden
+                };
+            }
+        }
+    }
+}
+
+pub struct HoldConfirmPlugin;
+
+impl Plugin for HoldConfirmPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, (spawn_hold_rings, sync_hold_rings).chain());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn short_tap_does_not_complete() {
+        let mut hold = HoldToConfirm::new(1.0);
+        assert!(!hold.advance(true, 0.2));
+        assert!(!hold.advance(false, 0.016));
+        assert_eq!(hold.progress_secs, 0.0);
+    }
+
+    #[test]
+    fn full_hold_completes_exactly_once() {
+        let mut hold = HoldToConfirm::new(0.5);
+        let mut completions = 0;
+        for _ in 0..60 {
+            if hold.advance(true, 0.016) {
+                completions += 1;
+            }
+        }
+        assert_eq!(completions, 1);
+    }
+
+    #[test]
+    fn release_resets_progress() {
+        let mut hold = HoldToConfirm::new(1.0);
+        hold.advance(true, 0.5);
+        assert!(hold.fraction() > 0.4);
+        hold.advance(false, 0.016);
+        assert_eq!(hold.fraction(), 0.0);
+    }
+}
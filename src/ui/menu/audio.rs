@@ -0,0 +1,172 @@
+use bevy::{audio::Volume, prelude::*};
+
+use crate::{
+    data::settings::{AudioChannel, AudioSettings, UserSettings},
+    systems::audio::{AudioCategory, BaseVolume},
+    ui::{
+        menu::pages::{
+            scaled_font_size, MenuCommand, MenuCommandEvent, MenuOptionRow, MenuPage,
+            MenuPageContent, OptionCycler, UiScale,
+        },
+        table::{Cell, Column, Row, Table},
+    },
+};
+
+use super::video::VIDEO_TABLE_TEXT_SIZE;
+
+/// Live mixer levels, loaded from disk and persisted on every change.
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct AudioSettingsState {
+    pub settings: AudioSettings,
+}
+
+impl Default for AudioSettingsState {
+    fn default() -> Self {
+        Self {
+            settings: UserSettings::load().audio,
+        }
+    }
+}
+
+/// The volume table shown on the audio page.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct AudioOptionsTable;
+
+fn volume_label(volume: f32) -> String {
+    format!("{:.0}%", (volume * 100.0).round())
+}
+
+fn audio_table_rows(settings: &AudioSettings) -> Vec<Row> {
+    vec![
+        Row::new(vec![
+            Cell::new("MASTER"),
+            Cell::new(volume_label(settings.master_volume)),
+        ]),
+        Row::new(vec![
+            Cell::new("MUSIC"),
+            Cell::new(volume_label(settings.music_volume)),
+        ]),
+        Row::new(vec![
+            Cell::new("SFX"),
+            Cell::new(volume_label(settings.sfx_volume)),
+        ]),
+        Row::new(vec![
+            Cell::new("UI"),
+            Cell::new(volume_label(settings.ui_volume)),
+        ]),
+    ]
+}
+
+/// Attaches the volume table and row cyclers once an audio page's
+/// content exists.
+pub fn populate_audio_page(
+    mut commands: Commands,
+    state: Res<AudioSettingsState>,
+    scale: Res<UiScale>,
+    contents: Query<(Entity, &MenuPageContent), Added<MenuPageContent>>,
+    rows: Query<(Entity, &MenuOptionRow)>,
+) {
+    for (content, page_content) in &contents {
+        if page_content.page != MenuPage::Audio {
+            continue;
+        }
+        let mut table = Table::new(
+            vec![Column::new("CHANNEL", 110.0), Column::new("VOLUME", 90.0)],
+            scaled_font_size(&scale, VIDEO_TABLE_TEXT_SIZE),
+        );
+        table.rows = audio_table_rows(&state.settings);
+        commands.spawn((
+            AudioOptionsTable,
+            table,
+            Transform::from_xyz(120.0, -50.0, 0.2),
+            ChildOf(content),
+        ));
+        let channels = [
+            AudioChannel::Master,
+            AudioChannel::Music,
+            AudioChannel::Sfx,
+            AudioChannel::Ui,
+        ];
+        for (entity, row) in &rows {
+            if row.content != content {
+                continue;
+            }
+            if let Some(channel) = channels.get(row.index) {
+                commands.entity(entity).insert(OptionCycler {
+                    left: MenuCommand::AdjustVolume(*channel, -1),
+                    right: MenuCommand::AdjustVolume(*channel, 1),
+                });
+            }
+        }
+    }
+}
+
+/// Refreshes the table cells whenever the mixer changes.
+pub fn refresh_audio_table(
+    state: Res<AudioSettingsState>,
+    mut tables: Query<&mut Table, With<AudioOptionsTable>>,
+) {
+    if !state.is_changed() {
+        return;
+    }
+    for mut table in &mut tables {
+        table.rows = audio_table_rows(&state.settings);
+    }
+}
+
+/// Consumes volume commands, persisting alongside the video settings.
+pub fn handle_audio_menu_commands(
+    mut events: EventReader<MenuCommandEvent>,
+    mut state: ResMut<AudioSettingsState>,
+) {
+    let mut changed = false;
+    for event in events.read() {
+        if let MenuCommand::AdjustVolume(channel, delta) = event.command {
+            state.settings.adjust(channel, delta);
+            changed = true;
+        }
+    }
+    if changed {
+        let mut settings = UserSettings::load();
+        settings.audio = state.settings;
+        settings.save();
+    }
+}
+
+/// Pushes the master level into `GlobalVolume` and rescales any playing
+/// sinks (via their recorded [`BaseVolume`] and [`AudioCategory`]) so
+/// changes are audible immediately rather than only on the next sound.
+pub fn apply_audio_settings(
+    state: Res<AudioSettingsState>,
+    mut global: ResMut<GlobalVolume>,
+    mut sinks: Query<(&mut AudioSink, &BaseVolume, Option<&AudioCategory>)>,
+) {
+    if !state.is_changed() {
+        return;
+    }
+    global.volume = Volume::Linear(state.settings.master_volume);
+    for (mut sink, base, category) in &mut sinks {
+        let category = category.copied().unwrap_or(AudioCategory::Sfx);
+        sink.set_volume(Volume::Linear(base.0 * category.scale(&state.settings)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn volume_steps_by_ten_percent_and_clamps() {
+        let mut settings = AudioSettings::default();
+        settings.adjust(AudioChannel::Music, 1);
+        assert!((settings.music_volume - 0.9).abs() < 1e-6);
+        for _ in 0..20 {
+            settings.adjust(AudioChannel::Music, -1);
+        }
+        assert_eq!(settings.music_volume, 0.0);
+        for _ in 0..20 {
+            settings.adjust(AudioChannel::Music, 1);
+        }
+        assert_eq!(settings.music_volume, 1.0);
+    }
+}
@@ -0,0 +1,248 @@
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+
+use crate::{
+    systems::colors::WARNING_COLOR,
+    ui::{
+        menu::{
+            pages::{
+                page_definition, scaled_font_size, MenuCommand, MenuCommandEvent, MenuOptionDef,
+                MenuPage, MenuPageContent, UiScale,
+            },
+            video::{spawn_video_modal_base, VideoModalRoot, VIDEO_TABLE_TEXT_SIZE},
+        },
+        table::{Cell, Column, Row, Table},
+    },
+};
+
+/// Every page whose shortcuts can be rebound; conflicts are detected
+/// across all of them together.
+const BINDABLE_PAGES: [MenuPage; 5] = [
+    MenuPage::MainRoot,
+    MenuPage::PauseRoot,
+    MenuPage::Options,
+    MenuPage::Video,
+    MenuPage::Audio,
+];
+
+/// Shortcut overrides keyed by `MenuOptionDef::action`. Defaults come
+/// from the page definitions; `resolve` is the single lookup the menu
+/// input path uses.
+#[derive(Resource, Debug, Default)]
+pub struct KeybindState {
+    overrides: HashMap<&'static str, KeyCode>,
+    /// The action currently waiting for a key press, if any.
+    pub listening: Option<&'static str>,
+}
+
+impl KeybindState {
+    /// Effective shortcut for an option: override first, default second.
+    pub fn resolve(&self, option: &MenuOptionDef) -> Option<KeyCode> {
+        self.overrides
+            .get(option.action)
+            .copied()
+            .or(option.shortcut)
+    }
+
+    /// Effective shortcut for an action id, searching the bindable pages.
+    pub fn resolve_action(&self, action: &str) -> Option<KeyCode> {
+        if let Some(key) = self.overrides.get(action) {
+            return Some(*key);
+        }
+        BINDABLE_PAGES
+            .iter()
+            .flat_map(|page| page_definition(*page).options)
+            .find(|option| option.action == action)
+            .and_then(|option| option.shortcut)
+    }
+
+    pub fn rebind(&mut self, action: &'static str, key: KeyCode) {
+        self.overrides.insert(action, key);
+    }
+
+    pub fn reset_to_defaults(&mut self) {
+        self.overrides.clear();
+    }
+
+    /// Pairs of actions resolved to the same key.
+    pub fn conflicts(&self) -> Vec<(&'static str, &'static str, KeyCode)> {
+        let mut seen: Vec<(&'static str, KeyCode)> = Vec::new();
+        let mut conflicts = Vec::new();
+        for page in BINDABLE_PAGES {
+            for option in page_definition(page).options {
+                let Some(key) = self.resolve(option) else {
+                    continue;
+                };
+                if let Some((other, _)) = seen.iter().find(|(_, bound)| *bound == key) {
+                    conflicts.push((*other, option.action, key));
+                }
+                seen.push((option.action, key));
+            }
+        }
+        conflicts
+    }
+}
+
+/// The binding table shown on the controls page.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct ControlsTable;
+
+/// Marks the listening modal so the key-capture system can find it.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct RebindListeningModal;
+
+/// Human-readable name for a shortcut key, `-` when unbound.
+pub fn key_label(key: Option<KeyCode>) -> String {
+    match key {
+        Some(key) => format!("{key:?}").to_uppercase(),
+        None => String::from("-"),
+    }
+}
+
+fn controls_table_rows(keybinds: &KeybindState) -> Vec<Row> {
+    let mut rows: Vec<Row> = page_definition(MenuPage::Controls)
+        .options
+        .iter()
+        .filter_map(|option| match option.command {
+            MenuCommand::StartRebind(action) => Some(Row::new(vec![
+                Cell::new(option.label),
+                Cell::new(key_label(keybinds.resolve_action(action))),
+            ])),
+            _ => None,
+        })
+        .collect();
+    for (first, second, key) in keybinds.conflicts() {
+        let mut cell = Cell::new(format!(
+            "CONFLICT: {first} / {second} both {}",
+            key_label(Some(key)),
+        ));
+        cell.text_color = WARNING_COLOR;
+        rows.push(Row::new(vec![cell, Cell::new("")]));
+    }
+    rows
+}
+
+/// Attaches the binding table once a controls page's content exists.
+pub fn populate_controls_page(
+    mut commands: Commands,
+    keybinds: Res<KeybindState>,
+    scale: Res<UiScale>,
+    contents: Query<(Entity, &MenuPageContent), Added<MenuPageContent>>,
+) {
+    for (content, page_content) in &contents {
+        if page_content.page != MenuPage::Controls {
+            continue;
+        }
+        let mut table = Table::new(
+            vec![Column::new("ACTION", 150.0), Column::new("KEY", 100.0)],
+            scaled_font_size(&scale, VIDEO_TABLE_TEXT_SIZE),
+        );
+        table.rows = controls_table_rows(&keybinds);
+        commands.spawn((
+            ControlsTable,
+            table,
+            Transform::from_xyz(150.0, -120.0, 0.2),
+            ChildOf(content),
+        ));
+    }
+}
+
+/// Refreshes the binding table whenever the overrides change.
+pub fn refresh_controls_table(
+    keybinds: Res<KeybindState>,
+    mut tables: Query<&mut Table, With<ControlsTable>>,
+) {
+    if !keybinds.is_changed() {
+        return;
+    }
+    for mut table in &mut tables {
+        table.rows = controls_table_rows(&keybinds);
+    }
+}
+
+/// Consumes rebind commands: opens the listening modal, resets defaults,
+/// or cancels an in-flight listen.
+pub fn handle_keybind_commands(
+    mut commands: Commands,
+    mut events: EventReader<MenuCommandEvent>,
+    mut keybinds: ResMut<KeybindState>,
+    scale: Res<UiScale>,
+    modals: Query<Entity, With<RebindListeningModal>>,
+) {
+    for event in events.read() {
+        match event.command {
+            MenuCommand::StartRebind(action) => {
+                if keybinds.listening.is_none() {
+                    keybinds.listening = Some(action);
+                    let modal = spawn_video_modal_base(
+                        &mut commands,
+                        &scale,
+                        "PRESS A KEY",
+                        &[("CANCEL [ESC]", MenuCommand::CancelRebind)],
+                    );
+                    commands.entity(modal).insert(RebindListeningModal);
+                }
+            }
+            MenuCommand::CancelRebind => {
+                keybinds.listening = None;
+                for modal in &modals {
+                    commands.entity(modal).despawn();
+                }
+            }
+            MenuCommand::ResetKeybinds => keybinds.reset_to_defaults(),
+            _ => {}
+        }
+    }
+}
+
+/// While a rebind is listening, the next key press becomes the binding
+/// (Escape cancels) and the modal closes.
+pub fn capture_rebind_key(
+    mut commands: Commands,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut keybinds: ResMut<KeybindState>,
+    modals: Query<Entity, (With<RebindListeningModal>, With<VideoModalRoot>)>,
+) {
+    let Some(action) = keybinds.listening else {
+        return;
+    };
+    let Some(key) = keys.get_just_pressed().next().copied() else {
+        return;
+    };
+    if key != KeyCode::Escape {
+        keybinds.rebind(action, key);
+    }
+    keybinds.listening = None;
+    for modal in &modals {
+        commands.entity(modal).despawn();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::menu::pages::VIDEO_MENU_OPTIONS;
+
+    #[test]
+    fn overrides_take_precedence_and_reset_restores_defaults() {
+        let mut keybinds = KeybindState::default();
+        let vsync = &VIDEO_MENU_OPTIONS[1];
+        assert_eq!(keybinds.resolve(vsync), Some(KeyCode::KeyV));
+        keybinds.rebind("video.vsync", KeyCode::KeyX);
+        assert_eq!(keybinds.resolve(vsync), Some(KeyCode::KeyX));
+        keybinds.reset_to_defaults();
+        assert_eq!(keybinds.resolve(vsync), Some(KeyCode::KeyV));
+    }
+
+    #[test]
+    fn duplicate_bindings_are_reported() {
+        let mut keybinds = KeybindState::default();
+        // Bind the resolution cycle onto the vsync key.
+        keybinds.rebind("video.resolution", KeyCode::KeyV);
+        assert!(keybinds
+            .conflicts()
+            .iter()
+            .any(|(_, _, key)| *key == KeyCode::KeyV));
+    }
+}
@@ -0,0 +1,429 @@
+use std::collections::BTreeSet;
+
+use bevy::prelude::*;
+
+use crate::{
+    systems::{
+        colors::{HIGHLIGHT_COLOR, PRIMARY_COLOR, WINDOW_BODY_COLOR},
+        interaction::{Clickable, ConsumedInputs, CustomCursor, InteractionSystem},
+    },
+    ui::shapes::BorderedRectangle,
+};
+
+pub const DROPDOWN_ITEM_HEIGHT: f32 = 18.0;
+pub const DROPDOWN_ITEM_FONT_SIZE: f32 = 12.0;
+
+/// What choosing an item does to the dropdown's selection.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DropdownSelection {
+    /// Exactly one item is selected; choosing replaces it and closes.
+    Single(usize),
+    /// Any number of items may be selected; choosing toggles membership
+    /// and leaves the dropdown open.
+    Multi(BTreeSet<usize>),
+}
+
+/// A generic expandable list attached to a value cell. Single-select by
+/// default; construct with [`Dropdown::multi_select`] for toggle lists
+/// that stay open until dismissed.
+#[derive(Component, Debug, Clone)]
+#[require(Transform, Visibility)]
+pub struct Dropdown {
+    pub items: Vec<String>,
+    pub open: bool,
+    pub selection: DropdownSelection,
+}
+
+impl Dropdown {
+    pub fn new(items: Vec<String>) -> Self {
+        Self {
+            items,
+            open: false,
+            selection: DropdownSelection::Single(0),
+        }
+    }
+
+    pub fn multi_select(items: Vec<String>) -> Self {
+        Self {
+            items,
+            open: false,
+            selection: DropdownSelection::Multi(BTreeSet::new()),
+        }
+    }
+
+    pub fn is_multi_select(&self) -> bool {
+        matches!(self.selection, DropdownSelection::Multi(_))
+    }
+
+    pub fn is_selected(&self, index: usize) -> bool {
+        match &self.selection {
+            DropdownSelection::Single(selected) => *selected == index,
+            DropdownSelection::Multi(selected) => selected.contains(&index),
+        }
+    }
+
+    /// Applies a choice. Returns true when the dropdown should close
+    /// (single-select); multi-select toggles in place and stays open.
+    pub fn choose(&mut self, index: usize) -> bool {
+        if index >= self.items.len() {
+            return false;
+        }
+        match &mut self.selection {
+            DropdownSelection::Single(selected) => {
+                *selected = index;
+                true
+            }
+            DropdownSelection::Multi(selected) => {
+                if !selected.remove(&index) {
+                    selected.insert(index);
+                }
+                false
+            }
+        }
+    }
+}
+
+/// How a dropdown opens. `Click` is the stock behaviour; `HoverDwell`
+/// additionally opens after the cursor rests on the value cell for the
+/// dwell, combo-box style. Closing (outside click, Escape) is shared.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub enum DropdownOpenMode {
+    Click,
+    HoverDwell { dwell_secs: f32 },
+}
+
+impl Default for DropdownOpenMode {
+    fn default() -> Self {
+        Self::Click
+    }
+}
+
+/// Dwell accumulator for hover-opened dropdowns.
+#[derive(Component, Debug, Default)]
+struct DropdownDwell {
+    elapsed_secs: f32,
+}
+
+/// Width of the value cell a hover dwell measures against.
+const DROPDOWN_VALUE_CELL_WIDTH: f32 = 160.0;
+
+/// Opens a dropdown on a menu page; the single entry point for every
+/// non-click open path (keyboard, hover dwell).
+pub fn open_dropdown_for_menu(dropdown: &mut Dropdown) {
+    if !dropdown.open {
+        dropdown.open = true;
+    }
+}
+
+/// Advances (or cancels) a hover dwell, returning true exactly when the
+/// dwell completes and the dropdown should open.
+pub fn dwell_open_ready(
+    hovered: bool,
+    elapsed_secs: &mut f32,
+    delta_secs: f32,
+    dwell_secs: f32,
+) -> bool {
+    if !hovered {
+        *elapsed_secs = 0.0;
+        return false;
+    }
+    let before = *elapsed_secs;
+    *elapsed_secs += delta_secs;
+    before < dwell_secs && *elapsed_secs >= dwell_secs
+}
+
+/// A spawned list entry belonging to an open dropdown.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct DropdownItem {
+    pub dropdown: Entity,
+    pub index: usize,
+}
+
+#[derive(Component)]
+struct DropdownListRoot {
+    dropdown: Entity,
+}
+
+/// Closes every open dropdown. Callable as a system or from handlers
+/// after a committing selection.
+pub fn close_all_dropdowns(dropdowns: &mut Query<&mut Dropdown>) {
+    for mut dropdown in dropdowns.iter_mut() {
+        if dropdown.open {
+            dropdown.open = false;
+        }
+    }
+}
+
+fn item_label(dropdown: &Dropdown, index: usize) -> String {
+    if dropdown.is_multi_select() {
+        let mark = if dropdown.is_selected(index) { "X" } else { " " };
+        format!("[{mark}] {}", dropdown.items[index])
+    } else {
+        dropdown.items[index].clone()
+    }
+}
+
+/// Spawns/despawns the expanded list to mirror each dropdown's `open`
+/// flag, and refreshes item labels (checkmarks) on selection changes.
+fn sync_dropdown_lists(
+    mut commands: Commands,
+    dropdowns: Query<(Entity, &Dropdown), Changed<Dropdown>>,
+    lists: Query<(Entity, &DropdownListRoot)>,
+    mut items: Query<(&DropdownItem, &mut Text2d)>,
+) {
+    for (entity, dropdown) in &dropdowns {
+        let existing = lists
+            .iter()
+            .find(|(_, root)| root.dropdown == entity)
+            .map(|(list, _)| list);
+        if !dropdown.open {
+            if let Some(list) = existing {
+                commands.entity(list).despawn();
+            }
+            continue;
+        }
+        if existing.is_some() {
+            // List already spawned: refresh labels in place.
+            for (item, mut text) in &mut items {
+                if item.dropdown == entity {
+                    text.0 = item_label(dropdown, item.index);
+                }
+            }
+            continue;
+        }
+        let height = dropdown.items.len() as f32 * DROPDOWN_ITEM_HEIGHT;
+        let width = 140.0;
+        commands
+            .entity(entity)
+            .with_children(|parent| {
+                parent
+                    .spawn((
+                        DropdownListRoot { dropdown: entity },
+                        BorderedRectangle {
+                            dimensions: Vec2::new(width, height),
+                            border_thickness: 1.0,
+                            border_color: PRIMARY_COLOR,
+                            fill_color: WINDOW_BODY_COLOR,
+                        },
+                        Transform::from_xyz(0.0, -(height + DROPDOWN_ITEM_HEIGHT) * 0.5, 2.0),
+                    ))
+                    .with_children(|list| {
+                        for (index, _) in dropdown.items.iter().enumerate() {
+                            let y = height * 0.5
+                                - DROPDOWN_ITEM_HEIGHT * (index as f32 + 0.5);
+                            list.spawn((
+                                DropdownItem {
+                                    dropdown: entity,
+                                    index,
+                                },
+                                Text2d::new(item_label(dropdown, index)),
+                                TextFont::from_font_size(DROPDOWN_ITEM_FONT_SIZE),
+                                TextColor(if dropdown.is_selected(index) {
+                                    HIGHLIGHT_COLOR
+                                } else {
+                                    PRIMARY_COLOR
+                                }),
+                                Clickable::new(Vec2::new(width, DROPDOWN_ITEM_HEIGHT)),
+                                Transform::from_xyz(0.0, y, 0.5),
+                            ));
+                        }
+                    });
+            });
+    }
+}
+
+/// Routes item clicks into `Dropdown::choose`, closing single-select
+/// dropdowns on commit.
+fn handle_dropdown_item_clicks(
+    items: Query<(&DropdownItem, &Clickable)>,
+    mut dropdowns: Query<&mut Dropdown>,
+) {
+    for (item, clickable) in &items {
+        if !clickable.triggered {
+            continue;
+        }
+        let Ok(mut dropdown) = dropdowns.get_mut(item.dropdown) else {
+            continue;
+        };
+        if dropdown.choose(item.index) {
+            close_all_dropdowns(&mut dropdowns);
+        }
+    }
+}
+
+/// A press outside an open dropdown's value cell and list closes it —
+/// the explicit "done" for multi-select dropdowns.
+fn close_dropdowns_on_outside_click(
+    buttons: Res<ButtonInput<MouseButton>>,
+    cursor: Res<CustomCursor>,
+    mut dropdowns: Query<(&mut Dropdown, &GlobalTransform)>,
+) {
+    if !buttons.just_pressed(MouseButton::Left) {
+        return;
+    }
+    for (mut dropdown, transform) in &mut dropdowns {
+        if !dropdown.open {
+            continue;
+        }
+        let origin = transform.translation().truncate();
+        let height = dropdown.items.len() as f32 * DROPDOWN_ITEM_HEIGHT;
+        let extent = Rect::from_corners(
+            origin + Vec2::new(-80.0, DROPDOWN_ITEM_HEIGHT * 0.5),
+            origin + Vec2::new(80.0, -(height + DROPDOWN_ITEM_HEIGHT)),
+        );
+        if !extent.contains(cursor.position) {
+            dropdown.open = false;
+        }
+    }
+}
+
+/// Marks the escape flag consumed when any dropdown is open, returning
+/// whether the press should close dropdowns. Split out so the two-step
+/// behaviour (first Escape closes the dropdown, the second reaches the
+/// menu) is testable without a world.
+pub fn consume_escape_for_dropdowns(any_open: bool, consumed: &mut ConsumedInputs) -> bool {
+    if any_open {
+        consumed.escape = true;
+    }
+    any_open
+}
+
+/// Drives hover-dwell opens: the cursor resting on a closed dropdown's
+/// value cell for the configured dwell opens it; leaving early cancels.
+fn open_dropdowns_on_hover_dwell(
+    mut commands: Commands,
+    time: Res<Time>,
+    cursor: Res<CustomCursor>,
+    mut dropdowns: Query<(
+        Entity,
+        &mut Dropdown,
+        &DropdownOpenMode,
+        &GlobalTransform,
+        Option<&mut DropdownDwell>,
+    )>,
+) {
+    for (entity, mut dropdown, mode, transform, dwell) in &mut dropdowns {
+        let DropdownOpenMode::HoverDwell { dwell_secs } = *mode else {
+            continue;
+        };
+        let Some(mut dwell) = dwell else {
+            commands.entity(entity).insert(DropdownDwell::default());
+            continue;
+        };
+        if dropdown.open {
+            dwell.elapsed_secs = 0.0;
+            continue;
+        }
+        let cell = Rect::from_center_size(
+            transform.translation().truncate(),
+            Vec2::new(DROPDOWN_VALUE_CELL_WIDTH, DROPDOWN_ITEM_HEIGHT),
+        );
+        let hovered = cell.contains(cursor.position);
+        if dwell_open_ready(
+            hovered,
+            &mut dwell.elapsed_secs,
+            time.delta_secs(),
+            dwell_secs,
+        ) {
+            open_dropdown_for_menu(&mut dropdown);
+        }
+    }
+}
+
+/// Escape closes open dropdowns and consumes the press, so the menu
+/// behind them only reacts to a second Escape.
+fn close_dropdowns_on_escape(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut consumed: ResMut<ConsumedInputs>,
+    mut dropdowns: Query<&mut Dropdown>,
+) {
+    if !keys.just_pressed(KeyCode::Escape) {
+        return;
+    }
+    let any_open = dropdowns.iter().any(|dropdown| dropdown.open);
+    if consume_escape_for_dropdowns(any_open, &mut consumed) {
+        close_all_dropdowns(&mut dropdowns);
+    }
+}
+
+pub struct DropdownPlugin;
+
+impl Plugin for DropdownPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            // The escape consumer runs in Sense so every observer of
+            // [`ConsumedInputs`] in React sees the flag this frame.
+            close_dropdowns_on_escape.in_set(InteractionSystem::Sense),
+        )
+        .add_systems(
+            Update,
+            (
+                handle_dropdown_item_clicks,
+                open_dropdowns_on_hover_dwell,
+                close_dropdowns_on_outside_click,
+                sync_dropdown_lists,
+            )
+                .chain(),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_first_escape_stops_at_the_dropdown_and_the_second_reaches_the_menu() {
+        // Frame one: a dropdown is open, so the press is consumed and
+        // closes only the dropdown.
+        let mut consumed = ConsumedInputs::default();
+        assert!(consume_escape_for_dropdowns(true, &mut consumed));
+        assert!(consumed.escape);
+        // Frame two: the flag has been cleared and no dropdown remains
+        // open — the press passes through to the menu untouched.
+        let mut consumed = ConsumedInputs::default();
+        assert!(!consume_escape_for_dropdowns(false, &mut consumed));
+        assert!(!consumed.escape);
+    }
+
+    #[test]
+    fn the_dwell_opens_once_and_cancels_when_the_cursor_leaves() {
+        let mut elapsed = 0.0;
+        // Two frames short of the dwell, then the completing frame.
+        assert!(!dwell_open_ready(true, &mut elapsed, 0.15, 0.35));
+        assert!(!dwell_open_ready(true, &mut elapsed, 0.15, 0.35));
+        assert!(dwell_open_ready(true, &mut elapsed, 0.15, 0.35));
+        // Completion fires exactly once.
+        assert!(!dwell_open_ready(true, &mut elapsed, 0.15, 0.35));
+        // Leaving resets the accumulator entirely.
+        assert!(!dwell_open_ready(false, &mut elapsed, 0.15, 0.35));
+        assert_eq!(elapsed, 0.0);
+    }
+
+    #[test]
+    fn single_select_replaces_and_closes() {
+        let mut dropdown = Dropdown::new(vec!["A".into(), "B".into()]);
+        dropdown.open = true;
+        assert!(dropdown.choose(1));
+        assert!(dropdown.is_selected(1));
+        assert!(!dropdown.is_selected(0));
+    }
+
+    #[test]
+    fn multi_select_toggles_and_stays_open() {
+        let mut dropdown =
+            Dropdown::multi_select(vec!["A".into(), "B".into(), "C".into()]);
+        dropdown.open = true;
+        assert!(!dropdown.choose(0));
+        assert!(!dropdown.choose(2));
+        assert!(dropdown.is_selected(0));
+        assert!(dropdown.is_selected(2));
+        assert!(dropdown.open);
+        // Toggling off removes from the set without closing.
+        assert!(!dropdown.choose(0));
+        assert!(!dropdown.is_selected(0));
+        assert!(dropdown.is_selected(2));
+        assert!(dropdown.open);
+    }
+}
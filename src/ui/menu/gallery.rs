@@ -0,0 +1,75 @@
+use bevy::prelude::*;
+
+use crate::{
+    scenes::ending::{Ending, EndingsCollection},
+    systems::colors::DIM_COLOR,
+    ui::{
+        menu::{
+            pages::{scaled_font_size, MenuPage, MenuPageContent, UiScale},
+            video::VIDEO_TABLE_TEXT_SIZE,
+        },
+        table::{Cell, Column, Row, Table},
+    },
+};
+
+/// The ending gallery table.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct EndingsTable;
+
+fn endings_table_rows(collection: &EndingsCollection) -> Vec<Row> {
+    Ending::ALL
+        .iter()
+        .map(|ending| {
+            if collection.is_unlocked(*ending) {
+                Row::new(vec![
+                    Cell::new(ending.title()),
+                    Cell::new(ending.description()),
+                ])
+            } else {
+                let mut title = Cell::new("???");
+                title.text_color = DIM_COLOR;
+                let mut description = Cell::new("");
+                description.text_color = DIM_COLOR;
+                Row::new(vec![title, description])
+            }
+        })
+        .collect()
+}
+
+/// Attaches the gallery table once an endings page's content exists.
+pub fn populate_endings_page(
+    mut commands: Commands,
+    collection: Res<EndingsCollection>,
+    scale: Res<UiScale>,
+    contents: Query<(Entity, &MenuPageContent), Added<MenuPageContent>>,
+) {
+    for (content, page_content) in &contents {
+        if page_content.page != MenuPage::Endings {
+            continue;
+        }
+        let mut table = Table::new(
+            vec![Column::new("ENDING", 130.0), Column::new("", 170.0)],
+            scaled_font_size(&scale, VIDEO_TABLE_TEXT_SIZE),
+        );
+        table.rows = endings_table_rows(&collection);
+        commands.spawn((
+            EndingsTable,
+            table,
+            Transform::from_xyz(160.0, -60.0, 0.2),
+            ChildOf(content),
+        ));
+    }
+}
+
+/// Refreshes the gallery when a new ending unlocks while it is open.
+pub fn refresh_endings_table(
+    collection: Res<EndingsCollection>,
+    mut tables: Query<&mut Table, With<EndingsTable>>,
+) {
+    if !collection.is_changed() {
+        return;
+    }
+    for mut table in &mut tables {
+        table.rows = endings_table_rows(&collection);
+    }
+}
@@ -0,0 +1,52 @@
+use bevy::prelude::*;
+
+use crate::{
+    scenes::dilemma::CompletedDilemmas,
+    systems::interaction::Disabled,
+    ui::menu::pages::{page_definition, MenuCommand, MenuOptionRow, MenuPage, MenuPageContent},
+};
+
+/// Dims and deactivates locked dilemma rows on the level selector.
+/// Reruns when completion changes so finishing a dilemma unlocks it in
+/// an open menu.
+pub fn sync_level_select_locks(
+    mut commands: Commands,
+    completed: Res<CompletedDilemmas>,
+    contents: Query<(Entity, &MenuPageContent), Added<MenuPageContent>>,
+    all_contents: Query<(Entity, &MenuPageContent)>,
+    rows: Query<(Entity, &MenuOptionRow)>,
+) {
+    let refresh_all = completed.is_changed() && !completed.is_added();
+    let targets: Vec<Entity> = if refresh_all {
+        all_contents
+            .iter()
+            .filter(|(_, content)| content.page == MenuPage::LevelSelect)
+            .map(|(entity, _)| entity)
+            .collect()
+    } else {
+        contents
+            .iter()
+            .filter(|(_, content)| content.page == MenuPage::LevelSelect)
+            .map(|(entity, _)| entity)
+            .collect()
+    };
+    if targets.is_empty() {
+        return;
+    }
+    let options = page_definition(MenuPage::LevelSelect).options;
+    for (entity, row) in &rows {
+        if !targets.contains(&row.content) {
+            continue;
+        }
+        let Some(MenuCommand::LoadDilemma(id)) =
+            options.get(row.index).map(|option| option.command)
+        else {
+            continue;
+        };
+        if completed.is_unlocked(id) {
+            commands.entity(entity).remove::<Disabled>();
+        } else {
+            commands.entity(entity).insert(Disabled);
+        }
+    }
+}
@@ -0,0 +1,581 @@
+use bevy::prelude::*;
+
+use crate::systems::interaction::{Disabled, RepeatTimer, UiInteractionState};
+
+pub mod audio;
+pub mod controls;
+pub mod dropdown;
+pub mod gallery;
+pub mod levels;
+pub mod pages;
+pub mod slider;
+pub mod video;
+
+/// What navigating past the ends of a [`SelectableMenu`] does.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SelectableWrap {
+    /// Past the last row comes the first again.
+    #[default]
+    Wrap,
+    /// The selection stops at the ends.
+    Clamp,
+}
+
+/// Keyboard-navigable list selection. The menu tracks which index is
+/// selected; option entities render themselves from it.
+#[derive(Component, Debug, Clone)]
+pub struct SelectableMenu {
+    pub selected: usize,
+    pub len: usize,
+    pub up_keys: Vec<KeyCode>,
+    pub down_keys: Vec<KeyCode>,
+    pub select_keys: Vec<KeyCode>,
+    pub wrap: SelectableWrap,
+    /// Navigation steps past [`Disabled`] rows instead of landing on
+    /// them. On by default; activation ignores disabled rows either way.
+    pub skip_disabled: bool,
+    /// True for the frame the select key lands.
+    pub select_triggered: bool,
+    /// Indices navigation skips and activation ignores; kept in sync
+    /// with rows carrying [`Disabled`].
+    pub disabled_indices: Vec<usize>,
+}
+
+impl SelectableMenu {
+    /// Compatibility shim over [`Self::with_wrap`]: `true` wraps, `false`
+    /// clamps at the ends.
+    pub fn new(
+        len: usize,
+        up_keys: Vec<KeyCode>,
+        down_keys: Vec<KeyCode>,
+        select_keys: Vec<KeyCode>,
+        wrap: bool,
+    ) -> Self {
+        Self::with_wrap(
+            len,
+            up_keys,
+            down_keys,
+            select_keys,
+            if wrap {
+                SelectableWrap::Wrap
+            } else {
+                SelectableWrap::Clamp
+            },
+        )
+    }
+
+    /// Constructor with the end-of-list behaviour spelled out.
+    pub fn with_wrap(
+        len: usize,
+        up_keys: Vec<KeyCode>,
+        down_keys: Vec<KeyCode>,
+        select_keys: Vec<KeyCode>,
+        wrap: SelectableWrap,
+    ) -> Self {
+        Self {
+            selected: 0,
+            len,
+            up_keys,
+            down_keys,
+            select_keys,
+            wrap,
+            skip_disabled: true,
+            select_triggered: false,
+            disabled_indices: Vec::new(),
+        }
+    }
+
+    /// Lets navigation land on disabled rows (activation still ignores
+    /// them) — for lists that grey rows out but keep them inspectable.
+    pub fn keeping_disabled_reachable(mut self) -> Self {
+        self.skip_disabled = false;
+        self
+    }
+
+    pub fn is_enabled(&self, index: usize) -> bool {
+        !self.disabled_indices.contains(&index)
+    }
+
+    /// Moves the selection by `delta`, wrapping or clamping at the ends
+    /// and stepping past disabled indices. With every reachable index
+    /// disabled the selection stays put.
+    pub fn navigate(&mut self, delta: i32) {
+        if self.len == 0 {
+            return;
+        }
+        let len = self.len as i32;
+        let dir = if delta < 0 { -1 } else { 1 };
+        let mut index = self.selected as i32 + delta;
+        for _ in 0..self.len {
+            let candidate = match self.wrap {
+                SelectableWrap::Wrap => index.rem_euclid(len),
+                SelectableWrap::Clamp => index.clamp(0, len - 1),
+            };
+            if !self.skip_disabled || self.is_enabled(candidate as usize) {
+                self.selected = candidate as usize;
+                return;
+            }
+            if self.wrap == SelectableWrap::Clamp
+                && (candidate == 0 && dir < 0 || candidate == len - 1 && dir > 0)
+            {
+                return;
+            }
+            index = candidate + dir;
+        }
+    }
+
+    /// Jumps to the first enabled index, as for a Home press.
+    pub fn select_first(&mut self) {
+        if let Some(index) = (0..self.len).find(|index| self.is_enabled(*index)) {
+            self.selected = index;
+        }
+    }
+
+    /// Jumps to the last enabled index, as for an End press.
+    pub fn select_last(&mut self) {
+        if let Some(index) = (0..self.len).rev().find(|index| self.is_enabled(*index)) {
+            self.selected = index;
+        }
+    }
+}
+
+/// Opt-in first-letter type-ahead for a [`SelectableMenu`] whose rows
+/// are textual (resolution lists, future font or device pickers). Keep
+/// `labels` in step with the rendered rows; menus with letter shortcut
+/// keys should not carry this.
+#[derive(Component, Debug, Clone, Default)]
+pub struct MenuTypeAhead {
+    pub labels: Vec<String>,
+}
+
+/// Next index after `selected` (cycling) whose label starts with
+/// `letter`, ignoring case. `None` when nothing matches.
+pub fn type_ahead_target(labels: &[String], selected: usize, letter: char) -> Option<usize> {
+    (1..=labels.len())
+        .map(|step| (selected + step) % labels.len())
+        .find(|index| {
+            labels[*index]
+                .chars()
+                .next()
+                .is_some_and(|first| first.eq_ignore_ascii_case(&letter))
+        })
+}
+
+const LETTER_KEYS: [(KeyCode, char); 26] = [
+    (KeyCode::KeyA, 'a'),
+    (KeyCode::KeyB, 'b'),
+    (KeyCode::KeyC, 'c'),
+    (KeyCode::KeyD, 'd'),
+    (KeyCode::KeyE, 'e'),
+    (KeyCode::KeyF, 'f'),
+    (KeyCode::KeyG, 'g'),
+    (KeyCode::KeyH, 'h'),
+    (KeyCode::KeyI, 'i'),
+    (KeyCode::KeyJ, 'j'),
+    (KeyCode::KeyK, 'k'),
+    (KeyCode::KeyL, 'l'),
+    (KeyCode::KeyM, 'm'),
+    (KeyCode::KeyN, 'n'),
+    (KeyCode::KeyO, 'o'),
+    (KeyCode::KeyP, 'p'),
+    (KeyCode::KeyQ, 'q'),
+    (KeyCode::KeyR, 'r'),
+    (KeyCode::KeyS, 's'),
+    (KeyCode::KeyT, 't'),
+    (KeyCode::KeyU, 'u'),
+    (KeyCode::KeyV, 'v'),
+    (KeyCode::KeyW, 'w'),
+    (KeyCode::KeyX, 'x'),
+    (KeyCode::KeyY, 'y'),
+    (KeyCode::KeyZ, 'z'),
+];
+
+/// The letter key pressed this frame, if any.
+pub fn pressed_letter(keys: &ButtonInput<KeyCode>) -> Option<char> {
+    LETTER_KEYS
+        .iter()
+        .find(|(key, _)| keys.just_pressed(*key))
+        .map(|(_, letter)| *letter)
+}
+
+/// Shared repeat timers for held menu navigation keys.
+#[derive(Resource)]
+pub struct MenuNavRepeat {
+    pub up: RepeatTimer,
+    pub down: RepeatTimer,
+}
+
+impl Default for MenuNavRepeat {
+    fn default() -> Self {
+        Self {
+            up: RepeatTimer::menu_nav(),
+            down: RepeatTimer::menu_nav(),
+        }
+    }
+}
+
+/// Gamepad mapping for menu navigation. Any connected pad drives the
+/// same selection path as the keyboard.
+#[derive(Resource, Debug, Clone)]
+pub struct GamepadMenuConfig {
+    pub up_buttons: Vec<GamepadButton>,
+    pub down_buttons: Vec<GamepadButton>,
+    pub confirm_buttons: Vec<GamepadButton>,
+    /// Cancel pops the current menu page, like Backspace.
+    pub cancel_buttons: Vec<GamepadButton>,
+    /// Stick deflection past this counts as a navigation press.
+    pub stick_threshold: f32,
+}
+
+impl Default for GamepadMenuConfig {
+    fn default() -> Self {
+        Self {
+            up_buttons: vec![GamepadButton::DPadUp],
+            down_buttons: vec![GamepadButton::DPadDown],
+            confirm_buttons: vec![GamepadButton::South],
+            cancel_buttons: vec![GamepadButton::East],
+            stick_threshold: 0.5,
+        }
+    }
+}
+
+/// D-pad repeat timers plus the stick debounce latch.
+#[derive(Resource)]
+pub struct GamepadNavState {
+    up: RepeatTimer,
+    down: RepeatTimer,
+    /// Last stick direction (-1, 0, 1); a tilt only counts again after
+    /// the stick re-centres or flips.
+    stick_dir: i8,
+}
+
+impl Default for GamepadNavState {
+    fn default() -> Self {
+        Self {
+            up: RepeatTimer::menu_nav(),
+            down: RepeatTimer::menu_nav(),
+            stick_dir: 0,
+        }
+    }
+}
+
+/// Debounces a stick axis into discrete navigation steps: returns the
+/// new direction only on the frame the stick crosses the threshold.
+fn stick_nav_step(previous_dir: &mut i8, axis: f32, threshold: f32) -> i8 {
+    let dir = if axis > threshold {
+        1
+    } else if axis < -threshold {
+        -1
+    } else {
+        0
+    };
+    let step = if dir != *previous_dir { dir } else { 0 };
+    *previous_dir = dir;
+    step
+}
+
+fn any_pressed(keys: &ButtonInput<KeyCode>, wanted: &[KeyCode]) -> bool {
+    wanted.iter().any(|key| keys.pressed(*key))
+}
+
+fn any_just_pressed(keys: &ButtonInput<KeyCode>, wanted: &[KeyCode]) -> bool {
+    wanted.iter().any(|key| keys.just_pressed(*key))
+}
+
+/// Mirrors rows carrying [`Disabled`] into their menu's skip list so
+/// navigation and activation agree on what is selectable.
+pub fn sync_menu_disabled_indices(
+    mut menus: Query<(Entity, &mut SelectableMenu)>,
+    rows: Query<(&pages::MenuOptionRow, Has<Disabled>)>,
+) {
+    for (entity, mut menu) in &mut menus {
+        let mut disabled: Vec<usize> = rows
+            .iter()
+            .filter(|(row, _)| row.content == entity)
+            .filter_map(|(row, is_disabled)| is_disabled.then_some(row.index))
+            .collect();
+        disabled.sort_unstable();
+        if menu.disabled_indices != disabled {
+            menu.disabled_indices = disabled;
+        }
+    }
+}
+
+/// Drives selection up/down/select from the keyboard with held-key
+/// repeat, plus Home/End jumps and first-letter type-ahead where a menu
+/// opts in. Suspended while a text field owns typing.
+pub fn handle_selectable_menu_navigation(
+    time: Res<Time>,
+    keys: Res<ButtonInput<KeyCode>>,
+    state: Res<UiInteractionState>,
+    mut repeat: ResMut<MenuNavRepeat>,
+    mut menus: Query<(&mut SelectableMenu, Option<&MenuTypeAhead>)>,
+) {
+    if state.text_input_focus.is_some() {
+        return;
+    }
+    let letter = pressed_letter(&keys);
+    for (mut menu, type_ahead) in &mut menus {
+        menu.select_triggered = false;
+        let up_held = any_pressed(&keys, &menu.up_keys);
+        let up_pressed = any_just_pressed(&keys, &menu.up_keys);
+        let down_held = any_pressed(&keys, &menu.down_keys);
+        let down_pressed = any_just_pressed(&keys, &menu.down_keys);
+        if repeat.up.tick(up_held, up_pressed, time.delta_secs()) && up_held {
+            menu.navigate(-1);
+        }
+        if repeat.down.tick(down_held, down_pressed, time.delta_secs()) && down_held {
+            menu.navigate(1);
+        }
+        if keys.just_pressed(KeyCode::Home) {
+            menu.select_first();
+        }
+        if keys.just_pressed(KeyCode::End) {
+            menu.select_last();
+        }
+        if let (Some(type_ahead), Some(letter)) = (type_ahead, letter) {
+            if let Some(index) = type_ahead_target(&type_ahead.labels, menu.selected, letter) {
+                if menu.is_enabled(index) {
+                    menu.selected = index;
+                }
+            }
+        }
+        if any_just_pressed(&keys, &menu.select_keys) {
+            menu.select_triggered = true;
+        }
+    }
+}
+
+/// Drives selection from any connected gamepad: D-pad with held repeat,
+/// left stick debounced to one move per tilt, confirm feeding the same
+/// `select_triggered` path as the keyboard and cancel popping the page.
+pub fn handle_gamepad_menu_navigation(
+    time: Res<Time>,
+    config: Res<GamepadMenuConfig>,
+    state: Res<UiInteractionState>,
+    mut nav: ResMut<GamepadNavState>,
+    gamepads: Query<&Gamepad>,
+    mut events: EventWriter<pages::MenuCommandEvent>,
+    mut menus: Query<(Entity, &mut SelectableMenu)>,
+    contents: Query<&pages::MenuPageContent>,
+) {
+    if state.text_input_focus.is_some() {
+        return;
+    }
+    let mut up_held = false;
+    let mut up_pressed = false;
+    let mut down_held = false;
+    let mut down_pressed = false;
+    let mut confirm = false;
+    let mut cancel = false;
+    let mut stick_y: f32 = 0.0;
+    for gamepad in &gamepads {
+        up_held |= config.up_buttons.iter().any(|b| gamepad.pressed(*b));
+        up_pressed |= config.up_buttons.iter().any(|b| gamepad.just_pressed(*b));
+        down_held |= config.down_buttons.iter().any(|b| gamepad.pressed(*b));
+        down_pressed |= config.down_buttons.iter().any(|b| gamepad.just_pressed(*b));
+        confirm |= config.confirm_buttons.iter().any(|b| gamepad.just_pressed(*b));
+        cancel |= config.cancel_buttons.iter().any(|b| gamepad.just_pressed(*b));
+        let y = gamepad.left_stick().y;
+        if y.abs() > stick_y.abs() {
+            stick_y = y;
+        }
+    }
+    let stick_step = {
+        let mut dir = nav.stick_dir;
+        let step = stick_nav_step(&mut dir, stick_y, config.stick_threshold);
+        nav.stick_dir = dir;
+        step
+    };
+    let up_step =
+        (nav.up.tick(up_held, up_pressed, time.delta_secs()) && up_held) || stick_step > 0;
+    let down_step =
+        (nav.down.tick(down_held, down_pressed, time.delta_secs()) && down_held) || stick_step < 0;
+    for (entity, mut menu) in &mut menus {
+        if up_step {
+            menu.navigate(-1);
+        }
+        if down_step {
+            menu.navigate(1);
+        }
+        if confirm {
+            menu.select_triggered = true;
+        }
+        if cancel {
+            if let Ok(content) = contents.get(entity) {
+                events.write(pages::MenuCommandEvent {
+                    root: content.root,
+                    command: pages::MenuCommand::Pop,
+                });
+            }
+        }
+    }
+}
+
+pub struct MenuPlugin;
+
+impl Plugin for MenuPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, video::spawn_brightness_overlay)
+            .init_resource::<MenuNavRepeat>()
+            .init_resource::<GamepadMenuConfig>()
+            .init_resource::<GamepadNavState>()
+            .init_resource::<pages::UiScale>()
+            .init_resource::<pages::MenuStackMemory>()
+            .init_resource::<video::VideoSettingsState>()
+            .init_resource::<video::ScreenBrightness>()
+            .init_resource::<audio::AudioSettingsState>()
+            .init_resource::<controls::KeybindState>()
+            .add_event::<pages::MenuCommandEvent>()
+            .add_plugins((dropdown::DropdownPlugin, crate::ui::table::TablePlugin))
+            .add_systems(
+                Update,
+                (
+                    sync_menu_disabled_indices,
+                    handle_selectable_menu_navigation,
+                    handle_gamepad_menu_navigation,
+                )
+                    .chain(),
+            )
+            .add_systems(
+                Update,
+                (
+                    // Intent capture first, then command consumers, then
+                    // the visual rebuild/refresh passes.
+                    (
+                        controls::capture_rebind_key,
+                        pages::handle_menu_option_activation,
+                        pages::handle_option_cycler_commands,
+                        pages::handle_option_cycler_wheel,
+                        pages::sync_option_shortcut_tooltips,
+                        video::handle_video_modal_shortcuts,
+                        video::sync_video_modal_button_visuals,
+                        video::sync_resolution_dropdown_items,
+                    ),
+                    (
+                        pages::execute_menu_navigation,
+                        video::handle_video_menu_commands,
+                        audio::handle_audio_menu_commands,
+                        controls::handle_keybind_commands,
+                    ),
+                    (
+                        pages::reflow_pages_on_ui_scale_change,
+                        pages::remember_menu_stacks,
+                        pages::rebuild_menu_page,
+                        pages::animate_menu_transitions,
+                        pages::sync_menu_option_visuals,
+                        video::populate_video_page,
+                        video::refresh_video_table,
+                        video::drag_brightness_slider,
+                        video::sync_brightness_overlay,
+                        video::update_confirm_countdown_modals,
+                        video::close_resolved_apply_modals,
+                        audio::populate_audio_page,
+                        audio::refresh_audio_table,
+                        slider::populate_audio_sliders,
+                        slider::drag_volume_sliders,
+                        slider::fine_adjust_selected_slider,
+                        slider::sync_slider_feedback,
+                        audio::apply_audio_settings,
+                        controls::populate_controls_page,
+                        controls::refresh_controls_table,
+                        levels::sync_level_select_locks,
+                        gallery::populate_endings_page,
+                        gallery::refresh_endings_table,
+                    ),
+                )
+                    .chain()
+                    .after(handle_gamepad_menu_navigation),
+            );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn navigation_wraps_and_clamps() {
+        let mut wrapping = SelectableMenu::new(3, vec![], vec![], vec![], true);
+        wrapping.navigate(-1);
+        assert_eq!(wrapping.selected, 2);
+        let mut clamping = SelectableMenu::new(3, vec![], vec![], vec![], false);
+        clamping.navigate(-1);
+        assert_eq!(clamping.selected, 0);
+        clamping.navigate(5);
+        assert_eq!(clamping.selected, 2);
+    }
+
+    #[test]
+    fn navigation_skips_disabled_indices() {
+        let mut menu = SelectableMenu::new(4, vec![], vec![], vec![], true);
+        menu.disabled_indices = vec![1, 3];
+        menu.navigate(1);
+        assert_eq!(menu.selected, 2);
+        // Wrapping down from index 0 skips the disabled index 3.
+        menu.selected = 0;
+        menu.navigate(-1);
+        assert_eq!(menu.selected, 2);
+        // Everything disabled: the selection stays where it is.
+        menu.disabled_indices = vec![0, 1, 2, 3];
+        menu.selected = 0;
+        menu.navigate(1);
+        assert_eq!(menu.selected, 0);
+    }
+
+    #[test]
+    fn the_boolean_shim_maps_onto_the_explicit_wrap_modes() {
+        let shimmed = SelectableMenu::new(3, vec![], vec![], vec![], false);
+        assert_eq!(shimmed.wrap, SelectableWrap::Clamp);
+        let mut explicit =
+            SelectableMenu::with_wrap(3, vec![], vec![], vec![], SelectableWrap::Wrap);
+        explicit.navigate(-1);
+        assert_eq!(explicit.selected, 2);
+    }
+
+    #[test]
+    fn reachable_disabled_rows_take_the_selection_but_not_activation() {
+        let mut menu = SelectableMenu::with_wrap(3, vec![], vec![], vec![], SelectableWrap::Wrap)
+            .keeping_disabled_reachable();
+        menu.disabled_indices = vec![1];
+        menu.navigate(1);
+        assert_eq!(menu.selected, 1);
+        assert!(!menu.is_enabled(menu.selected));
+    }
+
+    #[test]
+    fn home_and_end_jump_to_the_nearest_enabled_row() {
+        let mut menu = SelectableMenu::new(5, vec![], vec![], vec![], true);
+        menu.disabled_indices = vec![0, 4];
+        menu.selected = 2;
+        menu.select_first();
+        assert_eq!(menu.selected, 1);
+        menu.select_last();
+        assert_eq!(menu.selected, 3);
+    }
+
+    #[test]
+    fn type_ahead_cycles_through_matching_first_letters() {
+        let labels: Vec<String> = ["Native", "Narrow", "Wide"]
+            .into_iter()
+            .map(String::from)
+            .collect();
+        assert_eq!(type_ahead_target(&labels, 0, 'n'), Some(1));
+        // Wraps back round to the first match.
+        assert_eq!(type_ahead_target(&labels, 1, 'N'), Some(0));
+        assert_eq!(type_ahead_target(&labels, 0, 'w'), Some(2));
+        assert_eq!(type_ahead_target(&labels, 0, 'z'), None);
+        assert_eq!(type_ahead_target(&[], 0, 'n'), None);
+    }
+
+    #[test]
+    fn stick_tilt_moves_once_until_recentred() {
+        let mut dir = 0;
+        assert_eq!(stick_nav_step(&mut dir, 0.9, 0.5), 1);
+        // Still tilted: no further steps.
+        assert_eq!(stick_nav_step(&mut dir, 0.9, 0.5), 0);
+        assert_eq!(stick_nav_step(&mut dir, 0.2, 0.5), 0);
+        // Re-armed after centring.
+        assert_eq!(stick_nav_step(&mut dir, -0.8, 0.5), -1);
+    }
+}
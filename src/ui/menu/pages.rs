@@ -0,0 +1,1097 @@
+use std::collections::HashMap;
+
+use bevy::{input::mouse::MouseWheel, prelude::*};
+
+use crate::{
+    data::{
+        settings::{AudioChannel, UserSettings},
+        states::MainState,
+    },
+    scenes::dilemma::{CurrentDilemma, DilemmaId},
+    systems::{
+        colors::{DIM_COLOR, HIGHLIGHT_COLOR, SYSTEM_MENU_COLOR},
+        interaction::{Clickable, CustomCursor, Disabled, Hoverable, InteractionVisualPalette},
+    },
+    ui::{
+        menu::SelectableMenu,
+        scroll::{ContentSize, ScrollState, ScrollableRoot, SCROLL_EPSILON},
+        tooltip::Tooltip,
+        window::{Window, WindowContent, WindowTitle},
+    },
+};
+
+pub const UI_SCALE_MIN: f32 = 0.8;
+pub const UI_SCALE_MAX: f32 = 1.5;
+/// One cycler step of UI scale (10%).
+pub const UI_SCALE_STEP: f32 = 0.1;
+
+/// Runtime UI scale factor, adjustable from the video page. Base font
+/// sizes stay as constants; every spawned size resolves through this.
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct UiScale(pub f32);
+
+impl Default for UiScale {
+    fn default() -> Self {
+        Self(UserSettings::load().interface.ui_scale)
+    }
+}
+
+impl UiScale {
+    /// Steps the scale by `delta`, clamped to the supported range.
+    pub fn adjust(&mut self, delta: i8) {
+        self.0 = (self.0 + delta as f32 * UI_SCALE_STEP).clamp(UI_SCALE_MIN, UI_SCALE_MAX);
+    }
+
+    pub fn percent_label(&self) -> String {
+        format!("{:.0}%", self.0 * 100.0)
+    }
+}
+
+/// Resolves a base font size through the global UI scale.
+pub fn scaled_font_size(scale: &UiScale, base: f32) -> f32 {
+    base * scale.0
+}
+
+pub const MENU_OPTION_ROW_HEIGHT: f32 = 26.0;
+pub const MENU_OPTION_FONT_SIZE: f32 = 16.0;
+
+/// Which surface a menu window belongs to; each host keeps its own
+/// independent page stack.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MenuHost {
+    Main,
+    Pause,
+    Debug,
+}
+
+/// A navigable menu page. Pages are static definitions; dynamic content
+/// (tables, dropdowns) is attached by each page's own module.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MenuPage {
+    MainRoot,
+    PauseRoot,
+    Options,
+    Video,
+    Audio,
+    Controls,
+    LevelSelect,
+    Endings,
+}
+
+/// What activating a menu option does. Navigation commands are handled
+/// here; settings commands are consumed by the video/audio modules.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MenuCommand {
+    None,
+    Push(MenuPage),
+    Pop,
+    Quit,
+    CycleDisplayMode(i8),
+    CycleVsync(i8),
+    CycleResolution(i8),
+    SetResolutionIndex(usize),
+    CycleFrameLimit(i8),
+    AdjustUiScale(i8),
+    CycleColorScheme(i8),
+    ApplyVideoSettings,
+    ConfirmVideoSettings,
+    RevertVideoSettings,
+    AdjustVolume(AudioChannel, i8),
+    /// Jump straight into the named dilemma.
+    LoadDilemma(DilemmaId),
+    /// Open the sandbox dilemma builder.
+    OpenSandbox,
+    /// Start a rampage run, once unlocked.
+    OpenRampage,
+    /// Open the lever heaven toy, once unlocked.
+    OpenLeverHeaven,
+    /// Tear the live dilemma down and respawn it from its definition.
+    RestartDilemma,
+    /// Open the listening modal to rebind the named action.
+    StartRebind(&'static str),
+    CancelRebind,
+    ResetKeybinds,
+}
+
+/// One option line in a page definition.
+#[derive(Debug, Clone, Copy)]
+pub struct MenuOptionDef {
+    pub label: &'static str,
+    /// Stable identifier used by keybinding overrides.
+    pub action: &'static str,
+    /// Default shortcut; [`KeybindState`](super::controls::KeybindState)
+    /// overrides take precedence at runtime.
+    pub shortcut: Option<KeyCode>,
+    pub command: MenuCommand,
+}
+
+/// A page's static layout: window title plus its option rows.
+#[derive(Debug, Clone, Copy)]
+pub struct MenuPageDef {
+    pub title: &'static str,
+    pub options: &'static [MenuOptionDef],
+}
+
+pub const MAIN_MENU_OPTIONS: &[MenuOptionDef] = &[
+    MenuOptionDef {
+        label: "LEVELS",
+        action: "main.levels",
+        shortcut: Some(KeyCode::KeyL),
+        command: MenuCommand::Push(MenuPage::LevelSelect),
+    },
+    MenuOptionDef {
+        label: "ENDINGS",
+        action: "main.endings",
+        shortcut: Some(KeyCode::KeyE),
+        command: MenuCommand::Push(MenuPage::Endings),
+    },
+    MenuOptionDef {
+        label: "SANDBOX",
+        action: "main.sandbox",
+        shortcut: Some(KeyCode::KeyS),
+        command: MenuCommand::OpenSandbox,
+    },
+    MenuOptionDef {
+        label: "RAMPAGE",
+        action: "main.rampage",
+        shortcut: Some(KeyCode::KeyR),
+        command: MenuCommand::OpenRampage,
+    },
+    MenuOptionDef {
+        label: "LEVER HEAVEN",
+        action: "main.lever_heaven",
+        shortcut: Some(KeyCode::KeyH),
+        command: MenuCommand::OpenLeverHeaven,
+    },
+    MenuOptionDef {
+        label: "OPTIONS",
+        action: "main.options",
+        shortcut: Some(KeyCode::KeyO),
+        command: MenuCommand::Push(MenuPage::Options),
+    },
+    MenuOptionDef {
+        label: "QUIT",
+        action: "main.quit",
+        shortcut: Some(KeyCode::KeyQ),
+        command: MenuCommand::Quit,
+    },
+];
+
+pub const PAUSE_MENU_OPTIONS: &[MenuOptionDef] = &[
+    MenuOptionDef {
+        label: "RESTART",
+        action: "pause.restart",
+        shortcut: Some(KeyCode::KeyR),
+        command: MenuCommand::RestartDilemma,
+    },
+    MenuOptionDef {
+        label: "OPTIONS",
+        action: "pause.options",
+        shortcut: Some(KeyCode::KeyO),
+        command: MenuCommand::Push(MenuPage::Options),
+    },
+    MenuOptionDef {
+        label: "BACK",
+        action: "pause.back",
+        shortcut: Some(KeyCode::Backspace),
+        command: MenuCommand::Pop,
+    },
+];
+
+pub const OPTIONS_MENU_OPTIONS: &[MenuOptionDef] = &[
+    MenuOptionDef {
+        label: "VIDEO",
+        action: "options.video",
+        shortcut: Some(KeyCode::KeyV),
+        command: MenuCommand::Push(MenuPage::Video),
+    },
+    MenuOptionDef {
+        label: "AUDIO",
+        action: "options.audio",
+        shortcut: Some(KeyCode::KeyA),
+        command: MenuCommand::Push(MenuPage::Audio),
+    },
+    MenuOptionDef {
+        label: "CONTROLS",
+        action: "options.controls",
+        shortcut: Some(KeyCode::KeyC),
+        command: MenuCommand::Push(MenuPage::Controls),
+    },
+    MenuOptionDef {
+        label: "BACK",
+        action: "options.back",
+        shortcut: Some(KeyCode::Backspace),
+        command: MenuCommand::Pop,
+    },
+];
+
+/// Video rows show blank labels; the options table drawn alongside names
+/// each row, so only the shortcut wiring lives here.
+pub const VIDEO_MENU_OPTIONS: &[MenuOptionDef] = &[
+    MenuOptionDef {
+        label: "",
+        action: "video.display_mode",
+        shortcut: Some(KeyCode::KeyD),
+        command: MenuCommand::CycleDisplayMode(1),
+    },
+    MenuOptionDef {
+        label: "",
+        action: "video.vsync",
+        shortcut: Some(KeyCode::KeyV),
+        command: MenuCommand::CycleVsync(1),
+    },
+    MenuOptionDef {
+        label: "",
+        action: "video.resolution",
+        shortcut: Some(KeyCode::KeyR),
+        command: MenuCommand::CycleResolution(1),
+    },
+    MenuOptionDef {
+        label: "",
+        action: "video.frame_limit",
+        shortcut: Some(KeyCode::KeyL),
+        command: MenuCommand::CycleFrameLimit(1),
+    },
+    MenuOptionDef {
+        label: "",
+        action: "video.ui_scale",
+        shortcut: Some(KeyCode::KeyI),
+        command: MenuCommand::AdjustUiScale(1),
+    },
+    MenuOptionDef {
+        label: "",
+        action: "video.colorblind",
+        shortcut: Some(KeyCode::KeyC),
+        command: MenuCommand::CycleColorScheme(1),
+    },
+    MenuOptionDef {
+        label: "APPLY",
+        action: "video.apply",
+        shortcut: Some(KeyCode::Enter),
+        command: MenuCommand::ApplyVideoSettings,
+    },
+    MenuOptionDef {
+        label: "BACK",
+        action: "video.back",
+        shortcut: Some(KeyCode::Backspace),
+        command: MenuCommand::Pop,
+    },
+];
+
+pub const AUDIO_MENU_OPTIONS: &[MenuOptionDef] = &[
+    MenuOptionDef {
+        label: "",
+        action: "audio.master",
+        shortcut: Some(KeyCode::KeyM),
+        command: MenuCommand::AdjustVolume(AudioChannel::Master, 1),
+    },
+    MenuOptionDef {
+        label: "",
+        action: "audio.music",
+        shortcut: Some(KeyCode::KeyU),
+        command: MenuCommand::AdjustVolume(AudioChannel::Music, 1),
+    },
+    MenuOptionDef {
+        label: "",
+        action: "audio.sfx",
+        shortcut: Some(KeyCode::KeyF),
+        command: MenuCommand::AdjustVolume(AudioChannel::Sfx, 1),
+    },
+    MenuOptionDef {
+        label: "",
+        action: "audio.ui",
+        shortcut: Some(KeyCode::KeyI),
+        command: MenuCommand::AdjustVolume(AudioChannel::Ui, 1),
+    },
+    MenuOptionDef {
+        label: "BACK",
+        action: "audio.back",
+        shortcut: Some(KeyCode::Backspace),
+        command: MenuCommand::Pop,
+    },
+];
+
+/// Every authored dilemma, in campaign order. Locked entries get the
+/// `Disabled` treatment at runtime rather than being filtered out.
+pub const LEVEL_SELECT_MENU_OPTIONS: &[MenuOptionDef] = &[
+    MenuOptionDef {
+        label: "LAB RAT",
+        action: "levels.lab_rat",
+        shortcut: None,
+        command: MenuCommand::LoadDilemma(DilemmaId("lab_rat")),
+    },
+    MenuOptionDef {
+        label: "THE CLASSIC",
+        action: "levels.classic",
+        shortcut: None,
+        command: MenuCommand::LoadDilemma(DilemmaId("classic")),
+    },
+    MenuOptionDef {
+        label: "CROWDED TRACK",
+        action: "levels.crowded_track",
+        shortcut: None,
+        command: MenuCommand::LoadDilemma(DilemmaId("crowded_track")),
+    },
+    MenuOptionDef {
+        label: "SELF PRESERVATION",
+        action: "levels.self_preservation",
+        shortcut: None,
+        command: MenuCommand::LoadDilemma(DilemmaId("self_preservation")),
+    },
+    MenuOptionDef {
+        label: "BACK",
+        action: "levels.back",
+        shortcut: Some(KeyCode::Backspace),
+        command: MenuCommand::Pop,
+    },
+];
+
+/// Gallery rows live in the endings table; only BACK is an option row.
+pub const ENDINGS_MENU_OPTIONS: &[MenuOptionDef] = &[MenuOptionDef {
+    label: "BACK",
+    action: "endings.back",
+    shortcut: Some(KeyCode::Backspace),
+    command: MenuCommand::Pop,
+}];
+
+/// The rebindable shortcuts listed on the controls page, one row each,
+/// followed by the reset and back rows.
+pub const CONTROLS_MENU_OPTIONS: &[MenuOptionDef] = &[
+    MenuOptionDef {
+        label: "DISPLAY MODE",
+        action: "controls.rebind_display_mode",
+        shortcut: None,
+        command: MenuCommand::StartRebind("video.display_mode"),
+    },
+    MenuOptionDef {
+        label: "VSYNC",
+        action: "controls.rebind_vsync",
+        shortcut: None,
+        command: MenuCommand::StartRebind("video.vsync"),
+    },
+    MenuOptionDef {
+        label: "RESOLUTION",
+        action: "controls.rebind_resolution",
+        shortcut: None,
+        command: MenuCommand::StartRebind("video.resolution"),
+    },
+    MenuOptionDef {
+        label: "APPLY SETTINGS",
+        action: "controls.rebind_apply",
+        shortcut: None,
+        command: MenuCommand::StartRebind("video.apply"),
+    },
+    MenuOptionDef {
+        label: "MASTER VOLUME",
+        action: "controls.rebind_master",
+        shortcut: None,
+        command: MenuCommand::StartRebind("audio.master"),
+    },
+    MenuOptionDef {
+        label: "MUSIC VOLUME",
+        action: "controls.rebind_music",
+        shortcut: None,
+        command: MenuCommand::StartRebind("audio.music"),
+    },
+    MenuOptionDef {
+        label: "SFX VOLUME",
+        action: "controls.rebind_sfx",
+        shortcut: None,
+        command: MenuCommand::StartRebind("audio.sfx"),
+    },
+    MenuOptionDef {
+        label: "RESET TO DEFAULTS",
+        action: "controls.reset",
+        shortcut: None,
+        command: MenuCommand::ResetKeybinds,
+    },
+    MenuOptionDef {
+        label: "BACK",
+        action: "controls.back",
+        shortcut: Some(KeyCode::Backspace),
+        command: MenuCommand::Pop,
+    },
+];
+
+/// Static definition for each page.
+pub fn page_definition(page: MenuPage) -> MenuPageDef {
+    match page {
+        MenuPage::MainRoot => MenuPageDef {
+            title: "MORALITY ENGINE",
+            options: MAIN_MENU_OPTIONS,
+        },
+        MenuPage::PauseRoot => MenuPageDef {
+            title: "PAUSED",
+            options: PAUSE_MENU_OPTIONS,
+        },
+        MenuPage::Options => MenuPageDef {
+            title: "OPTIONS",
+            options: OPTIONS_MENU_OPTIONS,
+        },
+        MenuPage::Video => MenuPageDef {
+            title: "VIDEO",
+            options: VIDEO_MENU_OPTIONS,
+        },
+        MenuPage::Audio => MenuPageDef {
+            title: "AUDIO",
+            options: AUDIO_MENU_OPTIONS,
+        },
+        MenuPage::Controls => MenuPageDef {
+            title: "CONTROLS",
+            options: CONTROLS_MENU_OPTIONS,
+        },
+        MenuPage::LevelSelect => MenuPageDef {
+            title: "LEVELS",
+            options: LEVEL_SELECT_MENU_OPTIONS,
+        },
+        MenuPage::Endings => MenuPageDef {
+            title: "ENDINGS",
+            options: ENDINGS_MENU_OPTIONS,
+        },
+    }
+}
+
+/// Seconds a page slide transition takes.
+pub const MENU_TRANSITION_SECS: f32 = 0.18;
+
+/// Pixels a page travels while sliding in or out.
+pub const MENU_TRANSITION_SLIDE: f32 = 60.0;
+
+/// How a [`MenuStack`] last changed; decides which way the next page
+/// transition slides.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MenuNavDirection {
+    /// Pushed deeper; content slides leftwards.
+    Forward,
+    /// Popped back out; content slides rightwards.
+    Back,
+    /// Rebuilt in place (initial spawn, UI-scale reflow); no slide.
+    #[default]
+    None,
+}
+
+impl MenuNavDirection {
+    /// Signed slide sense: `+1` forward, `-1` back, `0` in place.
+    pub fn sign(self) -> f32 {
+        match self {
+            Self::Forward => 1.0,
+            Self::Back => -1.0,
+            Self::None => 0.0,
+        }
+    }
+}
+
+/// Page navigation stack on a menu window root. Mutating it triggers a
+/// page rebuild.
+#[derive(Component, Debug, Clone)]
+pub struct MenuStack {
+    pub frames: Vec<MenuPage>,
+    pub last_move: MenuNavDirection,
+}
+
+impl MenuStack {
+    pub fn new(root: MenuPage) -> Self {
+        Self::from_frames(vec![root])
+    }
+
+    /// A stack resuming from remembered frames; empty input falls back
+    /// to the pause root rather than violating the never-empty rule.
+    pub fn from_frames(frames: Vec<MenuPage>) -> Self {
+        let frames = if frames.is_empty() {
+            vec![MenuPage::PauseRoot]
+        } else {
+            frames
+        };
+        Self {
+            frames,
+            last_move: MenuNavDirection::None,
+        }
+    }
+
+    pub fn current(&self) -> MenuPage {
+        *self.frames.last().expect("menu stack is never empty")
+    }
+
+    pub fn push(&mut self, page: MenuPage) {
+        self.frames.push(page);
+        self.last_move = MenuNavDirection::Forward;
+    }
+
+    /// Pops back one page; the root frame stays put.
+    pub fn pop(&mut self) {
+        if self.frames.len() > 1 {
+            self.frames.pop();
+            self.last_move = MenuNavDirection::Back;
+        }
+    }
+}
+
+/// Slide animation on a page content entity. Outgoing content despawns
+/// when the timer runs out; incoming content settles at the origin.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct MenuTransition {
+    pub direction: MenuNavDirection,
+    pub remaining_secs: f32,
+    pub outgoing: bool,
+}
+
+impl MenuTransition {
+    pub fn new(direction: MenuNavDirection, outgoing: bool) -> Self {
+        Self {
+            direction,
+            remaining_secs: MENU_TRANSITION_SECS,
+            outgoing,
+        }
+    }
+}
+
+/// Horizontal offset of transitioning content with `remaining_secs` left
+/// on the clock. Outgoing content eases from the origin towards the exit
+/// side; incoming content eases from the entry side down to the origin.
+pub fn transition_offset(
+    direction: MenuNavDirection,
+    remaining_secs: f32,
+    outgoing: bool,
+) -> f32 {
+    let progress = 1.0 - (remaining_secs / MENU_TRANSITION_SECS).clamp(0.0, 1.0);
+    let eased = progress * progress * (3.0 - 2.0 * progress);
+    if outgoing {
+        -direction.sign() * MENU_TRANSITION_SLIDE * eased
+    } else {
+        direction.sign() * MENU_TRANSITION_SLIDE * (1.0 - eased)
+    }
+}
+
+/// The content entity holding the rows (and page extras) of the page
+/// currently shown in a menu window.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct MenuPageContent {
+    pub root: Entity,
+    pub page: MenuPage,
+}
+
+/// One option row inside a page content entity.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct MenuOptionRow {
+    pub content: Entity,
+    pub index: usize,
+}
+
+/// Left/right value stepper attached to option rows whose value cycles
+/// (display mode, volumes, ...). Arrow keys on the selected row fire the
+/// matching command.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct OptionCycler {
+    pub left: MenuCommand,
+    pub right: MenuCommand,
+}
+
+/// A menu option was activated; consumed by navigation here and by the
+/// settings modules.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct MenuCommandEvent {
+    pub root: Entity,
+    pub command: MenuCommand,
+}
+
+/// Last-open page stacks per host, so reopening a menu returns the
+/// player to the page they left (e.g. deep in Video). Each host keeps
+/// its own independent memory; [`remember_menu_stacks`] records every
+/// stack change, so no despawn path can lose it.
+#[derive(Resource, Debug, Default)]
+pub struct MenuStackMemory {
+    remembered: HashMap<MenuHost, Vec<MenuPage>>,
+}
+
+impl MenuStackMemory {
+    pub fn remember(&mut self, host: MenuHost, frames: &[MenuPage]) {
+        self.remembered.insert(host, frames.to_vec());
+    }
+
+    /// The frames a fresh menu for `host` should open with: the
+    /// remembered stack if it still starts at `root`, otherwise just
+    /// the root.
+    pub fn recall(&self, host: MenuHost, root: MenuPage) -> Vec<MenuPage> {
+        match self.remembered.get(&host) {
+            Some(frames) if frames.first() == Some(&root) => frames.clone(),
+            _ => vec![root],
+        }
+    }
+
+    /// Forgets a host's stack, so its next menu opens at the root.
+    pub fn reset(&mut self, host: MenuHost) {
+        self.remembered.remove(&host);
+    }
+}
+
+/// Records stack changes into [`MenuStackMemory`].
+pub fn remember_menu_stacks(
+    mut memory: ResMut<MenuStackMemory>,
+    stacks: Query<(&MenuHost, &MenuStack), Changed<MenuStack>>,
+) {
+    for (host, stack) in &stacks {
+        memory.remember(*host, &stack.frames);
+    }
+}
+
+/// Spawns a menu window for `host` opening at `page`, restoring the
+/// host's remembered stack if it has one.
+pub fn spawn_menu_root(
+    commands: &mut Commands,
+    memory: &MenuStackMemory,
+    host: MenuHost,
+    page: MenuPage,
+) -> Entity {
+    let stack = MenuStack::from_frames(memory.recall(host, page));
+    commands
+        .spawn((
+            Window {
+                has_close_button: false,
+                resizable: false,
+                ..default()
+            },
+            WindowTitle {
+                text: page_definition(stack.current()).title.to_string(),
+            },
+            stack,
+            host,
+        ))
+        .id()
+}
+
+/// Retires the current page content of `root`. In-place rebuilds despawn
+/// it outright; push/pop navigation starts its slide-out instead and
+/// defers the despawn to [`animate_menu_transitions`].
+pub fn clear_page_content(
+    commands: &mut Commands,
+    root: Entity,
+    direction: MenuNavDirection,
+    contents: &Query<(Entity, &MenuPageContent)>,
+) {
+    for (entity, content) in contents {
+        if content.root == root {
+            if direction == MenuNavDirection::None {
+                commands.entity(entity).despawn();
+            } else {
+                // Drop the page marker so the input and sync systems let
+                // go of the old rows while they slide out.
+                commands
+                    .entity(entity)
+                    .remove::<(MenuPageContent, SelectableMenu)>()
+                    .insert(MenuTransition::new(direction, true));
+            }
+        }
+    }
+}
+
+/// Rebuilds page content whenever a stack changes: rows with click
+/// regions plus a keyboard selection; page-specific extras (tables,
+/// dropdowns) are attached by the owning module once the content entity
+/// exists.
+pub fn rebuild_menu_page(
+    mut commands: Commands,
+    scale: Res<UiScale>,
+    stacks: Query<(Entity, &MenuStack), Changed<MenuStack>>,
+    contents: Query<(Entity, &MenuPageContent)>,
+    mut titles: Query<&mut WindowTitle>,
+) {
+    for (root, stack) in &stacks {
+        let direction = stack.last_move;
+        clear_page_content(&mut commands, root, direction, &contents);
+        let page = stack.current();
+        let definition = page_definition(page);
+        if let Ok(mut title) = titles.get_mut(root) {
+            title.text = definition.title.to_string();
+        }
+
+        let row_count = definition.options.len();
+        let content_height = row_count as f32 * MENU_OPTION_ROW_HEIGHT;
+        let content = commands
+            .spawn((
+                MenuPageContent { root, page },
+                WindowContent::new(root),
+                ContentSize(Vec2::new(200.0, content_height)),
+                SelectableMenu::new(
+                    row_count,
+                    vec![KeyCode::ArrowUp, KeyCode::KeyW],
+                    vec![KeyCode::ArrowDown, KeyCode::KeyS],
+                    vec![KeyCode::Enter, KeyCode::Space],
+                    true,
+                ),
+                Transform::from_xyz(
+                    transition_offset(direction, MENU_TRANSITION_SECS, false),
+                    0.0,
+                    0.0,
+                ),
+                Visibility::Inherited,
+            ))
+            .id();
+        if direction != MenuNavDirection::None {
+            commands
+                .entity(content)
+                .insert(MenuTransition::new(direction, false));
+        }
+        for (index, option) in definition.options.iter().enumerate() {
+            commands.spawn((
+                MenuOptionRow { content, index },
+                Text2d::new(option.label),
+                TextFont::from_font_size(scaled_font_size(&scale, MENU_OPTION_FONT_SIZE)),
+                TextColor(SYSTEM_MENU_COLOR),
+                Clickable::new(Vec2::new(200.0, MENU_OPTION_ROW_HEIGHT)),
+                Transform::from_xyz(
+                    0.0,
+                    -(index as f32 + 0.5) * MENU_OPTION_ROW_HEIGHT,
+                    0.2,
+                ),
+                ChildOf(content),
+            ));
+        }
+    }
+}
+
+/// Keeps a shortcut tooltip on every option row that has one, so rows
+/// with hidden labels (the video page) still reveal their key on hover.
+/// The text resolves through [`KeybindState`] and follows rebinds.
+pub fn sync_option_shortcut_tooltips(
+    mut commands: Commands,
+    keybinds: Res<super::controls::KeybindState>,
+    contents: Query<&MenuPageContent>,
+    rows: Query<(Entity, &MenuOptionRow, &Clickable, Option<&Tooltip>)>,
+) {
+    for (entity, row, clickable, tooltip) in &rows {
+        let Ok(content) = contents.get(row.content) else {
+            continue;
+        };
+        let Some(option) = page_definition(content.page).options.get(row.index) else {
+            continue;
+        };
+        let Some(key) = keybinds.resolve(option) else {
+            if tooltip.is_some() {
+                commands.entity(entity).remove::<Tooltip>();
+            }
+            continue;
+        };
+        let text = format!(
+            "SHORTCUT: {}",
+            super::controls::key_label(Some(key))
+        );
+        // Only touch stale rows: re-inserting resets the hover delay.
+        if tooltip.map(|tooltip| tooltip.text.as_str()) != Some(text.as_str()) {
+            commands
+                .entity(entity)
+                .insert((Tooltip::new(text), Hoverable::new(clickable.region)));
+        }
+    }
+}
+
+/// Marks every menu stack changed when the UI scale moves, so pages and
+/// their tables rebuild at the new text size.
+pub fn reflow_pages_on_ui_scale_change(
+    scale: Res<UiScale>,
+    mut stacks: Query<&mut MenuStack>,
+) {
+    if !scale.is_changed() || scale.is_added() {
+        return;
+    }
+    for mut stack in &mut stacks {
+        // The field write marks the stack changed; an in-place reflow
+        // must not replay the last slide direction.
+        stack.last_move = MenuNavDirection::None;
+    }
+}
+
+/// Slides transitioning page content towards its resting place, then
+/// despawns outgoing content and releases incoming content.
+pub fn animate_menu_transitions(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut transitions: Query<(Entity, &mut MenuTransition, &mut Transform)>,
+) {
+    for (entity, mut transition, mut transform) in &mut transitions {
+        transition.remaining_secs -= time.delta_secs();
+        if transition.remaining_secs <= 0.0 {
+            if transition.outgoing {
+                commands.entity(entity).despawn();
+            } else {
+                transform.translation.x = 0.0;
+                commands.entity(entity).remove::<MenuTransition>();
+            }
+            continue;
+        }
+        transform.translation.x = transition_offset(
+            transition.direction,
+            transition.remaining_secs,
+            transition.outgoing,
+        );
+    }
+}
+
+/// Routes row clicks, keyboard select and shortcut keys into
+/// [`MenuCommandEvent`]s. Shortcuts resolve through [`KeybindState`]
+/// overrides rather than the raw `MenuOptionDef::shortcut`; everything
+/// is suspended while a rebind is listening for its key.
+pub fn handle_menu_option_activation(
+    keys: Res<ButtonInput<KeyCode>>,
+    keybinds: Res<super::controls::KeybindState>,
+    mut events: EventWriter<MenuCommandEvent>,
+    mut contents: Query<(Entity, &MenuPageContent, &mut SelectableMenu)>,
+    rows: Query<(&MenuOptionRow, &Clickable)>,
+) {
+    if keybinds.listening.is_some() {
+        return;
+    }
+    for (entity, content, mut menu) in &mut contents {
+        let definition = page_definition(content.page);
+        let mut activated: Option<usize> = None;
+        for (row, clickable) in &rows {
+            if row.content == entity && clickable.triggered {
+                menu.selected = row.index;
+                activated = Some(row.index);
+            }
+        }
+        if menu.select_triggered {
+            activated = Some(menu.selected);
+        }
+        for (index, option) in definition.options.iter().enumerate() {
+            if keybinds
+                .resolve(option)
+                .is_some_and(|shortcut| keys.just_pressed(shortcut))
+            {
+                activated = Some(index);
+            }
+        }
+        if let Some(index) = activated {
+            if !menu.is_enabled(index) {
+                continue;
+            }
+            if let Some(option) = definition.options.get(index) {
+                events.write(MenuCommandEvent {
+                    root: content.root,
+                    command: option.command,
+                });
+            }
+        }
+    }
+}
+
+/// Steps cyclable rows with the left/right arrows while they hold the
+/// selection.
+pub fn handle_option_cycler_commands(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut events: EventWriter<MenuCommandEvent>,
+    contents: Query<(&MenuPageContent, &SelectableMenu)>,
+    cyclers: Query<(&MenuOptionRow, &OptionCycler)>,
+) {
+    let left = keys.just_pressed(KeyCode::ArrowLeft);
+    let right = keys.just_pressed(KeyCode::ArrowRight);
+    if !left && !right {
+        return;
+    }
+    for (row, cycler) in &cyclers {
+        let Ok((content, menu)) = contents.get(row.content) else {
+            continue;
+        };
+        if menu.selected != row.index {
+            continue;
+        }
+        let command = if left { cycler.left } else { cycler.right };
+        events.write(MenuCommandEvent {
+            root: content.root,
+            command,
+        });
+    }
+}
+
+/// Cycles the cyclable row under the cursor with the mouse wheel: wheel
+/// up steps forward, wheel down back. Emits the same commands as the
+/// arrow-key path, so bounds and feedback behave identically. Suppressed
+/// while the cursor also sits over a region that can actually scroll —
+/// the wheel belongs to scrolling there.
+pub fn handle_option_cycler_wheel(
+    mut wheel: EventReader<MouseWheel>,
+    cursor: Res<CustomCursor>,
+    mut events: EventWriter<MenuCommandEvent>,
+    scroll_roots: Query<(&ScrollableRoot, &ScrollState, &GlobalTransform)>,
+    contents: Query<&MenuPageContent>,
+    cyclers: Query<(&MenuOptionRow, &OptionCycler, &Clickable, &GlobalTransform)>,
+) {
+    let mut steps = 0i32;
+    for event in wheel.read() {
+        if event.y > 0.0 {
+            steps += 1;
+        } else if event.y < 0.0 {
+            steps -= 1;
+        }
+    }
+    if steps == 0 {
+        return;
+    }
+    for (root, state, transform) in &scroll_roots {
+        if state.max_offset <= SCROLL_EPSILON {
+            continue;
+        }
+        let centre = transform.translation().truncate();
+        let half = root.viewport_size * 0.5;
+        if (cursor.position.x - centre.x).abs() <= half.x
+            && (cursor.position.y - centre.y).abs() <= half.y
+        {
+            return;
+        }
+    }
+    for (row, cycler, clickable, transform) in &cyclers {
+        let centre = transform.translation().truncate();
+        let half = clickable.region * 0.5;
+        if (cursor.position.x - centre.x).abs() > half.x
+            || (cursor.position.y - centre.y).abs() > half.y
+        {
+            continue;
+        }
+        let Ok(content) = contents.get(row.content) else {
+            continue;
+        };
+        let command = if steps > 0 { cycler.right } else { cycler.left };
+        for _ in 0..steps.unsigned_abs() {
+            events.write(MenuCommandEvent {
+                root: content.root,
+                command,
+            });
+        }
+    }
+}
+
+/// Navigation commands (push/pop/quit); settings commands pass through to
+/// their own modules' readers.
+pub fn execute_menu_navigation(
+    mut events: EventReader<MenuCommandEvent>,
+    mut stacks: Query<&mut MenuStack>,
+    mut exit: EventWriter<AppExit>,
+    mut current_dilemma: ResMut<CurrentDilemma>,
+    mut next_main: ResMut<NextState<MainState>>,
+) {
+    for event in events.read() {
+        match event.command {
+            MenuCommand::Push(page) => {
+                if let Ok(mut stack) = stacks.get_mut(event.root) {
+                    stack.push(page);
+                }
+            }
+            MenuCommand::Pop => {
+                if let Ok(mut stack) = stacks.get_mut(event.root) {
+                    stack.pop();
+                }
+            }
+            MenuCommand::Quit => {
+                exit.write(AppExit::Success);
+            }
+            MenuCommand::LoadDilemma(id) => {
+                current_dilemma.id = Some(id);
+                next_main.set(MainState::Dilemma);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Highlights the selected row's text and dims disabled rows, through
+/// the row's [`InteractionVisualPalette`] when it carries one.
+pub fn sync_menu_option_visuals(
+    contents: Query<(Entity, &SelectableMenu), With<MenuPageContent>>,
+    mut rows: Query<(
+        &MenuOptionRow,
+        &mut TextColor,
+        Option<&InteractionVisualPalette>,
+        Has<Disabled>,
+    )>,
+) {
+    for (row, mut color, palette, disabled) in &mut rows {
+        let Ok((_, menu)) = contents.get(row.content) else {
+            continue;
+        };
+        let wanted = if disabled {
+            palette.map_or(DIM_COLOR, |p| p.disabled)
+        } else if menu.selected == row.index {
+            palette.map_or(HIGHLIGHT_COLOR, |p| p.highlighted)
+        } else {
+            palette.map_or(SYSTEM_MENU_COLOR, |p| p.normal)
+        };
+        if color.0 != wanted {
+            color.0 = wanted;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn menu_stack_pops_to_root_and_stops() {
+        let mut stack = MenuStack::new(MenuPage::PauseRoot);
+        stack.push(MenuPage::Options);
+        stack.push(MenuPage::Video);
+        assert_eq!(stack.current(), MenuPage::Video);
+        stack.pop();
+        stack.pop();
+        stack.pop();
+        assert_eq!(stack.current(), MenuPage::PauseRoot);
+        // The no-op pop at the root does not count as a move.
+        assert_eq!(stack.last_move, MenuNavDirection::Back);
+    }
+
+    #[test]
+    fn stack_memory_recalls_per_host_and_resets_on_demand() {
+        let mut memory = MenuStackMemory::default();
+        memory.remember(
+            MenuHost::Pause,
+            &[MenuPage::PauseRoot, MenuPage::Options, MenuPage::Video],
+        );
+        assert_eq!(
+            memory.recall(MenuHost::Pause, MenuPage::PauseRoot),
+            vec![MenuPage::PauseRoot, MenuPage::Options, MenuPage::Video],
+        );
+        // Hosts are independent: the main menu has no memory yet.
+        assert_eq!(
+            memory.recall(MenuHost::Main, MenuPage::MainRoot),
+            vec![MenuPage::MainRoot],
+        );
+        // A stack remembered under a different root is not resumed.
+        assert_eq!(
+            memory.recall(MenuHost::Pause, MenuPage::MainRoot),
+            vec![MenuPage::MainRoot],
+        );
+        memory.reset(MenuHost::Pause);
+        assert_eq!(
+            memory.recall(MenuHost::Pause, MenuPage::PauseRoot),
+            vec![MenuPage::PauseRoot],
+        );
+    }
+
+    #[test]
+    fn forward_slides_left_and_back_slides_right() {
+        // Outgoing content exits towards negative x on a push...
+        assert!(transition_offset(MenuNavDirection::Forward, 0.0, true) < 0.0);
+        // ...while the incoming page starts on the positive side and
+        // lands exactly at the origin.
+        assert!(
+            transition_offset(MenuNavDirection::Forward, MENU_TRANSITION_SECS, false) > 0.0
+        );
+        assert_eq!(transition_offset(MenuNavDirection::Forward, 0.0, false), 0.0);
+        assert!(transition_offset(MenuNavDirection::Back, 0.0, true) > 0.0);
+        assert_eq!(transition_offset(MenuNavDirection::None, 0.0, true), 0.0);
+    }
+
+    #[test]
+    fn ui_scale_steps_and_clamps_to_its_range() {
+        let mut scale = UiScale(1.0);
+        scale.adjust(2);
+        assert_eq!(scale.percent_label(), "120%");
+        for _ in 0..10 {
+            scale.adjust(1);
+        }
+        assert_eq!(scale.0, UI_SCALE_MAX);
+        for _ in 0..20 {
+            scale.adjust(-1);
+        }
+        assert_eq!(scale.0, UI_SCALE_MIN);
+    }
+}
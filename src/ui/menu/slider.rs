@@ -0,0 +1,232 @@
+use bevy::prelude::*;
+
+use crate::{
+    data::settings::{AudioChannel, UserSettings},
+    systems::{
+        audio::{SystemMenuAudio, SystemMenuSounds},
+        colors::PRIMARY_COLOR,
+        interaction::{CustomCursor, Draggable, DraggableRegion},
+    },
+    ui::{
+        menu::{
+            audio::AudioSettingsState,
+            pages::{MenuPage, MenuPageContent},
+            SelectableMenu,
+        },
+        shapes::{HollowRectangle, Plus},
+    },
+};
+
+const SLIDER_WIDTH: f32 = 140.0;
+const SLIDER_HEIGHT: f32 = 8.0;
+const HANDLE_SIZE: f32 = 12.0;
+const LABEL_FONT_SIZE: f32 = 11.0;
+/// Keyboard fine step, applied on Shift+Left/Right while the slider's
+/// row is selected (plain arrows keep the 10% cycler steps).
+const FINE_STEP: f32 = 0.01;
+/// Minimum gap between preview clicks while scrubbing.
+const PREVIEW_COOLDOWN_SECS: f32 = 0.09;
+
+/// A draggable volume slider for one mixer channel, spawned next to the
+/// audio page's option rows.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct VolumeSlider {
+    pub channel: AudioChannel,
+    /// The option row this slider belongs to, for keyboard selection.
+    pub row_index: usize,
+}
+
+/// The draggable grab glyph riding the slider track.
+#[derive(Component, Debug, Clone, Copy)]
+struct SliderHandle {
+    slider: Entity,
+}
+
+/// The percentage readout beside the track.
+#[derive(Component, Debug, Clone, Copy)]
+struct SliderLabel {
+    slider: Entity,
+}
+
+/// Handle x in slider-local space for a volume in `[0, 1]`.
+fn handle_x(volume: f32) -> f32 {
+    (volume.clamp(0.0, 1.0) - 0.5) * SLIDER_WIDTH
+}
+
+/// Volume for a cursor x relative to the slider centre.
+fn volume_at(cursor_offset_x: f32) -> f32 {
+    (cursor_offset_x / SLIDER_WIDTH + 0.5).clamp(0.0, 1.0)
+}
+
+/// Spawns a slider per channel once an audio page's content exists.
+pub fn populate_audio_sliders(
+    mut commands: Commands,
+    state: Res<AudioSettingsState>,
+    contents: Query<(Entity, &MenuPageContent), Added<MenuPageContent>>,
+) {
+    for (content, page_content) in &contents {
+        if page_content.page != MenuPage::Audio {
+            continue;
+        }
+        let channels = [
+            AudioChannel::Master,
+            AudioChannel::Music,
+            AudioChannel::Sfx,
+            AudioChannel::Ui,
+        ];
+        for (row_index, channel) in channels.into_iter().enumerate() {
+            let volume = state.settings.volume(channel);
+            let slider = commands
+                .spawn((
+                    VolumeSlider { channel, row_index },
+                    HollowRectangle {
+                        dimensions: Vec2::new(SLIDER_WIDTH, SLIDER_HEIGHT),
+                        thickness: 1.0,
+                        color: PRIMARY_COLOR,
+                    },
+                    Transform::from_xyz(-120.0, -40.0 - row_index as f32 * 26.0, 0.3),
+                    ChildOf(content),
+                ))
+                .id();
+            commands.spawn((
+                SliderHandle { slider },
+                Plus {
+                    size: HANDLE_SIZE,
+                    thickness: 2.0,
+                    color: PRIMARY_COLOR,
+                },
+                Draggable::default(),
+                DraggableRegion {
+                    dimensions: Vec2::splat(HANDLE_SIZE + 6.0),
+                    offset: Vec2::ZERO,
+                },
+                Transform::from_xyz(handle_x(volume), 0.0, 0.1),
+                ChildOf(slider),
+            ));
+            commands.spawn((
+                SliderLabel { slider },
+                Text2d::new(String::new()),
+                TextFont::from_font_size(LABEL_FONT_SIZE),
+                TextColor(PRIMARY_COLOR),
+                Transform::from_xyz(SLIDER_WIDTH * 0.5 + 32.0, 0.0, 0.1),
+                ChildOf(slider),
+            ));
+        }
+    }
+}
+
+/// Scrubs dragged handles into the mixer. The generic drag system has
+/// already written a world-space position this frame; the fraction is
+/// taken from the cursor against the track centre instead, and the
+/// handle is re-seated in local space before anything renders.
+pub fn drag_volume_sliders(
+    cursor: Res<CustomCursor>,
+    mut state: ResMut<AudioSettingsState>,
+    sliders: Query<(&VolumeSlider, &GlobalTransform)>,
+    mut handles: Query<(&SliderHandle, &Draggable, &mut Transform)>,
+    mut was_dragging: Local<bool>,
+) {
+    let mut any_dragging = false;
+    for (handle, draggable, mut transform) in &mut handles {
+        let Ok((slider, track)) = sliders.get(handle.slider) else {
+            continue;
+        };
+        if draggable.dragging() {
+            any_dragging = true;
+            let offset = cursor.position.x - track.translation().x;
+            let volume = volume_at(offset);
+            if (state.settings.volume(slider.channel) - volume).abs() > f32::EPSILON {
+                state.settings.set_volume(slider.channel, volume);
+            }
+        }
+        let seated = handle_x(state.settings.volume(slider.channel));
+        transform.translation = Vec3::new(seated, 0.0, 0.1);
+    }
+    // Persist once per drag, on release, rather than every scrub frame.
+    if *was_dragging && !any_dragging {
+        let mut settings = UserSettings::load();
+        settings.audio = state.settings;
+        settings.save();
+    }
+    *was_dragging = any_dragging;
+}
+
+/// Shift+Left/Right nudges the selected row's slider by 1%.
+pub fn fine_adjust_selected_slider(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut state: ResMut<AudioSettingsState>,
+    contents: Query<(&MenuPageContent, &SelectableMenu)>,
+    sliders: Query<&VolumeSlider>,
+) {
+    let shift = keys.pressed(KeyCode::ShiftLeft) || keys.pressed(KeyCode::ShiftRight);
+    let delta = keys.just_pressed(KeyCode::ArrowRight) as i32
+        - keys.just_pressed(KeyCode::ArrowLeft) as i32;
+    if !shift || delta == 0 {
+        return;
+    }
+    let Some(selected) = contents
+        .iter()
+        .find(|(content, _)| content.page == MenuPage::Audio)
+        .map(|(_, menu)| menu.selected)
+    else {
+        return;
+    };
+    for slider in &sliders {
+        if slider.row_index != selected {
+            continue;
+        }
+        let volume = state.settings.volume(slider.channel) + delta as f32 * FINE_STEP;
+        state.settings.set_volume(slider.channel, volume);
+    }
+}
+
+/// Keeps the percentage readouts current and plays a throttled preview
+/// click while levels move, so the change is heard at its new volume.
+pub fn sync_slider_feedback(
+    mut commands: Commands,
+    time: Res<Time>,
+    state: Res<AudioSettingsState>,
+    audio: Option<Res<SystemMenuAudio>>,
+    sliders: Query<&VolumeSlider>,
+    mut labels: Query<(&SliderLabel, &mut Text2d)>,
+    mut cooldown: Local<f32>,
+) {
+    *cooldown = (*cooldown - time.delta_secs()).max(0.0);
+    for (label, mut text) in &mut labels {
+        let Ok(slider) = sliders.get(label.slider) else {
+            continue;
+        };
+        let rendered = format!(
+            "{:.0}%",
+            (state.settings.volume(slider.channel) * 100.0).round()
+        );
+        if text.0 != rendered {
+            text.0 = rendered;
+        }
+    }
+    if state.is_changed() && !state.is_added() && *cooldown == 0.0 {
+        if let Some(audio) = audio.as_ref() {
+            audio.play(&mut commands, SystemMenuSounds::Switch, &state.settings);
+            *cooldown = PREVIEW_COOLDOWN_SECS;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn handle_position_and_volume_are_inverse() {
+        for volume in [0.0, 0.25, 0.5, 1.0] {
+            assert!((volume_at(handle_x(volume)) - volume).abs() < 1e-5);
+        }
+        assert_eq!(handle_x(0.5), 0.0);
+    }
+
+    #[test]
+    fn scrubbing_clamps_to_the_track_ends() {
+        assert_eq!(volume_at(-SLIDER_WIDTH), 0.0);
+        assert_eq!(volume_at(SLIDER_WIDTH), 1.0);
+    }
+}
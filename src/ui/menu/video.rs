@@ -0,0 +1,1030 @@
+use bevy::{
+    prelude::*,
+    window::{Monitor, MonitorSelection, PresentMode, PrimaryWindow, WindowMode},
+};
+
+use crate::{
+    data::settings::{ColorblindMode, FrameLimit, UserSettings, VideoDisplayMode, VideoSettings},
+    systems::{
+        colors::{ColorScheme, HIGHLIGHT_COLOR, PRIMARY_COLOR, SYSTEM_MENU_COLOR},
+        interaction::{Clickable, CustomCursor, Draggable, DraggableRegion},
+        time::FrameLimiter,
+    },
+    ui::{
+        menu::{
+            dropdown::{Dropdown, DropdownOpenMode},
+            pages::{
+                scaled_font_size, MenuCommand, MenuCommandEvent, MenuOptionRow, MenuPage,
+                MenuPageContent, OptionCycler, UiScale,
+            },
+            pressed_letter, type_ahead_target, SelectableMenu,
+        },
+        shapes::{HollowRectangle, Plus},
+        table::{Cell, Column, Row, Table},
+        window::{Window as UiWindow, WindowContent, WindowTitle},
+    },
+};
+
+pub const VIDEO_TABLE_TEXT_SIZE: f32 = 12.0;
+/// Seconds the player has to keep newly applied settings before they
+/// revert automatically.
+pub const APPLY_CONFIRM_SECS: f32 = 10.0;
+
+/// Selectable output sizes, smallest first.
+pub const RESOLUTIONS: [(u32, u32); 5] = [
+    (1280, 720),
+    (1600, 900),
+    (1920, 1080),
+    (2560, 1440),
+    (3840, 2160),
+];
+
+/// Brightness bounds the overlay slider covers; 100% is the midline.
+pub const BRIGHTNESS_MIN: f32 = 0.5;
+pub const BRIGHTNESS_MAX: f32 = 1.5;
+const BRIGHTNESS_SLIDER_WIDTH: f32 = 140.0;
+const BRIGHTNESS_SLIDER_HEIGHT: f32 = 8.0;
+const BRIGHTNESS_HANDLE_SIZE: f32 = 12.0;
+/// Above gameplay and the dilemma HUD, below the menu windows (z >= 600).
+const BRIGHTNESS_OVERLAY_Z: f32 = 595.0;
+const BRIGHTNESS_OVERLAY_SIZE: f32 = 20000.0;
+
+/// The live, applied brightness; staged edits only land here on Apply,
+/// matching how the other video settings reach the window.
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct ScreenBrightness(pub f32);
+
+impl Default for ScreenBrightness {
+    fn default() -> Self {
+        Self(UserSettings::load().video.brightness)
+    }
+}
+
+/// Applied-but-unconfirmed settings awaiting the countdown.
+#[derive(Debug, Clone, Copy)]
+pub struct PendingVideoApply {
+    pub previous: VideoSettings,
+}
+
+/// Staged video choices plus the apply/revert countdown state. `current`
+/// is what the table shows; it only reaches the OS window on Apply.
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct VideoSettingsState {
+    pub current: VideoSettings,
+    pub pending: Option<PendingVideoApply>,
+    /// The last resolution chosen while in windowed mode. Borderless
+    /// fills the monitor regardless, so without this a fullscreen
+    /// round-trip would clobber the windowed size with the monitor's.
+    pub windowed_resolution: (u32, u32),
+}
+
+impl Default for VideoSettingsState {
+    fn default() -> Self {
+        let video = UserSettings::load().video;
+        Self {
+            current: video,
+            pending: None,
+            windowed_resolution: video.resolution,
+        }
+    }
+}
+
+/// Reads the live window (and the current frame cap) back into a
+/// settings snapshot. In borderless mode the live surface reports the
+/// monitor's size, so the snapshot records `windowed_resolution` instead
+/// — reverting to it must not bake the monitor size into windowed mode.
+pub fn snapshot_from_window(
+    window: &bevy::window::Window,
+    frame_limit: FrameLimit,
+    brightness: f32,
+    windowed_resolution: (u32, u32),
+) -> VideoSettings {
+    let windowed = matches!(window.mode, WindowMode::Windowed);
+    VideoSettings {
+        display_mode: if windowed {
+            VideoDisplayMode::Windowed
+        } else {
+            VideoDisplayMode::Borderless
+        },
+        vsync: !matches!(
+            window.present_mode,
+            PresentMode::AutoNoVsync | PresentMode::Immediate
+        ),
+        resolution: if windowed {
+            (
+                window.resolution.width() as u32,
+                window.resolution.height() as u32,
+            )
+        } else {
+            windowed_resolution
+        },
+        frame_limit,
+        brightness,
+    }
+}
+
+/// Pushes a snapshot onto the live window. The resolution only applies
+/// in windowed mode; borderless lets the fullscreen mode size the
+/// surface, keeping the remembered windowed size out of its way.
+pub fn apply_snapshot_to_window(window: &mut bevy::window::Window, snapshot: &VideoSettings) {
+    window.mode = match snapshot.display_mode {
+        VideoDisplayMode::Windowed => WindowMode::Windowed,
+        VideoDisplayMode::Borderless => {
+            WindowMode::BorderlessFullscreen(MonitorSelection::Current)
+        }
+    };
+    window.present_mode = if snapshot.vsync {
+        PresentMode::AutoVsync
+    } else {
+        PresentMode::AutoNoVsync
+    };
+    if snapshot.display_mode == VideoDisplayMode::Windowed {
+        window
+            .resolution
+            .set(snapshot.resolution.0 as f32, snapshot.resolution.1 as f32);
+    }
+}
+
+/// Whether a resolution fits on a monitor of the given native size. With
+/// no monitor to measure against, everything is assumed to fit.
+fn fits_on_monitor((width, height): (u32, u32), native: Option<(u32, u32)>) -> bool {
+    match native {
+        Some((native_width, native_height)) => width <= native_width && height <= native_height,
+        None => true,
+    }
+}
+
+/// The selectable resolution list: the stock entries that fit on the
+/// current monitor, plus its native resolution appended when not already
+/// present. Filtering keeps a 4K entry off laptop dropdowns, where
+/// picking it would produce a window larger than the display; appending
+/// means odd aspect ratios (ultrawides) get a matching entry instead of
+/// index 0.
+pub fn available_resolutions(native: Option<(u32, u32)>) -> Vec<(u32, u32)> {
+    let mut list: Vec<(u32, u32)> = RESOLUTIONS
+        .iter()
+        .copied()
+        .filter(|resolution| fits_on_monitor(*resolution, native))
+        .collect();
+    if let Some(native) = native {
+        if !list.contains(&native) {
+            list.push(native);
+        }
+    }
+    list
+}
+
+/// Default pick for a resolution list: the largest entry by area, so a
+/// fresh install fills the monitor rather than opening at 1280 x 720.
+pub fn default_resolution_index(resolutions: &[(u32, u32)]) -> usize {
+    resolutions
+        .iter()
+        .enumerate()
+        .max_by_key(|(_, (width, height))| u64::from(*width) * u64::from(*height))
+        .map(|(index, _)| index)
+        .unwrap_or(0)
+}
+
+/// Native size of the first reported monitor, if any.
+pub fn native_resolution(monitors: &Query<&Monitor>) -> Option<(u32, u32)> {
+    monitors
+        .iter()
+        .next()
+        .map(|monitor| (monitor.physical_width, monitor.physical_height))
+}
+
+/// Index of the window's current resolution in `resolutions`, falling
+/// back to the largest fitting entry when it matches none of them.
+pub fn resolution_index_from_window(
+    window: &bevy::window::Window,
+    resolutions: &[(u32, u32)],
+) -> usize {
+    let current = (
+        window.resolution.width() as u32,
+        window.resolution.height() as u32,
+    );
+    resolutions
+        .iter()
+        .position(|resolution| *resolution == current)
+        .unwrap_or_else(|| default_resolution_index(resolutions))
+}
+
+pub fn resolution_label((width, height): (u32, u32)) -> String {
+    format!("{width} x {height}")
+}
+
+fn resolution_item_label(resolution: (u32, u32), native: Option<(u32, u32)>) -> String {
+    if Some(resolution) == native {
+        format!("{} (NATIVE)", resolution_label(resolution))
+    } else {
+        resolution_label(resolution)
+    }
+}
+
+fn display_mode_label(mode: VideoDisplayMode) -> &'static str {
+    match mode {
+        VideoDisplayMode::Windowed => "WINDOWED",
+        VideoDisplayMode::Borderless => "BORDERLESS",
+    }
+}
+
+/// The settings table shown on the video page.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct VideoOptionsTable;
+
+/// Resolution picker attached alongside the table.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct ResolutionDropdown;
+
+/// The staged-brightness slider track on the video page.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct BrightnessSlider;
+
+/// Its draggable grab glyph.
+#[derive(Component, Debug, Clone, Copy)]
+struct BrightnessHandle {
+    slider: Entity,
+}
+
+/// The full-screen tint sprite realising the applied brightness.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct BrightnessOverlay;
+
+fn video_table_rows(settings: &VideoSettings, scale: &UiScale, scheme: &ColorScheme) -> Vec<Row> {
+    vec![
+        Row::new(vec![
+            Cell::new("DISPLAY MODE"),
+            Cell::new(display_mode_label(settings.display_mode)),
+        ]),
+        Row::new(vec![
+            Cell::new("VSYNC"),
+            Cell::new(if settings.vsync { "ON" } else { "OFF" }),
+        ]),
+        Row::new(vec![
+            Cell::new("RESOLUTION"),
+            Cell::new(resolution_label(settings.resolution)),
+        ]),
+        Row::new(vec![
+            Cell::new("FRAME LIMIT"),
+            Cell::new(settings.frame_limit.label()),
+        ]),
+        Row::new(vec![
+            Cell::new("UI SCALE"),
+            Cell::new(scale.percent_label()),
+        ]),
+        Row::new(vec![
+            Cell::new("COLORBLIND"),
+            Cell::new(scheme.mode.label()),
+        ]),
+        Row::new(vec![
+            Cell::new("BRIGHTNESS"),
+            Cell::new(brightness_label(settings.brightness)),
+        ]),
+    ]
+}
+
+fn brightness_label(brightness: f32) -> String {
+    format!("{:.0}%", (brightness * 100.0).round())
+}
+
+/// Handle x in slider-local space for a brightness value.
+fn brightness_handle_x(brightness: f32) -> f32 {
+    let fraction =
+        (brightness.clamp(BRIGHTNESS_MIN, BRIGHTNESS_MAX) - BRIGHTNESS_MIN)
+            / (BRIGHTNESS_MAX - BRIGHTNESS_MIN);
+    (fraction - 0.5) * BRIGHTNESS_SLIDER_WIDTH
+}
+
+/// Brightness for a cursor x relative to the slider centre.
+fn brightness_at(cursor_offset_x: f32) -> f32 {
+    let fraction = (cursor_offset_x / BRIGHTNESS_SLIDER_WIDTH + 0.5).clamp(0.0, 1.0);
+    BRIGHTNESS_MIN + fraction * (BRIGHTNESS_MAX - BRIGHTNESS_MIN)
+}
+
+/// Overlay colour for a brightness level: black fading in below the
+/// midline, white above it, fully clear at exactly 100%.
+fn overlay_color(brightness: f32) -> Color {
+    if brightness < 1.0 {
+        Color::srgba(0.0, 0.0, 0.0, (1.0 - brightness).clamp(0.0, 1.0))
+    } else {
+        // Washing out reads stronger than darkening; halve the ramp.
+        Color::srgba(1.0, 1.0, 1.0, ((brightness - 1.0) * 0.5).clamp(0.0, 1.0))
+    }
+}
+
+/// Attaches the options table, resolution dropdown and row cyclers once
+/// a video page's content exists.
+pub fn populate_video_page(
+    mut commands: Commands,
+    state: Res<VideoSettingsState>,
+    scale: Res<UiScale>,
+    scheme: Res<ColorScheme>,
+    monitors: Query<&Monitor>,
+    contents: Query<(Entity, &MenuPageContent), Added<MenuPageContent>>,
+    rows: Query<(Entity, &MenuOptionRow)>,
+) {
+    let native = native_resolution(&monitors);
+    for (content, page_content) in &contents {
+        if page_content.page != MenuPage::Video {
+            continue;
+        }
+        let mut table = Table::new(
+            vec![Column::new("SETTING", 130.0), Column::new("VALUE", 110.0)],
+            scaled_font_size(&scale, VIDEO_TABLE_TEXT_SIZE),
+        );
+        table.rows = video_table_rows(&state.current, &scale, &scheme);
+        commands.spawn((
+            VideoOptionsTable,
+            table,
+            Transform::from_xyz(130.0, -60.0, 0.2),
+            ChildOf(content),
+        ));
+        commands.spawn((
+            ResolutionDropdown,
+            // Combo-box feel: resting on the value cell opens the list.
+            DropdownOpenMode::HoverDwell { dwell_secs: 0.35 },
+            Dropdown::new(
+                available_resolutions(native)
+                    .into_iter()
+                    .map(|resolution| resolution_item_label(resolution, native))
+                    .collect(),
+            ),
+            Transform::from_xyz(130.0, -150.0, 0.3),
+            ChildOf(content),
+        ));
+        let slider = commands
+            .spawn((
+                BrightnessSlider,
+                HollowRectangle {
+                    dimensions: Vec2::new(BRIGHTNESS_SLIDER_WIDTH, BRIGHTNESS_SLIDER_HEIGHT),
+                    thickness: 1.0,
+                    color: PRIMARY_COLOR,
+                },
+                Transform::from_xyz(-120.0, -150.0, 0.3),
+                ChildOf(content),
+            ))
+            .id();
+        commands.spawn((
+            BrightnessHandle { slider },
+            Plus {
+                size: BRIGHTNESS_HANDLE_SIZE,
+                thickness: 2.0,
+                color: PRIMARY_COLOR,
+            },
+            Draggable::default(),
+            DraggableRegion {
+                dimensions: Vec2::splat(BRIGHTNESS_HANDLE_SIZE + 6.0),
+                offset: Vec2::ZERO,
+            },
+            Transform::from_xyz(brightness_handle_x(state.current.brightness), 0.0, 0.1),
+            ChildOf(slider),
+        ));
+        let cyclers = [
+            OptionCycler {
+                left: MenuCommand::CycleDisplayMode(-1),
+                right: MenuCommand::CycleDisplayMode(1),
+            },
+            OptionCycler {
+                left: MenuCommand::CycleVsync(-1),
+                right: MenuCommand::CycleVsync(1),
+            },
+            OptionCycler {
+                left: MenuCommand::CycleResolution(-1),
+                right: MenuCommand::CycleResolution(1),
+            },
+            OptionCycler {
+                left: MenuCommand::CycleFrameLimit(-1),
+                right: MenuCommand::CycleFrameLimit(1),
+            },
+            OptionCycler {
+                left: MenuCommand::AdjustUiScale(-1),
+                right: MenuCommand::AdjustUiScale(1),
+            },
+            OptionCycler {
+                left: MenuCommand::CycleColorScheme(-1),
+                right: MenuCommand::CycleColorScheme(1),
+            },
+        ];
+        for (entity, row) in &rows {
+            if row.content != content {
+                continue;
+            }
+            if let Some(cycler) = cyclers.get(row.index) {
+                commands.entity(entity).insert(*cycler);
+            }
+        }
+    }
+}
+
+/// Digit shortcut (1..=n) into a resolution list of `len` entries.
+pub fn dropdown_resolution_shortcut_index(
+    keys: &ButtonInput<KeyCode>,
+    len: usize,
+) -> Option<usize> {
+    const DIGITS: [KeyCode; 9] = [
+        KeyCode::Digit1,
+        KeyCode::Digit2,
+        KeyCode::Digit3,
+        KeyCode::Digit4,
+        KeyCode::Digit5,
+        KeyCode::Digit6,
+        KeyCode::Digit7,
+        KeyCode::Digit8,
+        KeyCode::Digit9,
+    ];
+    DIGITS
+        .iter()
+        .position(|digit| keys.just_pressed(*digit))
+        .filter(|index| *index < len)
+}
+
+/// Keeps the dropdown's selection in step with the staged resolution and
+/// turns chosen items into commands.
+pub fn sync_resolution_dropdown_items(
+    keys: Res<ButtonInput<KeyCode>>,
+    state: Res<VideoSettingsState>,
+    monitors: Query<&Monitor>,
+    mut events: EventWriter<MenuCommandEvent>,
+    mut dropdowns: Query<(&mut Dropdown, &ChildOf), With<ResolutionDropdown>>,
+    contents: Query<&MenuPageContent>,
+) {
+    let native = native_resolution(&monitors);
+    let resolutions = available_resolutions(native);
+    for (mut dropdown, parent) in &mut dropdowns {
+        let Ok(content) = contents.get(parent.0) else {
+            continue;
+        };
+        // The monitor set can change at runtime; keep the items current.
+        let labels: Vec<String> = resolutions
+            .iter()
+            .map(|resolution| resolution_item_label(*resolution, native))
+            .collect();
+        if dropdown.items != labels {
+            dropdown.items = labels;
+        }
+        // A staged resolution that no longer fits (monitor hot-swap)
+        // resolves to the largest entry that does.
+        let staged = resolutions
+            .iter()
+            .position(|resolution| *resolution == state.current.resolution)
+            .unwrap_or_else(|| default_resolution_index(&resolutions));
+        if !dropdown.is_selected(staged) {
+            dropdown.choose(staged);
+        }
+        // Digit shortcuts, Home/End jumps and first-letter type-ahead
+        // all resolve to the same staged-index command.
+        let mut shortcut = dropdown_resolution_shortcut_index(&keys, resolutions.len());
+        if shortcut.is_none() && keys.just_pressed(KeyCode::Home) {
+            shortcut = Some(0);
+        }
+        if shortcut.is_none() && keys.just_pressed(KeyCode::End) && !resolutions.is_empty() {
+            shortcut = Some(resolutions.len() - 1);
+        }
+        if shortcut.is_none() {
+            if let Some(letter) = pressed_letter(&keys) {
+                shortcut = type_ahead_target(&dropdown.items, staged, letter);
+            }
+        }
+        if let Some(index) = shortcut {
+            events.write(MenuCommandEvent {
+                root: content.root,
+                command: MenuCommand::SetResolutionIndex(index),
+            });
+        }
+    }
+}
+
+/// Refreshes the table cells whenever the staged settings, the UI scale
+/// or the colour scheme change.
+pub fn refresh_video_table(
+    state: Res<VideoSettingsState>,
+    scale: Res<UiScale>,
+    scheme: Res<ColorScheme>,
+    mut tables: Query<&mut Table, With<VideoOptionsTable>>,
+) {
+    if !state.is_changed() && !scale.is_changed() && !scheme.is_changed() {
+        return;
+    }
+    for mut table in &mut tables {
+        table.rows = video_table_rows(&state.current, &scale, &scheme);
+    }
+}
+
+fn cycle<T: Copy + PartialEq>(options: &[T], current: T, delta: i8) -> T {
+    let index = options
+        .iter()
+        .position(|option| *option == current)
+        .unwrap_or(0) as i32;
+    let len = options.len() as i32;
+    options[(index + delta as i32).rem_euclid(len) as usize]
+}
+
+/// Consumes video commands: cycles mutate the staged settings; Apply
+/// pushes them to the window and opens the confirm countdown.
+pub fn handle_video_menu_commands(
+    mut commands: Commands,
+    mut events: EventReader<MenuCommandEvent>,
+    mut state: ResMut<VideoSettingsState>,
+    mut scale: ResMut<UiScale>,
+    monitors: Query<&Monitor>,
+    mut limiter: ResMut<FrameLimiter>,
+    mut screen: ResMut<ScreenBrightness>,
+    mut scheme: ResMut<ColorScheme>,
+    mut windows: Query<&mut bevy::window::Window, With<PrimaryWindow>>,
+) {
+    let resolutions = available_resolutions(native_resolution(&monitors));
+    for event in events.read() {
+        match event.command {
+            MenuCommand::CycleDisplayMode(delta) => {
+                let was_windowed = state.current.display_mode == VideoDisplayMode::Windowed;
+                state.current.display_mode = cycle(
+                    &[VideoDisplayMode::Windowed, VideoDisplayMode::Borderless],
+                    state.current.display_mode,
+                    delta,
+                );
+                // Stash the windowed size on the way out, restore it on
+                // the way back, so a fullscreen round-trip is lossless.
+                match state.current.display_mode {
+                    VideoDisplayMode::Borderless if was_windowed => {
+                        state.windowed_resolution = state.current.resolution;
+                    }
+                    VideoDisplayMode::Windowed if !was_windowed => {
+                        state.current.resolution = state.windowed_resolution;
+                    }
+                    _ => {}
+                }
+            }
+            MenuCommand::CycleVsync(_) => state.current.vsync = !state.current.vsync,
+            MenuCommand::CycleResolution(delta) => {
+                state.current.resolution = cycle(&resolutions, state.current.resolution, delta);
+            }
+            MenuCommand::SetResolutionIndex(index) => {
+                if let Some(resolution) = resolutions.get(index) {
+                    state.current.resolution = *resolution;
+                }
+            }
+            MenuCommand::CycleFrameLimit(delta) => {
+                state.current.frame_limit =
+                    cycle(&FrameLimit::ALL, state.current.frame_limit, delta);
+            }
+            MenuCommand::AdjustUiScale(delta) => {
+                // Applies immediately (like volume) rather than through
+                // the apply/revert countdown.
+                scale.adjust(delta);
+                let mut settings = UserSettings::load();
+                settings.interface.ui_scale = scale.0;
+                settings.save();
+            }
+            MenuCommand::CycleColorScheme(delta) => {
+                // Also immediate: a colorblind player can't judge the
+                // remap through the apply/revert countdown.
+                scheme.mode = cycle(&ColorblindMode::ALL, scheme.mode, delta);
+                let mut settings = UserSettings::load();
+                settings.interface.colorblind_mode = scheme.mode;
+                settings.save();
+            }
+            MenuCommand::ApplyVideoSettings => {
+                let Ok(mut window) = windows.single_mut() else {
+                    continue;
+                };
+                let previous =
+                    snapshot_from_window(&window, limiter.limit, screen.0, state.windowed_resolution);
+                let staged = state.current;
+                apply_snapshot_to_window(&mut window, &staged);
+                if staged.display_mode == VideoDisplayMode::Windowed {
+                    state.windowed_resolution = staged.resolution;
+                }
+                limiter.limit = staged.frame_limit;
+                screen.0 = staged.brightness;
+                state.pending = Some(PendingVideoApply { previous });
+                spawn_apply_confirm_modal(&mut commands, &scale);
+            }
+            MenuCommand::ConfirmVideoSettings => {
+                if state.pending.take().is_some() {
+                    let mut settings = UserSettings::load();
+                    settings.video = state.current;
+                    settings.save();
+                }
+            }
+            MenuCommand::RevertVideoSettings => {
+                if let Some(pending) = state.pending.take() {
+                    state.current = pending.previous;
+                    if pending.previous.display_mode == VideoDisplayMode::Windowed {
+                        state.windowed_resolution = pending.previous.resolution;
+                    }
+                    limiter.limit = pending.previous.frame_limit;
+                    screen.0 = pending.previous.brightness;
+                    if let Ok(mut window) = windows.single_mut() {
+                        apply_snapshot_to_window(&mut window, &pending.previous);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Scrubs the dragged brightness handle into the staged settings. The
+/// generic drag system wrote a world position this frame; the value is
+/// taken from the cursor against the track centre and the handle is
+/// re-seated in local space before rendering, as the volume sliders do.
+pub fn drag_brightness_slider(
+    cursor: Res<CustomCursor>,
+    mut state: ResMut<VideoSettingsState>,
+    sliders: Query<&GlobalTransform, With<BrightnessSlider>>,
+    mut handles: Query<(&BrightnessHandle, &Draggable, &mut Transform)>,
+) {
+    for (handle, draggable, mut transform) in &mut handles {
+        let Ok(track) = sliders.get(handle.slider) else {
+            continue;
+        };
+        if draggable.dragging() {
+            let staged = brightness_at(cursor.position.x - track.translation().x);
+            if (state.current.brightness - staged).abs() > f32::EPSILON {
+                state.current.brightness = staged;
+            }
+        }
+        transform.translation = Vec3::new(brightness_handle_x(state.current.brightness), 0.0, 0.1);
+    }
+}
+
+/// Spawns the always-present tint sprite the applied brightness drives.
+pub fn spawn_brightness_overlay(mut commands: Commands, screen: Res<ScreenBrightness>) {
+    commands.spawn((
+        BrightnessOverlay,
+        Sprite {
+            color: overlay_color(screen.0),
+            custom_size: Some(Vec2::splat(BRIGHTNESS_OVERLAY_SIZE)),
+            ..default()
+        },
+        Transform::from_xyz(0.0, 0.0, BRIGHTNESS_OVERLAY_Z),
+    ));
+}
+
+/// Retints the overlay when the applied brightness changes.
+pub fn sync_brightness_overlay(
+    screen: Res<ScreenBrightness>,
+    mut overlays: Query<&mut Sprite, With<BrightnessOverlay>>,
+) {
+    if !screen.is_changed() {
+        return;
+    }
+    for mut sprite in &mut overlays {
+        sprite.color = overlay_color(screen.0);
+    }
+}
+
+/// Root of a small modal window spawned by the video flow.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct VideoModalRoot;
+
+/// A button inside a video modal; fires its command on click or select.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct VideoModalButton {
+    pub command: MenuCommand,
+}
+
+/// A modal that fires a command when its countdown runs out. The keep
+/// button (or any other resolution) should despawn the modal before the
+/// timer expires; otherwise `on_timeout` is emitted for the modal root.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct ConfirmCountdownModal {
+    pub remaining_secs: f32,
+    /// Text shown ahead of the remaining seconds, e.g. "REVERTING IN".
+    pub text_prefix: &'static str,
+    pub on_timeout: MenuCommand,
+}
+
+impl ConfirmCountdownModal {
+    pub fn new(seconds: f32, text_prefix: &'static str, on_timeout: MenuCommand) -> Self {
+        Self {
+            remaining_secs: seconds,
+            text_prefix,
+            on_timeout,
+        }
+    }
+
+    /// Advances the countdown; true exactly once, on the tick it expires.
+    pub fn tick(&mut self, delta_secs: f32) -> bool {
+        let was_running = self.remaining_secs > 0.0;
+        self.remaining_secs -= delta_secs;
+        was_running && self.remaining_secs <= 0.0
+    }
+
+    pub fn countdown_text(&self) -> String {
+        format!(
+            "{} {:.0}s",
+            self.text_prefix,
+            self.remaining_secs.max(0.0).ceil()
+        )
+    }
+}
+
+/// Body text of a countdown modal, rewritten as the timer ticks.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct ModalCountdownText;
+
+/// Spawns a bare modal window with a title and a horizontal row of
+/// command buttons; returns the window root.
+pub fn spawn_video_modal_base(
+    commands: &mut Commands,
+    scale: &UiScale,
+    title: &str,
+    buttons: &[(&str, MenuCommand)],
+) -> Entity {
+    let root = commands
+        .spawn((
+            UiWindow {
+                has_close_button: false,
+                resizable: false,
+                boundary: crate::ui::window::WindowBoundary {
+                    dimensions: Vec2::new(260.0, 110.0),
+                },
+                ..default()
+            },
+            WindowTitle {
+                text: title.to_string(),
+            },
+            VideoModalRoot,
+        ))
+        .id();
+    let content = commands
+        .spawn((
+            WindowContent::new(root),
+            SelectableMenu::new(
+                buttons.len(),
+                vec![KeyCode::ArrowLeft],
+                vec![KeyCode::ArrowRight],
+                vec![KeyCode::Enter, KeyCode::Space],
+                true,
+            ),
+            Transform::default(),
+            Visibility::Inherited,
+        ))
+        .id();
+    let spacing = 120.0;
+    let left = -(buttons.len() as f32 - 1.0) * spacing * 0.5;
+    for (index, (label, command)) in buttons.iter().enumerate() {
+        commands.spawn((
+            VideoModalButton { command: *command },
+            Text2d::new(*label),
+            TextFont::from_font_size(scaled_font_size(scale, 14.0)),
+            TextColor(SYSTEM_MENU_COLOR),
+            Clickable::new(Vec2::new(100.0, 24.0)),
+            Transform::from_xyz(left + index as f32 * spacing, -70.0, 0.2),
+            ChildOf(content),
+        ));
+    }
+    root
+}
+
+/// Spawns a modal whose timeout fires `on_timeout`, with a ticking
+/// countdown line under the buttons; returns the window root.
+pub fn spawn_confirm_countdown_modal(
+    commands: &mut Commands,
+    scale: &UiScale,
+    title: &str,
+    buttons: &[(&str, MenuCommand)],
+    countdown: ConfirmCountdownModal,
+) -> Entity {
+    let root = spawn_video_modal_base(commands, scale, title, buttons);
+    commands.entity(root).insert(countdown);
+    commands.spawn((
+        ModalCountdownText,
+        WindowContent::new(root),
+        Text2d::new(String::new()),
+        TextFont::from_font_size(scaled_font_size(scale, 12.0)),
+        TextColor(SYSTEM_MENU_COLOR),
+        Transform::from_xyz(0.0, -30.0, 0.2),
+        Visibility::Inherited,
+    ));
+    root
+}
+
+/// The "keep these settings?" modal with its revert countdown.
+pub fn spawn_apply_confirm_modal(commands: &mut Commands, scale: &UiScale) -> Entity {
+    spawn_confirm_countdown_modal(
+        commands,
+        scale,
+        "KEEP SETTINGS?",
+        &[
+            ("KEEP [Y]", MenuCommand::ConfirmVideoSettings),
+            ("REVERT [N]", MenuCommand::RevertVideoSettings),
+        ],
+        ConfirmCountdownModal::new(
+            APPLY_CONFIRM_SECS,
+            "REVERTING IN",
+            MenuCommand::RevertVideoSettings,
+        ),
+    )
+}
+
+/// Ticks every countdown modal, rewrites its timer text, and emits the
+/// modal's timeout command (then closes it) when the timer expires.
+pub fn update_confirm_countdown_modals(
+    mut commands: Commands,
+    time: Res<Time<Real>>,
+    mut events: EventWriter<MenuCommandEvent>,
+    mut modals: Query<(Entity, &mut ConfirmCountdownModal)>,
+    mut countdown_text: Query<(&WindowContent, &mut Text2d), With<ModalCountdownText>>,
+) {
+    for (root, mut modal) in &mut modals {
+        let expired = modal.tick(time.delta_secs());
+        for (content, mut text) in &mut countdown_text {
+            if content.window == root {
+                text.0 = modal.countdown_text();
+            }
+        }
+        if expired {
+            events.write(MenuCommandEvent {
+                root,
+                command: modal.on_timeout,
+            });
+            commands.entity(root).despawn();
+        }
+    }
+}
+
+/// Closes the apply modal once the pending apply is resolved by a button
+/// or shortcut rather than by the timeout.
+pub fn close_resolved_apply_modals(
+    mut commands: Commands,
+    state: Res<VideoSettingsState>,
+    modals: Query<Entity, (With<VideoModalRoot>, With<ConfirmCountdownModal>)>,
+) {
+    if state.pending.is_some() {
+        return;
+    }
+    for modal in &modals {
+        commands.entity(modal).despawn();
+    }
+}
+
+/// Y/N/Backspace shortcuts plus click handling for modal buttons.
+pub fn handle_video_modal_shortcuts(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut events: EventWriter<MenuCommandEvent>,
+    modals: Query<Entity, With<VideoModalRoot>>,
+    menus: Query<(&SelectableMenu, &WindowContent, &Children)>,
+    buttons: Query<(&VideoModalButton, &Clickable)>,
+) {
+    let Some(root) = modals.iter().next() else {
+        return;
+    };
+    let mut letter_fired = false;
+    if keys.just_pressed(KeyCode::KeyY) {
+        letter_fired = true;
+        events.write(MenuCommandEvent {
+            root,
+            command: MenuCommand::ConfirmVideoSettings,
+        });
+    }
+    if keys.just_pressed(KeyCode::KeyN) || keys.just_pressed(KeyCode::Backspace) {
+        letter_fired = true;
+        events.write(MenuCommandEvent {
+            root,
+            command: MenuCommand::RevertVideoSettings,
+        });
+    }
+    // Enter/Space activate the button the modal's SelectableMenu has
+    // focused, so left/right plus confirm works without a mouse. The
+    // letter path wins if both land in one frame — never both.
+    if !letter_fired {
+        for (menu, content, children) in &menus {
+            if content.window != root || !menu.select_triggered {
+                continue;
+            }
+            if let Some((button, _)) = children
+                .iter()
+                .filter_map(|child| buttons.get(child).ok())
+                .nth(menu.selected)
+            {
+                events.write(MenuCommandEvent {
+                    root,
+                    command: button.command,
+                });
+            }
+        }
+    }
+    for (button, clickable) in &buttons {
+        if clickable.triggered {
+            events.write(MenuCommandEvent {
+                root,
+                command: button.command,
+            });
+        }
+    }
+}
+
+/// Highlights the modal button the keyboard selection rests on, so the
+/// Enter target is visible before it fires.
+pub fn sync_video_modal_button_visuals(
+    menus: Query<(&SelectableMenu, &Children), Changed<SelectableMenu>>,
+    mut buttons: Query<(&VideoModalButton, &mut TextColor)>,
+) {
+    for (menu, children) in &menus {
+        let targets: Vec<Entity> = children
+            .iter()
+            .filter(|child| buttons.contains(*child))
+            .collect();
+        for (index, child) in targets.into_iter().enumerate() {
+            if let Ok((_, mut color)) = buttons.get_mut(child) {
+                color.0 = if index == menu.selected {
+                    HIGHLIGHT_COLOR
+                } else {
+                    SYSTEM_MENU_COLOR
+                };
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cycling_wraps_both_directions() {
+        assert_eq!(cycle(&RESOLUTIONS, RESOLUTIONS[0], -1), RESOLUTIONS[4]);
+        assert_eq!(cycle(&RESOLUTIONS, RESOLUTIONS[4], 1), RESOLUTIONS[0]);
+        // Unknown current values fall back to the first entry.
+        assert_eq!(cycle(&RESOLUTIONS, (123, 456), 1), RESOLUTIONS[1]);
+    }
+
+    #[test]
+    fn native_resolution_is_appended_only_when_missing() {
+        let known = available_resolutions(Some(RESOLUTIONS[2]));
+        assert_eq!(known.len(), 3);
+        assert_eq!(*known.last().unwrap(), RESOLUTIONS[2]);
+        // The 4K entry is dropped, the ultrawide itself is appended.
+        let ultrawide = available_resolutions(Some((3440, 1440)));
+        assert_eq!(ultrawide.len(), RESOLUTIONS.len());
+        assert_eq!(*ultrawide.last().unwrap(), (3440, 1440));
+        assert_eq!(
+            resolution_item_label((3440, 1440), Some((3440, 1440))),
+            "3440 x 1440 (NATIVE)"
+        );
+    }
+
+    #[test]
+    fn oversized_resolutions_are_hidden_and_the_largest_fit_is_the_default() {
+        // A 1366 x 768 laptop keeps only 720p plus its own native size.
+        let laptop = available_resolutions(Some((1366, 768)));
+        assert_eq!(laptop, vec![(1280, 720), (1366, 768)]);
+        assert_eq!(default_resolution_index(&laptop), 1);
+        // Without a monitor to measure against, nothing is hidden and
+        // the default is the biggest stock entry.
+        let unknown = available_resolutions(None);
+        assert_eq!(unknown.len(), RESOLUTIONS.len());
+        assert_eq!(default_resolution_index(&unknown), RESOLUTIONS.len() - 1);
+        assert_eq!(default_resolution_index(&[]), 0);
+    }
+
+    #[test]
+    fn a_fullscreen_round_trip_preserves_the_windowed_size() {
+        let windowed = (1600, 900);
+        let mut window = bevy::window::Window::default();
+        window.resolution.set(windowed.0 as f32, windowed.1 as f32);
+        let taken = snapshot_from_window(&window, FrameLimit::Off, 1.0, windowed);
+        assert_eq!(taken.display_mode, VideoDisplayMode::Windowed);
+        assert_eq!(taken.resolution, windowed);
+
+        let mut borderless = taken;
+        borderless.display_mode = VideoDisplayMode::Borderless;
+        apply_snapshot_to_window(&mut window, &borderless);
+        // The OS resizes the surface to the monitor; a snapshot taken
+        // now must still report the remembered windowed size.
+        window.resolution.set(3840.0, 2160.0);
+        let fullscreen = snapshot_from_window(&window, FrameLimit::Off, 1.0, windowed);
+        assert_eq!(fullscreen.display_mode, VideoDisplayMode::Borderless);
+        assert_eq!(fullscreen.resolution, windowed);
+
+        apply_snapshot_to_window(&mut window, &taken);
+        assert_eq!(window.resolution.width() as u32, windowed.0);
+        assert_eq!(window.resolution.height() as u32, windowed.1);
+    }
+
+    #[test]
+    fn brightness_slider_maps_both_ways_with_a_clear_midline() {
+        assert_eq!(brightness_handle_x(1.0), 0.0);
+        for brightness in [BRIGHTNESS_MIN, 0.8, 1.0, 1.2, BRIGHTNESS_MAX] {
+            assert!((brightness_at(brightness_handle_x(brightness)) - brightness).abs() < 1e-5);
+        }
+        assert_eq!(overlay_color(1.0).alpha(), 0.0);
+        assert!(overlay_color(BRIGHTNESS_MIN).alpha() > 0.0);
+        assert!(overlay_color(BRIGHTNESS_MAX).alpha() > 0.0);
+    }
+
+    #[test]
+    fn countdown_modal_ticks_down_and_fires_exactly_once() {
+        let mut modal =
+            ConfirmCountdownModal::new(3.0, "REVERTING IN", MenuCommand::RevertVideoSettings);
+        assert_eq!(modal.countdown_text(), "REVERTING IN 3s");
+        assert!(!modal.tick(1.0));
+        assert_eq!(modal.countdown_text(), "REVERTING IN 2s");
+        assert!(modal.tick(2.5));
+        assert_eq!(modal.countdown_text(), "REVERTING IN 0s");
+        // Already expired: must not fire again.
+        assert!(!modal.tick(1.0));
+    }
+}
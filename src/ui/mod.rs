@@ -0,0 +1,12 @@
+pub mod context_menu;
+pub mod focus;
+pub mod graph;
+pub mod hold_confirm;
+pub mod menu;
+pub mod notifications;
+pub mod shapes;
+pub mod table;
+pub mod text;
+pub mod tooltip;
+pub mod scroll;
+pub mod window;
@@ -0,0 +1,218 @@
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+
+use crate::{
+    systems::{
+        colors::{DANGER_COLOR, PRIMARY_COLOR, WARNING_COLOR, WINDOW_BODY_COLOR},
+        scheduling::AutoDespawn,
+    },
+    ui::{
+        scroll::{ContentSize, ScrollState, ScrollableRoot},
+        shapes::BorderedRectangle,
+        window::{Window, WindowContent, WindowTitle},
+    },
+};
+
+/// Oldest entries beyond this are dropped.
+pub const NOTIFICATION_CAP: usize = 100;
+pub const NOTIFICATION_PANEL_KEY: KeyCode = KeyCode::F2;
+
+const TOAST_SECS: f32 = 4.0;
+const NOTIFICATION_ROW_HEIGHT: f32 = 16.0;
+const NOTIFICATION_FONT_SIZE: f32 = 11.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NotificationSeverity {
+    Info,
+    Warning,
+    Error,
+}
+
+impl NotificationSeverity {
+    pub fn color(&self) -> Color {
+        match self {
+            NotificationSeverity::Info => PRIMARY_COLOR,
+            NotificationSeverity::Warning => WARNING_COLOR,
+            NotificationSeverity::Error => DANGER_COLOR,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Notification {
+    pub severity: NotificationSeverity,
+    pub message: String,
+    pub timestamp_secs: f64,
+}
+
+/// The single funnel for cross-cutting player/system feedback (autosave,
+/// settings applied, achievement progress). Posting is a cheap push; the
+/// panel only rebuilds when open and dirty.
+#[derive(Resource, Debug, Default)]
+pub struct Notifications {
+    entries: VecDeque<Notification>,
+    /// Bumped on every post so consumers can detect changes cheaply.
+    pub revision: u64,
+}
+
+impl Notifications {
+    pub fn post(&mut self, severity: NotificationSeverity, message: impl Into<String>, now: f64) {
+        self.entries.push_back(Notification {
+            severity,
+            message: message.into(),
+            timestamp_secs: now,
+        });
+        while self.entries.len() > NOTIFICATION_CAP {
+            self.entries.pop_front();
+        }
+        self.revision += 1;
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Notification> {
+        self.entries.iter()
+    }
+
+    pub fn latest(&self) -> Option<&Notification> {
+        self.entries.back()
+    }
+}
+
+/// Root of the toggleable log window.
+#[derive(Component)]
+struct NotificationPanel {
+    seen_revision: u64,
+}
+
+#[derive(Component)]
+struct NotificationRow;
+
+#[derive(Component)]
+struct NotificationToast;
+
+/// Spawns a transient toast for every newly posted notification.
+fn spawn_notification_toasts(
+    mut commands: Commands,
+    notifications: Res<Notifications>,
+    mut seen: Local<u64>,
+) {
+    if notifications.revision == *seen {
+        return;
+    }
+    *seen = notifications.revision;
+    let Some(latest) = notifications.latest() else {
+        return;
+    };
+    let size = Vec2::new(
+        latest.message.len() as f32 * NOTIFICATION_FONT_SIZE * 0.55 + 16.0,
+        NOTIFICATION_ROW_HEIGHT + 10.0,
+    );
+    commands
+        .spawn((
+            NotificationToast,
+            AutoDespawn::after(TOAST_SECS),
+            BorderedRectangle {
+                dimensions: size,
+                border_thickness: 1.0,
+                border_color: latest.severity.color(),
+                fill_color: WINDOW_BODY_COLOR,
+            },
+            Transform::from_xyz(0.0, 260.0, 650.0),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text2d::new(latest.message.clone()),
+                TextFont::from_font_size(NOTIFICATION_FONT_SIZE),
+                TextColor(latest.severity.color()),
+                Transform::from_xyz(0.0, 0.0, 0.5),
+            ));
+        });
+}
+
+fn toggle_notification_panel(
+    mut commands: Commands,
+    keys: Res<ButtonInput<KeyCode>>,
+    panels: Query<Entity, With<NotificationPanel>>,
+) {
+    if !keys.just_pressed(NOTIFICATION_PANEL_KEY) {
+        return;
+    }
+    if let Ok(panel) = panels.single() {
+        commands.entity(panel).despawn();
+        return;
+    }
+    commands.spawn((
+        NotificationPanel { seen_revision: 0 },
+        Window {
+            boundary: crate::ui::window::WindowBoundary {
+                dimensions: Vec2::new(360.0, 220.0),
+            },
+            ..default()
+        },
+        WindowTitle {
+            text: String::from("SYSTEM LOG"),
+        },
+        Transform::from_xyz(200.0, -120.0, 0.0),
+    ));
+}
+
+/// Rebuilds the open panel's rows when new entries arrive, then pins the
+/// scroll position to the newest entry.
+fn sync_notification_panel(
+    mut commands: Commands,
+    notifications: Res<Notifications>,
+    mut panels: Query<(Entity, &mut NotificationPanel)>,
+    rows: Query<Entity, With<NotificationRow>>,
+    children: Query<&Children>,
+    mut scroll_roots: Query<(&ScrollableRoot, &mut ScrollState)>,
+) {
+    let Ok((panel, mut state)) = panels.single_mut() else {
+        return;
+    };
+    if state.seen_revision == notifications.revision {
+        return;
+    }
+    state.seen_revision = notifications.revision;
+    for row in &rows {
+        commands.entity(row).despawn();
+    }
+    for (index, entry) in notifications.iter().enumerate() {
+        let label = format!("[{:>8.1}] {}", entry.timestamp_secs, entry.message);
+        commands.spawn((
+            NotificationRow,
+            WindowContent::new(panel),
+            Text2d::new(label),
+            TextFont::from_font_size(NOTIFICATION_FONT_SIZE),
+            TextColor(entry.severity.color()),
+            ContentSize(Vec2::new(340.0, NOTIFICATION_ROW_HEIGHT)),
+            Transform::from_xyz(
+                0.0,
+                -(index as f32) * NOTIFICATION_ROW_HEIGHT,
+                0.5,
+            ),
+        ));
+    }
+    // Auto-scroll to the newest entry; the clamp pass trims the excess.
+    if let Ok(kids) = children.get(panel) {
+        for child in kids.iter() {
+            if let Ok((_, mut scroll)) = scroll_roots.get_mut(child) {
+                scroll.offset_px = f32::MAX;
+            }
+        }
+    }
+}
+
+pub struct NotificationsPlugin;
+
+impl Plugin for NotificationsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Notifications>().add_systems(
+            Update,
+            (
+                spawn_notification_toasts,
+                toggle_notification_panel,
+                sync_notification_panel,
+            ),
+        );
+    }
+}
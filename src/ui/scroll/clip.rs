@@ -0,0 +1,180 @@
+use bevy::{
+    prelude::*,
+    render::{
+        camera::RenderTarget,
+        render_resource::{
+            Extent3d, TextureDescriptor, TextureDimension, TextureFormat, TextureUsages,
+        },
+        view::RenderLayers,
+    },
+};
+
+use super::ScrollableRoot;
+
+/// Render layers below this are left for ordinary world rendering; clip
+/// regions claim layers from here upward, round-robin.
+const CLIP_LAYER_BASE: usize = 8;
+const CLIP_LAYER_COUNT: usize = 24;
+
+/// Hands out render layers for clip regions. Layers recycle, so two
+/// simultaneously clipped regions sharing a layer would bleed into each
+/// other — acceptable for the handful of clipped windows we spawn.
+#[derive(Resource, Debug, Default)]
+pub struct ClipLayerAllocator {
+    next: usize,
+}
+
+impl ClipLayerAllocator {
+    pub fn allocate(&mut self) -> usize {
+        let layer = CLIP_LAYER_BASE + self.next % CLIP_LAYER_COUNT;
+        self.next += 1;
+        layer
+    }
+}
+
+/// Requests that this scroll root's content be rendered offscreen and
+/// cropped to the viewport rect rather than spilling past it.
+#[derive(Component, Debug, Default)]
+pub struct ScrollClip;
+
+/// Wiring for an active clip region: the offscreen camera, the sprite
+/// displaying its texture, and the claimed render layer.
+#[derive(Component, Debug)]
+pub struct ScrollClipRuntime {
+    layer: usize,
+    camera: Entity,
+    display: Entity,
+    image: Handle<Image>,
+}
+
+#[derive(Component)]
+struct ScrollClipCamera {
+    root: Entity,
+}
+
+fn clip_image(size: UVec2) -> Image {
+    let size = size.max(UVec2::ONE);
+    let mut image = Image {
+        texture_descriptor: TextureDescriptor {
+            label: Some("scroll_clip_target"),
+            size: Extent3d {
+                width: size.x,
+                height: size.y,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: TextureDimension::D2,
+            format: TextureFormat::Bgra8UnormSrgb,
+            usage: TextureUsages::RENDER_ATTACHMENT | TextureUsages::TEXTURE_BINDING,
+            view_formats: &[],
+        },
+        ..default()
+    };
+    image.resize(image.texture_descriptor.size);
+    image
+}
+
+/// Builds the offscreen camera + display sprite for freshly clipped
+/// roots. The camera renders only the claimed layer; the content subtree
+/// is moved onto that layer by `propagate_clip_layers`, so the main
+/// camera sees nothing but the cropped texture.
+pub fn setup_scroll_clips(
+    mut commands: Commands,
+    mut allocator: ResMut<ClipLayerAllocator>,
+    mut images: ResMut<Assets<Image>>,
+    roots: Query<(Entity, &ScrollableRoot), (Added<ScrollClip>, Without<ScrollClipRuntime>)>,
+) {
+    for (root, scrollable) in &roots {
+        let layer = allocator.allocate();
+        let image = images.add(clip_image(scrollable.viewport_size.as_uvec2()));
+        let camera = commands
+            .spawn((
+                Camera2d,
+                Camera {
+                    target: RenderTarget::Image(image.clone().into()),
+                    clear_color: ClearColorConfig::Custom(Color::NONE),
+                    order: -1,
+                    ..default()
+                },
+                RenderLayers::layer(layer),
+                ScrollClipCamera { root },
+            ))
+            .id();
+        let display = commands
+            .spawn((
+                Sprite {
+                    image: image.clone(),
+                    custom_size: Some(scrollable.viewport_size),
+                    ..default()
+                },
+                Transform::from_xyz(0.0, 0.0, 0.1),
+                ChildOf(root),
+            ))
+            .id();
+        commands.entity(root).insert(ScrollClipRuntime {
+            layer,
+            camera,
+            display,
+            image,
+        });
+    }
+}
+
+/// Keeps each clip camera centred on its root and its texture sized to
+/// the current viewport, and tears down cameras whose root is gone.
+pub fn sync_scroll_clips(
+    mut commands: Commands,
+    mut images: ResMut<Assets<Image>>,
+    roots: Query<(&ScrollableRoot, &ScrollClipRuntime, &GlobalTransform)>,
+    mut cameras: Query<(Entity, &ScrollClipCamera, &mut Transform)>,
+    mut displays: Query<&mut Sprite>,
+) {
+    for (entity, camera, mut transform) in &mut cameras {
+        let Ok((scrollable, runtime, global)) = roots.get(camera.root) else {
+            commands.entity(entity).despawn();
+            continue;
+        };
+        transform.translation = global.translation().truncate().extend(transform.translation.z);
+
+        let wanted = scrollable.viewport_size.as_uvec2().max(UVec2::ONE);
+        if let Some(image) = images.get(&runtime.image) {
+            if image.size() != wanted {
+                *images.get_mut(&runtime.image).unwrap() = clip_image(wanted);
+            }
+        }
+        if let Ok(mut sprite) = displays.get_mut(runtime.display) {
+            sprite.custom_size = Some(scrollable.viewport_size);
+        }
+    }
+}
+
+/// Pushes the clip layer onto every entity under the clipped root's
+/// subtree (except the display sprite, which must stay visible to the
+/// main camera).
+pub fn propagate_clip_layers(
+    mut commands: Commands,
+    roots: Query<(Entity, &ScrollClipRuntime)>,
+    children: Query<&Children>,
+    layered: Query<&RenderLayers>,
+) {
+    for (root, runtime) in &roots {
+        let mut stack: Vec<Entity> = children
+            .get(root)
+            .map(|kids| kids.iter().collect())
+            .unwrap_or_default();
+        while let Some(entity) = stack.pop() {
+            if entity == runtime.display {
+                continue;
+            }
+            if layered.get(entity).is_err() {
+                commands
+                    .entity(entity)
+                    .insert(RenderLayers::layer(runtime.layer));
+            }
+            if let Ok(kids) = children.get(entity) {
+                stack.extend(kids.iter());
+            }
+        }
+    }
+}
@@ -0,0 +1,1071 @@
+use bevy::{
+    input::mouse::{MouseScrollUnit, MouseWheel},
+    prelude::*,
+};
+
+use crate::systems::{
+    colors::DIM_COLOR,
+    interaction::{Clickable, CustomCursor},
+};
+
+pub mod clip;
+
+/// Pixels scrolled per `MouseScrollUnit::Line` wheel notch.
+pub const SCROLL_WHEEL_LINE_PX: f32 = 40.0;
+/// Pixels scrolled per arrow-key step.
+pub const SCROLL_KEYBOARD_STEP_PX: f32 = 48.0;
+/// Offsets closer than this are treated as equal.
+pub const SCROLL_EPSILON: f32 = 0.5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ScrollAxis {
+    #[default]
+    Vertical,
+    Horizontal,
+}
+
+/// Marks an entity as a scroll region root. The root owns a viewport rect
+/// (world-space, centred on the root) and a content child whose local
+/// translation is driven from `ScrollState::offset_px`.
+#[derive(Component, Debug, Clone, Default)]
+#[require(ScrollState, LastNotifiedOffset)]
+pub struct ScrollableRoot {
+    pub axis: ScrollAxis,
+    /// Viewport extent along both axes, in world units.
+    pub viewport_size: Vec2,
+    /// Total content extent along the scroll axis.
+    pub content_extent: f32,
+}
+
+/// Current scroll position in pixels from the content origin.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct ScrollState {
+    pub offset_px: f32,
+    pub max_offset: f32,
+}
+
+/// Fired when a root's offset moves by more than [`SCROLL_EPSILON`],
+/// whatever caused it (input, animation, programmatic scroll-to).
+/// Consumers use this for lazy loading near the extents.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct ScrollOffsetChanged {
+    pub root: Entity,
+    pub axis: ScrollAxis,
+    pub offset_px: f32,
+    pub max_offset: f32,
+}
+
+/// Last offset for which a [`ScrollOffsetChanged`] was emitted.
+#[derive(Component, Debug, Clone, Copy, Default)]
+struct LastNotifiedOffset(f32);
+
+/// Emits [`ScrollOffsetChanged`] once all offset mutators for the frame
+/// have run.
+fn emit_scroll_offset_changes(
+    mut events: EventWriter<ScrollOffsetChanged>,
+    mut roots: Query<(
+        Entity,
+        &ScrollableRoot,
+        &ScrollState,
+        &mut LastNotifiedOffset,
+    )>,
+) {
+    for (entity, root, state, mut last) in &mut roots {
+        if (state.offset_px - last.0).abs() <= SCROLL_EPSILON {
+            continue;
+        }
+        last.0 = state.offset_px;
+        events.write(ScrollOffsetChanged {
+            root: entity,
+            axis: root.axis,
+            offset_px: state.offset_px,
+            max_offset: state.max_offset,
+        });
+    }
+}
+
+/// Measured size of a piece of scrollable content, in world units. The
+/// window runtime unions these to derive the content extent.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct ContentSize(pub Vec2);
+
+/// A keyed item inside a scroll region; `extent` is its length along the
+/// scroll axis, used for item-relative positioning.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct ScrollableItem {
+    pub key: u64,
+    pub extent: f32,
+}
+
+/// Set when focus-follow should not fight a user's manual scroll.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct ScrollFocusFollowLock {
+    pub manual_override: bool,
+}
+
+/// Marks the content child of a `ScrollableRoot`. `origin_offset` is a
+/// static shift applied on top of the scroll offset (used for content
+/// alignment inside oversized viewports).
+#[derive(Component, Debug, Clone, Copy)]
+pub struct ScrollContent {
+    pub root: Entity,
+    pub origin_offset: Vec2,
+}
+
+impl ScrollContent {
+    pub fn new(root: Entity) -> Self {
+        Self {
+            root,
+            origin_offset: Vec2::ZERO,
+        }
+    }
+}
+
+/// Scrollbar visual attached to a scroll root.
+#[derive(Component, Debug, Clone)]
+pub struct ScrollBar {
+    pub root: Entity,
+    pub axis: ScrollAxis,
+    pub width: f32,
+}
+
+pub const SCROLL_BAR_WIDTH: f32 = 6.0;
+
+/// Glyph size and hit size of a scroll-end jump button.
+pub const SCROLL_END_BUTTON_SIZE: f32 = 10.0;
+
+/// Opt-in flag on a [`ScrollBar`]: spawns small clickable arrows just
+/// past the track's ends that jump the offset straight to the top or
+/// bottom of the range. The arrows are bar children, so they inherit
+/// the bar's hidden state whenever there is no scroll range.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct ScrollBarEndButtons;
+
+/// One of the jump arrows belonging to a scrollbar.
+#[derive(Component, Debug, Clone, Copy)]
+struct ScrollEndButton {
+    bar: Entity,
+    /// Jumps to the start of the range; otherwise to the end.
+    to_start: bool,
+}
+
+/// Spawns the pair of jump arrows once the flag lands on a bar.
+pub fn spawn_scroll_end_buttons(
+    mut commands: Commands,
+    bars: Query<(Entity, &ScrollBar), Added<ScrollBarEndButtons>>,
+) {
+    for (entity, bar) in &bars {
+        for to_start in [true, false] {
+            let glyph = match (bar.axis, to_start) {
+                (ScrollAxis::Vertical, true) => "^",
+                (ScrollAxis::Vertical, false) => "v",
+                (ScrollAxis::Horizontal, true) => "<",
+                (ScrollAxis::Horizontal, false) => ">",
+            };
+            commands.spawn((
+                ScrollEndButton {
+                    bar: entity,
+                    to_start,
+                },
+                Text2d::new(glyph),
+                TextFont::from_font_size(SCROLL_END_BUTTON_SIZE),
+                TextColor(DIM_COLOR),
+                Clickable::new(Vec2::splat(SCROLL_END_BUTTON_SIZE)),
+                Transform::from_xyz(0.0, 0.0, 0.5),
+                Visibility::Inherited,
+                ChildOf(entity),
+            ));
+        }
+    }
+}
+
+/// Seats each jump arrow just past its track end. The bar entity is the
+/// moving thumb, so its own local translation is subtracted back out to
+/// keep the arrows pinned while the thumb travels.
+pub fn sync_scroll_end_buttons(
+    roots: Query<&ScrollableRoot, Without<ScrollBar>>,
+    bars: Query<(&ScrollBar, &Transform), Without<ScrollEndButton>>,
+    mut buttons: Query<(&ScrollEndButton, &mut Transform), Without<ScrollBar>>,
+) {
+    for (button, mut transform) in &mut buttons {
+        let Ok((bar, bar_transform)) = bars.get(button.bar) else {
+            continue;
+        };
+        let Ok(root) = roots.get(bar.root) else {
+            continue;
+        };
+        let track = match bar.axis {
+            ScrollAxis::Vertical => root.viewport_size.y,
+            ScrollAxis::Horizontal => root.viewport_size.x,
+        };
+        let end = track * 0.5 + SCROLL_END_BUTTON_SIZE * 0.75;
+        match bar.axis {
+            ScrollAxis::Vertical => {
+                let along = if button.to_start { end } else { -end };
+                transform.translation.x = 0.0;
+                transform.translation.y = along - bar_transform.translation.y;
+            }
+            ScrollAxis::Horizontal => {
+                let along = if button.to_start { -end } else { end };
+                transform.translation.x = along - bar_transform.translation.x;
+                transform.translation.y = 0.0;
+            }
+        }
+    }
+}
+
+/// Snaps the owning root to an end of its range when an arrow fires.
+pub fn handle_scroll_end_buttons(
+    buttons: Query<(&ScrollEndButton, &Clickable)>,
+    bars: Query<&ScrollBar>,
+    mut roots: Query<&mut ScrollState, With<ScrollableRoot>>,
+) {
+    for (button, clickable) in &buttons {
+        if !clickable.triggered {
+            continue;
+        }
+        let Ok(bar) = bars.get(button.bar) else {
+            continue;
+        };
+        let Ok(mut state) = roots.get_mut(bar.root) else {
+            continue;
+        };
+        state.offset_px = if button.to_start {
+            0.0
+        } else {
+            state.max_offset
+        };
+    }
+}
+
+/// Opt-in hover expansion for a scrollbar: thin by default so it wastes
+/// no content space, thickening while the cursor sits within the hover
+/// band so it is actually grabbable.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct ScrollBarHoverExpand {
+    pub thin: f32,
+    pub thick: f32,
+    /// Extra reach around the thumb that counts as hovering, so the
+    /// thin bar doesn't require pixel-perfect aim to wake up.
+    pub hover_band: f32,
+}
+
+impl Default for ScrollBarHoverExpand {
+    fn default() -> Self {
+        Self {
+            thin: SCROLL_BAR_WIDTH,
+            thick: 14.0,
+            hover_band: 10.0,
+        }
+    }
+}
+
+/// Pixels-per-second rate the bar width animates at.
+const SCROLL_BAR_EXPAND_SPEED: f32 = 90.0;
+
+/// Moves the current bar width towards `target` at the expansion rate.
+pub fn eased_bar_width(current: f32, target: f32, delta_secs: f32) -> f32 {
+    let max_step = SCROLL_BAR_EXPAND_SPEED * delta_secs;
+    current + (target - current).clamp(-max_step, max_step)
+}
+
+/// Animates `ScrollBar::width` between thin and thick depending on
+/// whether the cursor sits inside the thumb's hover band.
+pub fn animate_scrollbar_hover_expansion(
+    time: Res<Time>,
+    cursor: Res<CustomCursor>,
+    mut bars: Query<(
+        &mut ScrollBar,
+        &ScrollBarHoverExpand,
+        &GlobalTransform,
+        &Sprite,
+        &Visibility,
+    )>,
+) {
+    for (mut bar, expand, transform, sprite, visibility) in &mut bars {
+        let target = if *visibility == Visibility::Hidden {
+            expand.thin
+        } else {
+            let centre = transform.translation().truncate();
+            let half = sprite.custom_size.unwrap_or(Vec2::ZERO) * 0.5
+                + Vec2::splat(expand.hover_band);
+            let inside = (cursor.position.x - centre.x).abs() <= half.x
+                && (cursor.position.y - centre.y).abs() <= half.y;
+            if inside {
+                expand.thick
+            } else {
+                expand.thin
+            }
+        };
+        let width = eased_bar_width(bar.width, target, time.delta_secs());
+        if (width - bar.width).abs() > f32::EPSILON {
+            bar.width = width;
+        }
+    }
+}
+
+/// Clamps the offset into the valid range for the current extents.
+pub fn clamp_scroll_state(state: &mut ScrollState) {
+    state.offset_px = state.offset_px.clamp(0.0, state.max_offset.max(0.0));
+}
+
+/// Recomputes `max_offset` from the root's viewport and content extents.
+pub fn sync_scroll_extents(mut roots: Query<(&ScrollableRoot, &mut ScrollState)>) {
+    for (root, mut state) in &mut roots {
+        let viewport_extent = match root.axis {
+            ScrollAxis::Vertical => root.viewport_size.y,
+            ScrollAxis::Horizontal => root.viewport_size.x,
+        };
+        state.max_offset = (root.content_extent - viewport_extent).max(0.0);
+        clamp_scroll_state(&mut state);
+    }
+}
+
+/// Applies the scroll offset to each content child's local translation.
+pub fn sync_scroll_content_offsets(
+    roots: Query<(&ScrollableRoot, &ScrollState)>,
+    mut contents: Query<(&ScrollContent, &mut Transform)>,
+) {
+    for (content, mut transform) in &mut contents {
+        let Ok((root, state)) = roots.get(content.root) else {
+            continue;
+        };
+        match root.axis {
+            // Scrolling down moves content up.
+            ScrollAxis::Vertical => {
+                transform.translation.y = state.offset_px + content.origin_offset.y;
+                transform.translation.x = content.origin_offset.x;
+            }
+            ScrollAxis::Horizontal => {
+                transform.translation.x = -state.offset_px + content.origin_offset.x;
+                transform.translation.y = content.origin_offset.y;
+            }
+        }
+    }
+}
+
+/// Easing curve applied to animated scrolling.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ScrollEasing {
+    Linear,
+    #[default]
+    EaseOutCubic,
+    EaseInOutQuad,
+}
+
+impl ScrollEasing {
+    /// Maps a linear fraction in `[0, 1]` through the curve.
+    pub fn apply(&self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            ScrollEasing::Linear => t,
+            ScrollEasing::EaseOutCubic => 1.0 - (1.0 - t).powi(3),
+            ScrollEasing::EaseInOutQuad => {
+                if t < 0.5 {
+                    2.0 * t * t
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(2) * 0.5
+                }
+            }
+        }
+    }
+}
+
+/// Global toggle and tuning for eased scrolling. Pixel-precise wheel
+/// input (touchpads) always applies instantly regardless.
+#[derive(Resource, Debug, Clone)]
+pub struct ScrollAnimationConfig {
+    pub enabled: bool,
+    pub duration_secs: f32,
+    pub easing: ScrollEasing,
+}
+
+impl Default for ScrollAnimationConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            duration_secs: 0.18,
+            easing: ScrollEasing::default(),
+        }
+    }
+}
+
+/// In-flight eased scroll on a root. Removed when the target is reached;
+/// retargeting mid-flight restarts the curve from the current offset.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct ScrollAnimation {
+    pub target_px: f32,
+    /// Current speed in px/s, sampled each frame for callers that care
+    /// about motion (e.g. velocity-matched visuals).
+    pub velocity: f32,
+    pub duration: f32,
+    from_px: f32,
+    elapsed: f32,
+}
+
+impl ScrollAnimation {
+    pub fn toward(from_px: f32, target_px: f32, duration: f32) -> Self {
+        Self {
+            target_px,
+            velocity: 0.0,
+            duration,
+            from_px,
+            elapsed: 0.0,
+        }
+    }
+}
+
+/// Advances eased scrolls, writing the interpolated offset into
+/// `ScrollState` and despawning finished animations.
+pub fn animate_scroll_offsets(
+    mut commands: Commands,
+    time: Res<Time>,
+    config: Res<ScrollAnimationConfig>,
+    mut roots: Query<(Entity, &mut ScrollAnimation, &mut ScrollState)>,
+) {
+    for (entity, mut animation, mut state) in &mut roots {
+        animation.elapsed += time.delta_secs();
+        let fraction = if animation.duration <= 0.0 {
+            1.0
+        } else {
+            animation.elapsed / animation.duration
+        };
+        let previous = state.offset_px;
+        state.offset_px = animation.from_px
+            + (animation.target_px - animation.from_px) * config.easing.apply(fraction);
+        clamp_scroll_state(&mut state);
+        let delta = time.delta_secs();
+        animation.velocity = if delta > 0.0 {
+            (state.offset_px - previous) / delta
+        } else {
+            0.0
+        };
+        if fraction >= 1.0 {
+            commands.entity(entity).remove::<ScrollAnimation>();
+        }
+    }
+}
+
+/// Velocity imparted per pixel of wheel delta when momentum is enabled.
+const SCROLL_MOMENTUM_IMPULSE: f32 = 6.0;
+/// Momentum below this speed is dropped.
+const SCROLL_MOMENTUM_REST_PX_S: f32 = 1.0;
+
+/// Global toggle and friction for wheel inertia. Disabled by default;
+/// when on, line-wheel input feeds [`ScrollMomentum`] instead of the
+/// eased animation (keyboard input keeps the animation either way).
+#[derive(Resource, Debug, Clone)]
+pub struct ScrollMomentumConfig {
+    pub enabled: bool,
+    /// Exponential decay rate; higher stops the flick sooner.
+    pub friction: f32,
+}
+
+impl Default for ScrollMomentumConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            friction: 4.0,
+        }
+    }
+}
+
+/// Residual scroll velocity on a root, decayed each frame.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct ScrollMomentum {
+    pub velocity_px_s: f32,
+}
+
+/// Velocity after `delta_secs` of exponential friction.
+pub fn decayed_velocity(velocity_px_s: f32, friction: f32, delta_secs: f32) -> f32 {
+    velocity_px_s * (-friction * delta_secs).exp()
+}
+
+/// Integrates momentum into the offset and decays it, zeroing the
+/// velocity when the offset hits either clamp so the region does not
+/// strain against its extents.
+pub fn apply_scroll_momentum(
+    mut commands: Commands,
+    time: Res<Time>,
+    config: Res<ScrollMomentumConfig>,
+    mut roots: Query<(Entity, &mut ScrollMomentum, &mut ScrollState)>,
+) {
+    for (entity, mut momentum, mut state) in &mut roots {
+        if !config.enabled {
+            commands.entity(entity).remove::<ScrollMomentum>();
+            continue;
+        }
+        let delta_secs = time.delta_secs();
+        state.offset_px += momentum.velocity_px_s * delta_secs;
+        let unclamped = state.offset_px;
+        clamp_scroll_state(&mut state);
+        momentum.velocity_px_s = if state.offset_px != unclamped {
+            0.0
+        } else {
+            decayed_velocity(momentum.velocity_px_s, config.friction, delta_secs)
+        };
+        if momentum.velocity_px_s.abs() < SCROLL_MOMENTUM_REST_PX_S {
+            commands.entity(entity).remove::<ScrollMomentum>();
+        }
+    }
+}
+
+/// Offsets a root is allowed to rest at, e.g. section boundaries in a
+/// tabbed settings window. Once input settles the offset animates (or
+/// jumps, when `hard`) to the nearest point within `threshold_px`;
+/// offsets further out stay where the user left them.
+#[derive(Component, Debug, Clone)]
+pub struct ScrollSnapPoints {
+    pub points: Vec<f32>,
+    /// Maximum distance a snap will pull the offset.
+    pub threshold_px: f32,
+    /// Jump to the point instantly instead of easing.
+    pub hard: bool,
+}
+
+impl ScrollSnapPoints {
+    pub fn new(points: impl IntoIterator<Item = f32>) -> Self {
+        Self {
+            points: points.into_iter().collect(),
+            threshold_px: 64.0,
+            hard: false,
+        }
+    }
+}
+
+/// The snap point nearest `offset_px`, if any lies within
+/// `threshold_px`. Returns `None` when the offset should stay put.
+pub fn nearest_snap_point(offset_px: f32, points: &[f32], threshold_px: f32) -> Option<f32> {
+    points
+        .iter()
+        .copied()
+        .map(|point| (point, (point - offset_px).abs()))
+        .filter(|(_, distance)| *distance <= threshold_px)
+        .min_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map(|(point, _)| point)
+}
+
+/// Resolves rested roots onto their snap points. "Settled" means no
+/// eased animation or momentum in flight, the left button up (so a
+/// scrollbar-thumb drag snaps on release, not mid-drag) and no wheel
+/// input this frame; keyboard steps settle once their animation lands.
+pub fn resolve_scroll_snap_points(
+    mut commands: Commands,
+    mut wheel: EventReader<MouseWheel>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    config: Res<ScrollAnimationConfig>,
+    animations: Query<(), With<ScrollAnimation>>,
+    momenta: Query<(), With<ScrollMomentum>>,
+    mut roots: Query<(Entity, &ScrollSnapPoints, &mut ScrollState)>,
+) {
+    let wheel_active = wheel.read().next().is_some();
+    if wheel_active || mouse.pressed(MouseButton::Left) {
+        return;
+    }
+    for (entity, snap, mut state) in &mut roots {
+        if animations.contains(entity) || momenta.contains(entity) {
+            continue;
+        }
+        let Some(target) = nearest_snap_point(state.offset_px, &snap.points, snap.threshold_px)
+        else {
+            continue;
+        };
+        let target = target.clamp(0.0, state.max_offset.max(0.0));
+        if (target - state.offset_px).abs() <= SCROLL_EPSILON {
+            continue;
+        }
+        if snap.hard || !config.enabled {
+            state.offset_px = target;
+            clamp_scroll_state(&mut state);
+        } else {
+            commands.entity(entity).insert(ScrollAnimation::toward(
+                state.offset_px,
+                target,
+                config.duration_secs,
+            ));
+        }
+    }
+}
+
+/// Per-root overrides for scroll step sizes. Roots without one use the
+/// `SCROLL_WHEEL_LINE_PX`/`SCROLL_KEYBOARD_STEP_PX` constants and a page
+/// factor of one viewport; the component always takes precedence.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct ScrollStepConfig {
+    /// Pixels per `MouseScrollUnit::Line` notch.
+    pub line_px: f32,
+    /// Pixels per arrow-key step.
+    pub keyboard_px: f32,
+    /// Fraction of the viewport scrolled per PageUp/PageDown press.
+    pub page_factor: f32,
+}
+
+impl Default for ScrollStepConfig {
+    fn default() -> Self {
+        Self {
+            line_px: SCROLL_WHEEL_LINE_PX,
+            keyboard_px: SCROLL_KEYBOARD_STEP_PX,
+            page_factor: 1.0,
+        }
+    }
+}
+
+/// Eased scroll delta for the given input counts under a step config.
+pub fn eased_scroll_delta(
+    line_notches: f32,
+    key_steps: f32,
+    page_steps: f32,
+    viewport_extent: f32,
+    step: &ScrollStepConfig,
+) -> f32 {
+    line_notches * step.line_px
+        + key_steps * step.keyboard_px
+        + page_steps * step.page_factor * viewport_extent
+}
+
+/// One-shot request to bring the [`ScrollableItem`] with `key` into view.
+/// Insert on the scroll root; removed once handled (or if the key is not
+/// found among the root's items).
+#[derive(Component, Debug, Clone, Copy)]
+pub struct ScrollToRequest {
+    pub key: u64,
+}
+
+/// Minimal offset change that brings an item spanning
+/// `[item_start, item_start + item_extent]` fully into a viewport of
+/// `viewport_extent` starting at `current_offset`. Already-visible items
+/// leave the offset untouched.
+pub fn scroll_offset_for_item(
+    item_start: f32,
+    item_extent: f32,
+    viewport_extent: f32,
+    current_offset: f32,
+) -> f32 {
+    let item_end = item_start + item_extent;
+    if item_start < current_offset {
+        item_start
+    } else if item_end > current_offset + viewport_extent {
+        item_end - viewport_extent
+    } else {
+        current_offset
+    }
+}
+
+/// Resolves `ScrollToRequest`s: walks the root's subtree for its items in
+/// order, accumulates extents up to the requested key and adjusts the
+/// offset so the item sits inside the viewport. Clears any manual
+/// override so focus-follow resumes from the new position.
+pub fn handle_scroll_to_requests(
+    mut commands: Commands,
+    mut roots: Query<(
+        Entity,
+        &ScrollToRequest,
+        &ScrollableRoot,
+        &mut ScrollState,
+        Option<&mut ScrollFocusFollowLock>,
+    )>,
+    children: Query<&Children>,
+    items: Query<&ScrollableItem>,
+) {
+    for (entity, request, root, mut state, lock) in &mut roots {
+        // The jump supersedes any eased scroll in flight.
+        commands
+            .entity(entity)
+            .remove::<(ScrollToRequest, ScrollAnimation)>();
+
+        let mut cumulative = 0.0;
+        let mut found: Option<(f32, f32)> = None;
+        let mut stack: Vec<Entity> = children
+            .get(entity)
+            .map(|kids| kids.iter().collect())
+            .unwrap_or_default();
+        // Children are pushed then popped, so reverse to keep spawn order.
+        stack.reverse();
+        while let Some(candidate) = stack.pop() {
+            if let Ok(item) = items.get(candidate) {
+                if item.key == request.key {
+                    found = Some((cumulative, item.extent));
+                    break;
+                }
+                cumulative += item.extent;
+            }
+            if let Ok(kids) = children.get(candidate) {
+                let mut kids: Vec<Entity> = kids.iter().collect();
+                kids.reverse();
+                stack.append(&mut kids);
+            }
+        }
+        let Some((item_start, item_extent)) = found else {
+            continue;
+        };
+
+        let viewport_extent = match root.axis {
+            ScrollAxis::Vertical => root.viewport_size.y,
+            ScrollAxis::Horizontal => root.viewport_size.x,
+        };
+        state.offset_px =
+            scroll_offset_for_item(item_start, item_extent, viewport_extent, state.offset_px);
+        clamp_scroll_state(&mut state);
+        if let Some(mut lock) = lock {
+            lock.manual_override = false;
+        }
+    }
+}
+
+/// Whether a root can still move in the direction of `delta_px`.
+pub fn can_consume_scroll(offset_px: f32, max_offset: f32, delta_px: f32) -> bool {
+    if delta_px > 0.0 {
+        offset_px < max_offset - SCROLL_EPSILON
+    } else if delta_px < 0.0 {
+        offset_px > SCROLL_EPSILON
+    } else {
+        false
+    }
+}
+
+/// Index of the first `(offset_px, max_offset)` candidate — ordered
+/// top-most first — that can absorb the wheel delta. Clamped inner
+/// regions pass the scroll outward; when every layer is clamped the
+/// top-most keeps the event.
+pub fn chained_scroll_index(candidates: &[(f32, f32)], delta_px: f32) -> usize {
+    candidates
+        .iter()
+        .position(|(offset, max)| can_consume_scroll(*offset, *max, delta_px))
+        .unwrap_or(0)
+}
+
+fn cursor_over_root(cursor: Vec2, translation: Vec2, viewport: Vec2) -> bool {
+    let half = viewport * 0.5;
+    (cursor.x - translation.x).abs() <= half.x && (cursor.y - translation.y).abs() <= half.y
+}
+
+/// Routes wheel and keyboard scrolling to the top-most scroll root under
+/// the cursor. Pixel-unit wheel deltas apply instantly; line-unit wheel,
+/// arrow keys and PageUp/PageDown go through the eased animation when
+/// it is enabled.
+pub fn handle_scrollable_pointer_and_keyboard_input(
+    mut commands: Commands,
+    mut wheel: EventReader<MouseWheel>,
+    keys: Res<ButtonInput<KeyCode>>,
+    cursor: Res<CustomCursor>,
+    config: Res<ScrollAnimationConfig>,
+    momentum_config: Res<ScrollMomentumConfig>,
+    animations: Query<&ScrollAnimation>,
+    mut momenta: Query<&mut ScrollMomentum>,
+    mut roots: Query<(
+        Entity,
+        &ScrollableRoot,
+        &mut ScrollState,
+        &GlobalTransform,
+        Option<&ScrollStepConfig>,
+        Option<&mut ScrollFocusFollowLock>,
+    )>,
+) {
+    // Raw input counts; step sizes depend on the root hit, so scaling
+    // waits until the target is known.
+    let mut instant_px = 0.0;
+    let mut line_notches = 0.0;
+    for event in wheel.read() {
+        match event.unit {
+            MouseScrollUnit::Line => line_notches -= event.y,
+            MouseScrollUnit::Pixel => instant_px -= event.y,
+        }
+    }
+    let mut key_steps = 0.0;
+    if keys.just_pressed(KeyCode::ArrowDown) {
+        key_steps += 1.0;
+    }
+    if keys.just_pressed(KeyCode::ArrowUp) {
+        key_steps -= 1.0;
+    }
+    let mut page_steps = 0.0;
+    if keys.just_pressed(KeyCode::PageDown) {
+        page_steps += 1.0;
+    }
+    if keys.just_pressed(KeyCode::PageUp) {
+        page_steps -= 1.0;
+    }
+
+    if instant_px == 0.0 && line_notches == 0.0 && key_steps == 0.0 && page_steps == 0.0 {
+        return;
+    }
+
+    // All roots under the cursor, top-most (highest global z) first.
+    let mut hits: Vec<(Entity, f32)> = Vec::new();
+    for (entity, root, _, transform, _, _) in &roots {
+        let translation = transform.translation();
+        if cursor_over_root(cursor.position, translation.truncate(), root.viewport_size) {
+            hits.push((entity, translation.z));
+        }
+    }
+    hits.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+    let Some(&(top, _)) = hits.first() else {
+        return;
+    };
+    // Wheel input chains past nested regions already clamped in its
+    // direction, so an inner list at its limit bubbles the scroll to
+    // the window behind it; keyboard steps stay with the top-most.
+    let wheel_direction = instant_px + line_notches;
+    let entity = if wheel_direction != 0.0 && hits.len() > 1 {
+        let candidates: Vec<(f32, f32)> = hits
+            .iter()
+            .filter_map(|(entity, _)| roots.get(*entity).ok())
+            .map(|(_, _, state, _, _, _)| (state.offset_px, state.max_offset))
+            .collect();
+        hits[chained_scroll_index(&candidates, wheel_direction)].0
+    } else {
+        top
+    };
+    let Ok((_, root, mut state, _, step, lock)) = roots.get_mut(entity) else {
+        return;
+    };
+    let viewport_extent = match root.axis {
+        ScrollAxis::Vertical => root.viewport_size.y,
+        ScrollAxis::Horizontal => root.viewport_size.x,
+    };
+    let step = step.copied().unwrap_or_default();
+    let mut wheel_eased_px = eased_scroll_delta(line_notches, 0.0, 0.0, viewport_extent, &step);
+    let key_eased_px =
+        eased_scroll_delta(0.0, key_steps, page_steps, viewport_extent, &step);
+    if momentum_config.enabled && wheel_eased_px != 0.0 {
+        // Inertia takes over line-wheel input: convert the notch delta
+        // into a velocity impulse instead of an eased jump.
+        let impulse = wheel_eased_px * SCROLL_MOMENTUM_IMPULSE;
+        if let Ok(mut momentum) = momenta.get_mut(entity) {
+            momentum.velocity_px_s += impulse;
+        } else {
+            commands.entity(entity).insert(ScrollMomentum {
+                velocity_px_s: impulse,
+            });
+        }
+        wheel_eased_px = 0.0;
+    }
+    let eased_px = wheel_eased_px + key_eased_px;
+
+    state.offset_px += instant_px;
+    clamp_scroll_state(&mut state);
+
+    if eased_px != 0.0 {
+        if config.enabled {
+            // Stack onto an in-flight target so rapid notches accumulate.
+            let base = animations
+                .get(entity)
+                .map(|animation| animation.target_px)
+                .unwrap_or(state.offset_px);
+            let target_px = (base + eased_px).clamp(0.0, state.max_offset.max(0.0));
+            commands.entity(entity).insert(ScrollAnimation::toward(
+                state.offset_px,
+                target_px,
+                config.duration_secs,
+            ));
+        } else {
+            state.offset_px += eased_px;
+            clamp_scroll_state(&mut state);
+        }
+    }
+    if let Some(mut lock) = lock {
+        lock.manual_override = true;
+    }
+}
+
+/// Sizes and positions scrollbar thumbs from their root's scroll state.
+pub fn sync_scrollbar_visuals(
+    roots: Query<(&ScrollableRoot, &ScrollState, Option<&Visibility>), Without<ScrollBar>>,
+    mut bars: Query<(&ScrollBar, &mut Sprite, &mut Transform, &mut Visibility)>,
+) {
+    for (bar, mut sprite, mut transform, mut visibility) in &mut bars {
+        let Ok((root, state, root_visibility)) = roots.get(bar.root) else {
+            continue;
+        };
+        // A hidden root (e.g. a collapsed window) hides its bar too.
+        let root_hidden = root_visibility == Some(&Visibility::Hidden);
+        if root_hidden || state.max_offset <= SCROLL_EPSILON {
+            *visibility = Visibility::Hidden;
+            continue;
+        }
+        *visibility = Visibility::Inherited;
+        let (track, cross) = match bar.axis {
+            ScrollAxis::Vertical => (root.viewport_size.y, root.viewport_size.x),
+            ScrollAxis::Horizontal => (root.viewport_size.x, root.viewport_size.y),
+        };
+        let thumb = (track * track / root.content_extent).clamp(12.0, track);
+        let progress = state.offset_px / state.max_offset;
+        let travel = track - thumb;
+        match bar.axis {
+            ScrollAxis::Vertical => {
+                sprite.custom_size = Some(Vec2::new(bar.width, thumb));
+                transform.translation.x = cross * 0.5 + bar.width * 0.5;
+                transform.translation.y = (track - thumb) * 0.5 - progress * travel;
+            }
+            ScrollAxis::Horizontal => {
+                sprite.custom_size = Some(Vec2::new(thumb, bar.width));
+                transform.translation.y = -cross * 0.5 - bar.width * 0.5;
+                transform.translation.x = -(track - thumb) * 0.5 + progress * travel;
+            }
+        }
+    }
+}
+
+#[derive(SystemSet, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ScrollSystem {
+    Input,
+    Extents,
+    Offsets,
+    Visuals,
+}
+
+pub struct ScrollPlugin;
+
+impl Plugin for ScrollPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<clip::ClipLayerAllocator>()
+            .init_resource::<ScrollAnimationConfig>()
+            .init_resource::<ScrollMomentumConfig>()
+            .add_event::<ScrollOffsetChanged>()
+            .configure_sets(
+            Update,
+            (
+                ScrollSystem::Input,
+                ScrollSystem::Extents,
+                ScrollSystem::Offsets,
+                ScrollSystem::Visuals,
+            )
+                .chain(),
+        )
+        .add_systems(
+            Update,
+            (
+                (
+                    handle_scrollable_pointer_and_keyboard_input,
+                    spawn_scroll_end_buttons,
+                    handle_scroll_end_buttons,
+                )
+                    .chain()
+                    .in_set(ScrollSystem::Input),
+                (
+                    sync_scroll_extents,
+                    handle_scroll_to_requests,
+                    animate_scroll_offsets,
+                    apply_scroll_momentum,
+                    resolve_scroll_snap_points,
+                    emit_scroll_offset_changes,
+                )
+                    .chain()
+                    .in_set(ScrollSystem::Extents),
+                sync_scroll_content_offsets.in_set(ScrollSystem::Offsets),
+                (
+                    animate_scrollbar_hover_expansion,
+                    sync_scrollbar_visuals,
+                    sync_scroll_end_buttons,
+                )
+                    .chain()
+                    .in_set(ScrollSystem::Visuals),
+                (
+                    clip::setup_scroll_clips,
+                    clip::propagate_clip_layers,
+                    clip::sync_scroll_clips,
+                )
+                    .chain()
+                    .in_set(ScrollSystem::Visuals),
+            ),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bar_width_eases_towards_its_target_without_overshoot() {
+        let widened = eased_bar_width(6.0, 14.0, 0.05);
+        assert!(widened > 6.0 && widened < 14.0);
+        // A long frame lands exactly on the target.
+        assert_eq!(eased_bar_width(6.0, 14.0, 1.0), 14.0);
+        assert_eq!(eased_bar_width(14.0, 6.0, 1.0), 6.0);
+    }
+
+    #[test]
+    fn clamped_inner_region_chains_wheel_scroll_to_its_parent() {
+        // Inner (top-most) region pinned at its limit, parent mid-scroll.
+        let candidates = [(200.0, 200.0), (50.0, 400.0)];
+        assert_eq!(chained_scroll_index(&candidates, 10.0), 1);
+        // Scrolling back up the inner can still move, so it keeps the event.
+        assert_eq!(chained_scroll_index(&candidates, -10.0), 0);
+        // Every layer clamped: the top-most keeps the event.
+        let pinned = [(200.0, 200.0), (400.0, 400.0)];
+        assert_eq!(chained_scroll_index(&pinned, 10.0), 0);
+    }
+
+    #[test]
+    fn snapping_picks_the_nearest_point_inside_the_threshold() {
+        let points = [0.0, 120.0, 240.0];
+        assert_eq!(nearest_snap_point(110.0, &points, 64.0), Some(120.0));
+        assert_eq!(nearest_snap_point(180.0, &points, 64.0), Some(240.0));
+        assert_eq!(nearest_snap_point(500.0, &points, 64.0), None);
+        assert_eq!(nearest_snap_point(60.0, &[], 64.0), None);
+    }
+
+    #[test]
+    fn momentum_decays_exponentially() {
+        let slowed = decayed_velocity(240.0, 4.0, 0.25);
+        assert!(slowed > 0.0 && slowed < 240.0);
+        // More friction stops sooner.
+        assert!(decayed_velocity(240.0, 8.0, 0.25) < slowed);
+        assert_eq!(decayed_velocity(0.0, 4.0, 0.25), 0.0);
+    }
+
+    #[test]
+    fn custom_line_step_scales_the_scroll_delta() {
+        let default = eased_scroll_delta(2.0, 0.0, 0.0, 100.0, &ScrollStepConfig::default());
+        assert_eq!(default, 2.0 * SCROLL_WHEEL_LINE_PX);
+        let fast = ScrollStepConfig {
+            line_px: 120.0,
+            ..ScrollStepConfig::default()
+        };
+        assert_eq!(eased_scroll_delta(2.0, 0.0, 0.0, 100.0, &fast), 240.0);
+        // Page steps scale with the viewport and the configured factor.
+        let half_pages = ScrollStepConfig {
+            page_factor: 0.5,
+            ..ScrollStepConfig::default()
+        };
+        assert_eq!(eased_scroll_delta(0.0, 0.0, 1.0, 100.0, &half_pages), 50.0);
+    }
+
+    #[test]
+    fn easing_curves_hit_both_endpoints() {
+        for easing in [
+            ScrollEasing::Linear,
+            ScrollEasing::EaseOutCubic,
+            ScrollEasing::EaseInOutQuad,
+        ] {
+            assert_eq!(easing.apply(0.0), 0.0);
+            assert_eq!(easing.apply(1.0), 1.0);
+            // Out-of-range inputs clamp rather than overshoot.
+            assert_eq!(easing.apply(1.5), 1.0);
+        }
+    }
+
+    #[test]
+    fn scroll_to_moves_only_offscreen_items_into_view() {
+        // Already visible: untouched.
+        assert_eq!(scroll_offset_for_item(20.0, 40.0, 100.0, 0.0), 0.0);
+        // Below the viewport: bottom-aligns.
+        assert_eq!(scroll_offset_for_item(150.0, 40.0, 100.0, 0.0), 90.0);
+        // Above the viewport: top-aligns.
+        assert_eq!(scroll_offset_for_item(20.0, 40.0, 100.0, 80.0), 20.0);
+    }
+
+    #[test]
+    fn clamp_keeps_offset_in_range() {
+        let mut state = ScrollState {
+            offset_px: -10.0,
+            max_offset: 100.0,
+        };
+        clamp_scroll_state(&mut state);
+        assert_eq!(state.offset_px, 0.0);
+        state.offset_px = 150.0;
+        clamp_scroll_state(&mut state);
+        assert_eq!(state.offset_px, 100.0);
+    }
+}
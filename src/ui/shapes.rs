@@ -0,0 +1,184 @@
+use bevy::prelude::*;
+
+/// A filled rectangle with a one-sprite border drawn behind the fill.
+/// Children are spawned on insert and resized whenever the component
+/// changes, so callers can mutate `dimensions` freely.
+#[derive(Component, Debug, Clone)]
+#[require(Transform, Visibility)]
+pub struct BorderedRectangle {
+    pub dimensions: Vec2,
+    pub border_thickness: f32,
+    pub border_color: Color,
+    pub fill_color: Color,
+}
+
+#[derive(Component)]
+struct BorderSprite;
+
+#[derive(Component)]
+struct FillSprite;
+
+/// An unfilled rectangle outline built from four bar sprites.
+#[derive(Component, Debug, Clone)]
+#[require(Transform, Visibility)]
+pub struct HollowRectangle {
+    pub dimensions: Vec2,
+    pub thickness: f32,
+    pub color: Color,
+}
+
+#[derive(Component, Clone, Copy)]
+enum HollowEdge {
+    Top,
+    Bottom,
+    Left,
+    Right,
+}
+
+/// A plus glyph built from two crossing bars. Rotating the entity 45
+/// degrees produces the close-button cross.
+#[derive(Component, Debug, Clone)]
+#[require(Transform, Visibility)]
+pub struct Plus {
+    pub size: f32,
+    pub thickness: f32,
+    pub color: Color,
+}
+
+#[derive(Component, Clone, Copy)]
+enum PlusBar {
+    Horizontal,
+    Vertical,
+}
+
+fn spawn_bordered_rectangles(
+    mut commands: Commands,
+    rectangles: Query<Entity, Added<BorderedRectangle>>,
+) {
+    for entity in &rectangles {
+        commands.entity(entity).with_children(|parent| {
+            parent.spawn((BorderSprite, Sprite::default(), Transform::from_xyz(0.0, 0.0, 0.0)));
+            parent.spawn((FillSprite, Sprite::default(), Transform::from_xyz(0.0, 0.0, 0.1)));
+        });
+    }
+}
+
+fn sync_bordered_rectangles(
+    rectangles: Query<(&BorderedRectangle, &Children), Changed<BorderedRectangle>>,
+    mut borders: Query<&mut Sprite, (With<BorderSprite>, Without<FillSprite>)>,
+    mut fills: Query<&mut Sprite, (With<FillSprite>, Without<BorderSprite>)>,
+) {
+    for (rectangle, children) in &rectangles {
+        for child in children.iter() {
+            if let Ok(mut sprite) = borders.get_mut(child) {
+                sprite.color = rectangle.border_color;
+                sprite.custom_size = Some(rectangle.dimensions);
+            }
+            if let Ok(mut sprite) = fills.get_mut(child) {
+                sprite.color = rectangle.fill_color;
+                sprite.custom_size = Some(
+                    (rectangle.dimensions - Vec2::splat(rectangle.border_thickness * 2.0))
+                        .max(Vec2::ZERO),
+                );
+            }
+        }
+    }
+}
+
+fn spawn_hollow_rectangles(
+    mut commands: Commands,
+    rectangles: Query<Entity, Added<HollowRectangle>>,
+) {
+    for entity in &rectangles {
+        commands.entity(entity).with_children(|parent| {
+            for edge in [
+                HollowEdge::Top,
+                HollowEdge::Bottom,
+                HollowEdge::Left,
+                HollowEdge::Right,
+            ] {
+                parent.spawn((edge, Sprite::default(), Transform::default()));
+            }
+        });
+    }
+}
+
+fn sync_hollow_rectangles(
+    rectangles: Query<(&HollowRectangle, &Children), Changed<HollowRectangle>>,
+    mut edges: Query<(&HollowEdge, &mut Sprite, &mut Transform)>,
+) {
+    for (rectangle, children) in &rectangles {
+        let half = rectangle.dimensions * 0.5;
+        for child in children.iter() {
+            let Ok((edge, mut sprite, mut transform)) = edges.get_mut(child) else {
+                continue;
+            };
+            sprite.color = rectangle.color;
+            let (size, translation) = match edge {
+                HollowEdge::Top => (
+                    Vec2::new(rectangle.dimensions.x, rectangle.thickness),
+                    Vec2::new(0.0, half.y),
+                ),
+                HollowEdge::Bottom => (
+                    Vec2::new(rectangle.dimensions.x, rectangle.thickness),
+                    Vec2::new(0.0, -half.y),
+                ),
+                HollowEdge::Left => (
+                    Vec2::new(rectangle.thickness, rectangle.dimensions.y),
+                    Vec2::new(-half.x, 0.0),
+                ),
+                HollowEdge::Right => (
+                    Vec2::new(rectangle.thickness, rectangle.dimensions.y),
+                    Vec2::new(half.x, 0.0),
+                ),
+            };
+            sprite.custom_size = Some(size);
+            transform.translation = translation.extend(0.0);
+        }
+    }
+}
+
+fn spawn_pluses(mut commands: Commands, pluses: Query<Entity, Added<Plus>>) {
+    for entity in &pluses {
+        commands.entity(entity).with_children(|parent| {
+            parent.spawn((PlusBar::Horizontal, Sprite::default(), Transform::default()));
+            parent.spawn((PlusBar::Vertical, Sprite::default(), Transform::default()));
+        });
+    }
+}
+
+fn sync_pluses(
+    pluses: Query<(&Plus, &Children), Changed<Plus>>,
+    mut bars: Query<(&PlusBar, &mut Sprite)>,
+) {
+    for (plus, children) in &pluses {
+        for child in children.iter() {
+            let Ok((bar, mut sprite)) = bars.get_mut(child) else {
+                continue;
+            };
+            sprite.color = plus.color;
+            sprite.custom_size = Some(match bar {
+                PlusBar::Horizontal => Vec2::new(plus.size, plus.thickness),
+                PlusBar::Vertical => Vec2::new(plus.thickness, plus.size),
+            });
+        }
+    }
+}
+
+pub struct ShapesPlugin;
+
+impl Plugin for ShapesPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                spawn_bordered_rectangles,
+                sync_bordered_rectangles,
+                spawn_hollow_rectangles,
+                sync_hollow_rectangles,
+                spawn_pluses,
+                sync_pluses,
+            ),
+        );
+    }
+}
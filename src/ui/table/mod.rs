@@ -0,0 +1,849 @@
+use bevy::{prelude::*, text::TextBounds};
+
+use crate::{
+    systems::{
+        colors::{DIM_COLOR, HIGHLIGHT_COLOR, PRIMARY_COLOR, WINDOW_BODY_COLOR},
+        interaction::{Clickable, CustomCursor, Draggable, DraggableRegion, UiInteractionState},
+    },
+    ui::{scroll::ContentSize, shapes::BorderedRectangle},
+};
+
+pub const TABLE_DEFAULT_ROW_HEIGHT: f32 = 22.0;
+pub const TABLE_DEFAULT_HEADER_HEIGHT: f32 = 24.0;
+/// Approximate glyph advance as a fraction of the font size, used to
+/// estimate how many characters fit one line of a wrapped column.
+pub const TABLE_CHAR_WIDTH_FACTOR: f32 = 0.6;
+/// Horizontal inset between a cell border and its text.
+pub const TABLE_CELL_TEXT_INSET: f32 = 4.0;
+
+const TABLE_BORDER_THICKNESS: f32 = 1.0;
+/// Visible width of a column divider; the grab band is wider.
+const TABLE_DIVIDER_THICKNESS: f32 = 2.0;
+const TABLE_DIVIDER_GRAB_WIDTH: f32 = 8.0;
+/// Line height of wrapped text as a multiple of the font size.
+const TABLE_WRAP_LINE_FACTOR: f32 = 1.3;
+/// Vertical padding added around a wrapped cell's lines.
+const TABLE_WRAP_PADDING: f32 = 6.0;
+
+/// Row-selection mode for a [`Table`]. Clicking a body row selects it;
+/// Up/Down move the selection while the cursor is over the table. The
+/// selection highlight owns the cells' fill channel, so selectable
+/// tables should not also drive `Cell::fill_color` themselves.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct SelectableTable {
+    pub selected: Option<usize>,
+}
+
+/// Fired when a row becomes selected, by click or keyboard.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct TableRowSelected {
+    pub table: Entity,
+    pub row: usize,
+}
+
+/// Click-to-sort configuration for a column.
+#[derive(Debug, Clone, Copy)]
+pub struct SortableColumn {
+    /// Compare cells as numbers instead of text.
+    pub numeric: bool,
+}
+
+/// Active sort on a table, exposed so headers can draw an indicator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TableSortState {
+    pub column: usize,
+    pub ascending: bool,
+}
+
+/// A table column: header label plus the width every cell in the column
+/// occupies.
+#[derive(Debug, Clone)]
+pub struct Column {
+    pub label: String,
+    pub width: f32,
+    /// Wrap cell text to the column width instead of overflowing; rows
+    /// grow to fit the wrapped lines via `measure_wrapped_rows`.
+    pub wrap: bool,
+    /// When set, clicking the header sorts the rows by this column.
+    pub sortable: Option<SortableColumn>,
+}
+
+impl Column {
+    pub fn new(label: impl Into<String>, width: f32) -> Self {
+        Self {
+            label: label.into(),
+            width,
+            wrap: false,
+            sortable: None,
+        }
+    }
+
+    pub fn wrapping(mut self) -> Self {
+        self.wrap = true;
+        self
+    }
+
+    pub fn sortable(mut self) -> Self {
+        self.sortable = Some(SortableColumn { numeric: false });
+        self
+    }
+
+    pub fn sortable_numeric(mut self) -> Self {
+        self.sortable = Some(SortableColumn { numeric: true });
+        self
+    }
+}
+
+/// Numeric value of a cell for sorting; unparsable cells sort last.
+fn numeric_sort_value(text: &str) -> f32 {
+    text.trim().parse::<f32>().unwrap_or(f32::MAX)
+}
+
+/// Greedy word wrap of `text` into lines of at most `max_chars`
+/// characters. Words longer than a whole line are split hard.
+pub fn wrap_text(text: &str, max_chars: usize) -> Vec<String> {
+    let max_chars = max_chars.max(1);
+    let mut lines = Vec::new();
+    let mut current = String::new();
+    for word in text.split_whitespace() {
+        let mut word = word;
+        while word.chars().count() > max_chars {
+            if !current.is_empty() {
+                lines.push(std::mem::take(&mut current));
+            }
+            let head: String = word.chars().take(max_chars).collect();
+            word = &word[head.len()..];
+            lines.push(head);
+        }
+        let needed = if current.is_empty() {
+            word.chars().count()
+        } else {
+            current.chars().count() + 1 + word.chars().count()
+        };
+        if needed > max_chars && !current.is_empty() {
+            lines.push(std::mem::take(&mut current));
+        }
+        if !current.is_empty() {
+            current.push(' ');
+        }
+        current.push_str(word);
+    }
+    if !current.is_empty() {
+        lines.push(current);
+    }
+    if lines.is_empty() {
+        lines.push(String::new());
+    }
+    lines
+}
+
+/// Characters that fit one line of a wrapped column of `width`.
+pub fn column_wrap_chars(width: f32, text_size: f32) -> usize {
+    let usable = width - TABLE_CELL_TEXT_INSET * 2.0;
+    ((usable / (text_size * TABLE_CHAR_WIDTH_FACTOR)).floor() as usize).max(1)
+}
+
+/// One body cell. `fill_color` overrides the default body fill, used for
+/// selection and status highlighting.
+#[derive(Debug, Clone)]
+pub struct Cell {
+    pub text: String,
+    pub text_color: Color,
+    pub fill_color: Option<Color>,
+}
+
+impl Cell {
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            text_color: PRIMARY_COLOR,
+            fill_color: None,
+        }
+    }
+
+    pub fn set_fill_color(&mut self, color: Option<Color>) {
+        self.fill_color = color;
+    }
+}
+
+/// A row of cells; `height` is uniform across the row.
+#[derive(Debug, Clone)]
+pub struct Row {
+    pub cells: Vec<Cell>,
+    pub height: f32,
+}
+
+impl Row {
+    pub fn new(cells: Vec<Cell>) -> Self {
+        Self {
+            cells,
+            height: TABLE_DEFAULT_ROW_HEIGHT,
+        }
+    }
+}
+
+/// A bordered data table, centred on its entity. Cell visuals are
+/// rebuilt whenever the component changes, so callers mutate columns,
+/// rows and cells directly. The entity carries a [`ContentSize`] so
+/// windows can measure it.
+#[derive(Component, Debug, Clone)]
+#[require(Transform, Visibility)]
+pub struct Table {
+    pub columns: Vec<Column>,
+    pub rows: Vec<Row>,
+    pub header_height: f32,
+    pub text_size: f32,
+    /// Active header sort; rows are kept in this order across refreshes.
+    pub sort: Option<TableSortState>,
+}
+
+impl Table {
+    pub fn new(columns: Vec<Column>, text_size: f32) -> Self {
+        Self {
+            columns,
+            rows: Vec::new(),
+            header_height: TABLE_DEFAULT_HEADER_HEIGHT,
+            text_size,
+            sort: None,
+        }
+    }
+
+    /// Ordering of two rows under the active sort.
+    fn row_ordering(&self, sort: TableSortState, a: &Row, b: &Row) -> std::cmp::Ordering {
+        let numeric = self
+            .columns
+            .get(sort.column)
+            .and_then(|column| column.sortable)
+            .is_some_and(|sortable| sortable.numeric);
+        let left = a.cells.get(sort.column).map(|c| c.text.as_str()).unwrap_or("");
+        let right = b.cells.get(sort.column).map(|c| c.text.as_str()).unwrap_or("");
+        let ordering = if numeric {
+            numeric_sort_value(left).total_cmp(&numeric_sort_value(right))
+        } else {
+            left.cmp(right)
+        };
+        if sort.ascending {
+            ordering
+        } else {
+            ordering.reverse()
+        }
+    }
+
+    /// Whether the rows are already in the active sort order.
+    pub fn is_sorted(&self) -> bool {
+        let Some(sort) = self.sort else {
+            return true;
+        };
+        self.rows
+            .windows(2)
+            .all(|pair| self.row_ordering(sort, &pair[0], &pair[1]) != std::cmp::Ordering::Greater)
+    }
+
+    /// Stable-sorts the rows by the active sort state, if any; ties keep
+    /// their existing relative order.
+    pub fn apply_sort(&mut self) {
+        let Some(sort) = self.sort else {
+            return;
+        };
+        let mut rows = std::mem::take(&mut self.rows);
+        rows.sort_by(|a, b| self.row_ordering(sort, a, b));
+        self.rows = rows;
+    }
+
+    pub fn total_size(&self) -> Vec2 {
+        let width: f32 = self.columns.iter().map(|column| column.width).sum();
+        let height: f32 =
+            self.header_height + self.rows.iter().map(|row| row.height).sum::<f32>();
+        Vec2::new(width, height)
+    }
+
+    /// Centre x of `column`, relative to the table entity.
+    pub fn column_centre_x(&self, column: usize) -> f32 {
+        let left: f32 = self.columns[..column].iter().map(|c| c.width).sum();
+        -self.total_size().x * 0.5 + left + self.columns[column].width * 0.5
+    }
+
+    /// Height `row` needs once wrapped columns are accounted for:
+    /// single-line rows keep the default, wrapped rows grow with their
+    /// largest line count.
+    pub fn measured_row_height(&self, row: &Row) -> f32 {
+        let mut lines = 1usize;
+        for (index, cell) in row.cells.iter().enumerate() {
+            let Some(column) = self.columns.get(index) else {
+                continue;
+            };
+            if !column.wrap {
+                continue;
+            }
+            let max_chars = column_wrap_chars(column.width, self.text_size);
+            lines = lines.max(wrap_text(&cell.text, max_chars).len());
+        }
+        if lines <= 1 {
+            TABLE_DEFAULT_ROW_HEIGHT
+        } else {
+            lines as f32 * self.text_size * TABLE_WRAP_LINE_FACTOR + TABLE_WRAP_PADDING
+        }
+    }
+
+    /// Centre y of `row` (`None` for the header), relative to the entity.
+    pub fn row_centre_y(&self, row: Option<usize>) -> f32 {
+        let top = self.total_size().y * 0.5;
+        match row {
+            None => top - self.header_height * 0.5,
+            Some(index) => {
+                let above: f32 = self.rows[..index].iter().map(|r| r.height).sum();
+                top - self.header_height - above - self.rows[index].height * 0.5
+            }
+        }
+    }
+}
+
+/// Opt-in: draggable dividers between columns that resize the column to
+/// the divider's left. Widths persist directly on the [`Table`]'s
+/// columns; cells re-lay out through the usual changed-table rebuild.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct ResizableColumns {
+    /// No column can be dragged narrower than this.
+    pub min_width: f32,
+}
+
+impl Default for ResizableColumns {
+    fn default() -> Self {
+        Self { min_width: 24.0 }
+    }
+}
+
+/// The divider after `column`, spawned for interior boundaries only.
+#[derive(Component, Debug, Clone, Copy)]
+struct TableColumnDivider {
+    table: Entity,
+    column: usize,
+}
+
+/// New width for the column left of a divider dragged to
+/// `cursor_local_x` (in table-local space), clamped to the minimum.
+pub fn dragged_column_width(
+    table: &Table,
+    column: usize,
+    cursor_local_x: f32,
+    min_width: f32,
+) -> f32 {
+    let left = -table.total_size().x * 0.5
+        + table.columns[..column].iter().map(|c| c.width).sum::<f32>();
+    (cursor_local_x - left).max(min_width)
+}
+
+/// Keeps one divider per interior column boundary on resizable tables,
+/// despawning extras when columns are removed.
+pub fn spawn_table_column_dividers(
+    mut commands: Commands,
+    tables: Query<(Entity, &Table), With<ResizableColumns>>,
+    dividers: Query<(Entity, &TableColumnDivider)>,
+) {
+    for (entity, table) in &tables {
+        let wanted = table.columns.len().saturating_sub(1);
+        for (divider_entity, divider) in &dividers {
+            if divider.table == entity && divider.column >= wanted {
+                commands.entity(divider_entity).despawn();
+            }
+        }
+        for column in 0..wanted {
+            if dividers
+                .iter()
+                .any(|(_, divider)| divider.table == entity && divider.column == column)
+            {
+                continue;
+            }
+            commands.spawn((
+                TableColumnDivider {
+                    table: entity,
+                    column,
+                },
+                Sprite {
+                    color: DIM_COLOR,
+                    custom_size: Some(Vec2::new(TABLE_DIVIDER_THICKNESS, 1.0)),
+                    ..default()
+                },
+                Draggable::default(),
+                DraggableRegion {
+                    dimensions: Vec2::new(TABLE_DIVIDER_GRAB_WIDTH, 1.0),
+                    offset: Vec2::ZERO,
+                },
+                Transform::from_xyz(0.0, 0.0, 0.4),
+                ChildOf(entity),
+            ));
+        }
+    }
+}
+
+/// Applies active divider drags to the column widths and re-seats every
+/// divider on its boundary. The generic drag system has already written
+/// a world position this frame; the width comes from the cursor against
+/// the table centre, and the divider is re-seated in local space before
+/// anything renders, as the menu sliders do.
+pub fn drag_table_column_dividers(
+    cursor: Res<CustomCursor>,
+    mut tables: Query<(&mut Table, &ResizableColumns, &GlobalTransform)>,
+    mut dividers: Query<(
+        &TableColumnDivider,
+        &Draggable,
+        &mut Transform,
+        &mut Sprite,
+        &mut DraggableRegion,
+    )>,
+) {
+    for (divider, draggable, mut transform, mut sprite, mut region) in &mut dividers {
+        let Ok((mut table, resizable, global)) = tables.get_mut(divider.table) else {
+            continue;
+        };
+        if divider.column >= table.columns.len() {
+            continue;
+        }
+        if draggable.dragging() {
+            let cursor_local = cursor.position.x - global.translation().x;
+            let width =
+                dragged_column_width(&table, divider.column, cursor_local, resizable.min_width);
+            if (table.columns[divider.column].width - width).abs() > f32::EPSILON {
+                table.columns[divider.column].width = width;
+            }
+        }
+        let total = table.total_size();
+        let boundary = -total.x * 0.5
+            + table.columns[..=divider.column]
+                .iter()
+                .map(|c| c.width)
+                .sum::<f32>();
+        transform.translation = Vec3::new(boundary, 0.0, 0.4);
+        sprite.custom_size = Some(Vec2::new(TABLE_DIVIDER_THICKNESS, total.y));
+        region.dimensions = Vec2::new(TABLE_DIVIDER_GRAB_WIDTH, total.y);
+    }
+}
+
+/// Marks a spawned cell visual. `row` is `None` for header cells.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct TableCellVisual {
+    pub table: Entity,
+    pub row: Option<usize>,
+    pub column: usize,
+}
+
+/// Routes body-row clicks into the selection, emitting
+/// [`TableRowSelected`] when it actually moves.
+pub fn handle_table_row_clicks(
+    cells: Query<(&TableCellVisual, &Clickable)>,
+    mut tables: Query<&mut SelectableTable>,
+    mut selected_events: EventWriter<TableRowSelected>,
+) {
+    for (visual, clickable) in &cells {
+        let Some(row) = visual.row else {
+            continue;
+        };
+        if !clickable.triggered {
+            continue;
+        }
+        let Ok(mut selectable) = tables.get_mut(visual.table) else {
+            continue;
+        };
+        if selectable.selected != Some(row) {
+            selectable.selected = Some(row);
+            selected_events.write(TableRowSelected {
+                table: visual.table,
+                row,
+            });
+        }
+    }
+}
+
+/// Moves the selection with Up/Down on the table under the cursor, the
+/// same hover-scoped targeting the scroll regions use. Suspended while
+/// a text field owns typing.
+pub fn handle_table_selection_keys(
+    keys: Res<ButtonInput<KeyCode>>,
+    cursor: Res<CustomCursor>,
+    state: Res<UiInteractionState>,
+    mut tables: Query<(Entity, &Table, &mut SelectableTable, &GlobalTransform)>,
+    mut selected_events: EventWriter<TableRowSelected>,
+) {
+    if state.text_input_focus.is_some() {
+        return;
+    }
+    let mut delta = 0i32;
+    if keys.just_pressed(KeyCode::ArrowDown) {
+        delta += 1;
+    }
+    if keys.just_pressed(KeyCode::ArrowUp) {
+        delta -= 1;
+    }
+    if delta == 0 {
+        return;
+    }
+    for (entity, table, mut selectable, transform) in &mut tables {
+        if table.rows.is_empty() {
+            continue;
+        }
+        let centre = transform.translation().truncate();
+        let half = table.total_size() * 0.5;
+        if (cursor.position.x - centre.x).abs() > half.x
+            || (cursor.position.y - centre.y).abs() > half.y
+        {
+            continue;
+        }
+        let last = table.rows.len() - 1;
+        let next = match selectable.selected {
+            None => 0,
+            Some(current) => (current as i32 + delta).clamp(0, last as i32) as usize,
+        };
+        if selectable.selected != Some(next) {
+            selectable.selected = Some(next);
+            selected_events.write(TableRowSelected { table: entity, row: next });
+        }
+    }
+}
+
+/// Keeps the selection valid when rows are refreshed underneath it.
+pub fn clamp_table_selection(
+    mut tables: Query<(&Table, &mut SelectableTable), Changed<Table>>,
+) {
+    for (table, mut selectable) in &mut tables {
+        let clamped = selectable
+            .selected
+            .filter(|_| !table.rows.is_empty())
+            .map(|row| row.min(table.rows.len() - 1));
+        if selectable.selected != clamped {
+            selectable.selected = clamped;
+        }
+    }
+}
+
+/// Paints the selected row's fill, clearing the rest; writes only when
+/// a fill actually changes so the pass settles.
+pub fn sync_table_selection_highlight(
+    mut tables: Query<
+        (&mut Table, &SelectableTable),
+        Or<(Changed<SelectableTable>, Changed<Table>)>,
+    >,
+) {
+    for (mut table, selectable) in &mut tables {
+        let highlight = Some(HIGHLIGHT_COLOR.with_alpha(0.2));
+        let stale = table.rows.iter().enumerate().any(|(index, row)| {
+            let wanted = if Some(index) == selectable.selected {
+                highlight
+            } else {
+                None
+            };
+            row.cells.iter().any(|cell| cell.fill_color != wanted)
+        });
+        if !stale {
+            continue;
+        }
+        let selected = selectable.selected;
+        for (index, row) in table.rows.iter_mut().enumerate() {
+            let wanted = if Some(index) == selected { highlight } else { None };
+            for cell in &mut row.cells {
+                cell.set_fill_color(wanted);
+            }
+        }
+    }
+}
+
+/// Sorts on header clicks: the first click on a sortable column sorts
+/// ascending, a second on the same column flips to descending.
+pub fn handle_table_header_clicks(
+    headers: Query<(&TableCellVisual, &Clickable)>,
+    mut tables: Query<&mut Table>,
+) {
+    for (visual, clickable) in &headers {
+        if visual.row.is_some() || !clickable.triggered {
+            continue;
+        }
+        let Ok(mut table) = tables.get_mut(visual.table) else {
+            continue;
+        };
+        if table
+            .columns
+            .get(visual.column)
+            .and_then(|column| column.sortable)
+            .is_none()
+        {
+            continue;
+        }
+        let ascending = match table.sort {
+            Some(sort) if sort.column == visual.column => !sort.ascending,
+            _ => true,
+        };
+        table.sort = Some(TableSortState {
+            column: visual.column,
+            ascending,
+        });
+        table.apply_sort();
+    }
+}
+
+/// Re-applies the active sort after callers refresh the row data, only
+/// touching the rows when their order is actually stale so the pass
+/// settles instead of re-triggering itself.
+pub fn apply_table_sorts(mut tables: Query<&mut Table, Changed<Table>>) {
+    for mut table in &mut tables {
+        if !table.is_sorted() {
+            table.apply_sort();
+        }
+    }
+}
+
+/// Grows wrapped rows to fit their line count ahead of the rebuild, so
+/// `ContentSize` — and window sizing through `WindowContentMetrics` —
+/// sees the final extent. Heights are only written when they actually
+/// change, so the pass settles rather than re-triggering itself.
+pub fn measure_wrapped_rows(mut tables: Query<&mut Table, Changed<Table>>) {
+    for mut table in &mut tables {
+        if !table.columns.iter().any(|column| column.wrap) {
+            continue;
+        }
+        let heights: Vec<f32> = table
+            .rows
+            .iter()
+            .map(|row| table.measured_row_height(row))
+            .collect();
+        if table
+            .rows
+            .iter()
+            .zip(&heights)
+            .all(|(row, height)| row.height == *height)
+        {
+            continue;
+        }
+        for (row, height) in table.rows.iter_mut().zip(heights) {
+            row.height = height;
+        }
+    }
+}
+
+/// Rebuilds the cell visuals of changed tables and refreshes the
+/// measured [`ContentSize`].
+pub fn sync_tables(
+    mut commands: Commands,
+    tables: Query<(Entity, &Table, Option<&Children>, Has<SelectableTable>), Changed<Table>>,
+    visuals: Query<(), With<TableCellVisual>>,
+) {
+    for (entity, table, children, selectable) in &tables {
+        if let Some(children) = children {
+            for child in children.iter() {
+                if visuals.get(child).is_ok() {
+                    commands.entity(child).despawn();
+                }
+            }
+        }
+        commands
+            .entity(entity)
+            .insert(ContentSize(table.total_size()));
+
+        for (column_index, column) in table.columns.iter().enumerate() {
+            let mut label = column.label.clone();
+            if let Some(sort) = table.sort {
+                if sort.column == column_index {
+                    label.push_str(if sort.ascending { " ^" } else { " v" });
+                }
+            }
+            spawn_cell(
+                &mut commands,
+                entity,
+                table,
+                None,
+                column_index,
+                Vec2::new(column.width, table.header_height),
+                &label,
+                PRIMARY_COLOR,
+                WINDOW_BODY_COLOR,
+                column.sortable.is_some(),
+            );
+        }
+        for (row_index, row) in table.rows.iter().enumerate() {
+            for (column_index, cell) in row.cells.iter().enumerate() {
+                let Some(column) = table.columns.get(column_index) else {
+                    continue;
+                };
+                spawn_cell(
+                    &mut commands,
+                    entity,
+                    table,
+                    Some(row_index),
+                    column_index,
+                    Vec2::new(column.width, row.height),
+                    &cell.text,
+                    cell.text_color,
+                    cell.fill_color.unwrap_or(WINDOW_BODY_COLOR),
+                    selectable,
+                );
+            }
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn spawn_cell(
+    commands: &mut Commands,
+    table_entity: Entity,
+    table: &Table,
+    row: Option<usize>,
+    column: usize,
+    dimensions: Vec2,
+    text: &str,
+    text_color: Color,
+    fill_color: Color,
+    clickable: bool,
+) {
+    let cell = commands
+        .spawn((
+            TableCellVisual {
+                table: table_entity,
+                row,
+                column,
+            },
+            BorderedRectangle {
+                dimensions,
+                border_thickness: TABLE_BORDER_THICKNESS,
+                border_color: DIM_COLOR,
+                fill_color,
+            },
+            Transform::from_xyz(
+                table.column_centre_x(column),
+                table.row_centre_y(row),
+                0.2,
+            ),
+            ChildOf(table_entity),
+        ))
+        .id();
+    // Sortable headers and selectable body rows take clicks for the
+    // header-sort and row-selection handlers.
+    if clickable {
+        commands.entity(cell).insert(Clickable::new(dimensions));
+    }
+    let mut cell_text = commands.spawn((
+        Text2d::new(text),
+        TextFont::from_font_size(table.text_size),
+        TextColor(text_color),
+        Transform::from_xyz(0.0, 0.0, 0.2),
+        ChildOf(cell),
+    ));
+    // Body cells of wrapping columns bound their text to the cell, so
+    // long content folds into the lines the measurement pass counted.
+    if row.is_some() && table.columns.get(column).is_some_and(|c| c.wrap) {
+        cell_text.insert(TextBounds::new(
+            dimensions.x - TABLE_CELL_TEXT_INSET * 2.0,
+            dimensions.y,
+        ));
+    }
+}
+
+pub struct TablePlugin;
+
+impl Plugin for TablePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<TableRowSelected>().add_systems(
+            Update,
+            (
+                handle_table_header_clicks,
+                handle_table_row_clicks,
+                handle_table_selection_keys,
+                spawn_table_column_dividers,
+                drag_table_column_dividers,
+                apply_table_sorts,
+                clamp_table_selection,
+                sync_table_selection_highlight,
+                measure_wrapped_rows,
+                sync_tables,
+            )
+                .chain(),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table() -> Table {
+        let mut table = Table::new(
+            vec![Column::new("A", 100.0), Column::new("B", 60.0)],
+            12.0,
+        );
+        table.rows.push(Row::new(vec![Cell::new("1"), Cell::new("2")]));
+        table
+    }
+
+    #[test]
+    fn sorting_compares_numerically_and_flips_direction() {
+        let mut table = Table::new(
+            vec![Column::new("NAME", 100.0), Column::new("KILLED", 60.0).sortable_numeric()],
+            12.0,
+        );
+        for (name, killed) in [("LOOP", "12"), ("LEVER", "1"), ("BRIDGE", "5")] {
+            table.rows.push(Row::new(vec![Cell::new(name), Cell::new(killed)]));
+        }
+        table.sort = Some(TableSortState {
+            column: 1,
+            ascending: true,
+        });
+        assert!(!table.is_sorted());
+        table.apply_sort();
+        let order: Vec<&str> = table.rows.iter().map(|r| r.cells[0].text.as_str()).collect();
+        assert_eq!(order, ["LEVER", "BRIDGE", "LOOP"]);
+        table.sort = Some(TableSortState {
+            column: 1,
+            ascending: false,
+        });
+        table.apply_sort();
+        let order: Vec<&str> = table.rows.iter().map(|r| r.cells[0].text.as_str()).collect();
+        assert_eq!(order, ["LOOP", "BRIDGE", "LEVER"]);
+        assert!(table.is_sorted());
+    }
+
+    #[test]
+    fn wrap_splits_on_words_and_hard_splits_long_ones() {
+        assert_eq!(
+            wrap_text("the trolley kills five", 11),
+            vec!["the trolley", "kills five"],
+        );
+        assert_eq!(wrap_text("unconscionable", 6), vec!["uncons", "cionab", "le"]);
+        assert_eq!(wrap_text("", 10), vec![String::new()]);
+    }
+
+    #[test]
+    fn wrapped_rows_grow_and_single_lines_keep_the_default() {
+        let mut table = table();
+        table.columns[1] = Column::new("B", 60.0).wrapping();
+        let short = Row::new(vec![Cell::new("1"), Cell::new("ok")]);
+        let long = Row::new(vec![
+            Cell::new("2"),
+            Cell::new("a consequence description that will not fit on one line"),
+        ]);
+        assert_eq!(table.measured_row_height(&short), TABLE_DEFAULT_ROW_HEIGHT);
+        assert!(table.measured_row_height(&long) > TABLE_DEFAULT_ROW_HEIGHT);
+    }
+
+    #[test]
+    fn divider_drags_resize_only_the_left_column_down_to_the_minimum() {
+        // Columns A(100) + B(60): the first boundary sits at x = 20.
+        let table = table();
+        assert_eq!(dragged_column_width(&table, 0, 20.0, 24.0), 100.0);
+        assert_eq!(dragged_column_width(&table, 0, -30.0, 24.0), 50.0);
+        assert_eq!(dragged_column_width(&table, 0, -200.0, 24.0), 24.0);
+    }
+
+    #[test]
+    fn total_size_sums_columns_and_rows() {
+        assert_eq!(
+            table().total_size(),
+            Vec2::new(160.0, TABLE_DEFAULT_HEADER_HEIGHT + TABLE_DEFAULT_ROW_HEIGHT),
+        );
+    }
+
+    #[test]
+    fn cell_centres_tile_the_table() {
+        let table = table();
+        assert_eq!(table.column_centre_x(0), -30.0);
+        assert_eq!(table.column_centre_x(1), 50.0);
+        // Header sits above row zero.
+        assert!(table.row_centre_y(None) > table.row_centre_y(Some(0)));
+    }
+}
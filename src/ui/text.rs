@@ -0,0 +1,541 @@
+use bevy::{
+    prelude::*,
+    text::TextLayoutInfo,
+    window::{PrimaryWindow, SystemCursorIcon},
+    winit::cursor::CursorIcon,
+};
+
+use crate::{
+    systems::{
+        audio::TransientAudioPallet,
+        colors::{HIGHLIGHT_COLOR, PRIMARY_COLOR},
+        interaction::CustomCursor,
+        time::Dilation,
+    },
+    ui::{menu::audio::AudioSettingsState, shapes::BorderedRectangle},
+};
+
+/// One styled segment of a [`TextContent`]: its own colour and,
+/// optionally, its own font face (how weight variants are supplied).
+#[derive(Debug, Clone)]
+pub struct TextRun {
+    pub text: String,
+    pub color: Color,
+    /// Overrides the block's font for this run, e.g. a bold face for a
+    /// speaker name. `None` inherits the default.
+    pub font: Option<Handle<Font>>,
+    /// Makes this run a clickable link; see [`TextLink`].
+    pub link: Option<String>,
+}
+
+impl TextRun {
+    pub fn new(text: impl Into<String>, color: Color) -> Self {
+        Self {
+            text: text.into(),
+            color,
+            font: None,
+            link: None,
+        }
+    }
+
+    pub fn with_font(mut self, font: Handle<Font>) -> Self {
+        self.font = Some(font);
+        self
+    }
+
+    pub fn with_link(mut self, target: impl Into<String>) -> Self {
+        self.link = Some(target.into());
+        self
+    }
+}
+
+/// A rich text block rendered as one laid-out `Text2d` with a span per
+/// run, so colored casualty numbers and speaker names flow inline with
+/// the surrounding text. Spans are rebuilt whenever the component
+/// changes; mutate `runs` freely.
+#[derive(Component, Debug, Clone)]
+#[require(Transform, Visibility)]
+pub struct TextContent {
+    pub runs: Vec<TextRun>,
+    pub font_size: f32,
+}
+
+impl TextContent {
+    /// A single-colour block — the original `TextContent` shape, kept
+    /// as a thin wrapper over one run.
+    pub fn new(text: impl Into<String>, font_size: f32) -> Self {
+        Self::styled(vec![TextRun::new(text, PRIMARY_COLOR)], font_size)
+    }
+
+    pub fn styled(runs: Vec<TextRun>, font_size: f32) -> Self {
+        Self { runs, font_size }
+    }
+
+    /// The unstyled concatenation, e.g. for measurements or logging.
+    pub fn plain_text(&self) -> String {
+        self.runs.iter().map(|run| run.text.as_str()).collect()
+    }
+}
+
+/// Rebuilds the span children of changed blocks. The root carries an
+/// empty `Text2d` so every glyph lives in a run and the whole block is
+/// laid out as one text.
+fn sync_text_content(
+    mut commands: Commands,
+    contents: Query<(Entity, &TextContent, Option<&Children>), Changed<TextContent>>,
+    spans: Query<(), With<TextSpan>>,
+) {
+    for (entity, content, children) in &contents {
+        commands.entity(entity).insert((
+            Text2d::new(String::new()),
+            TextFont::from_font_size(content.font_size),
+        ));
+        if let Some(children) = children {
+            for child in children.iter() {
+                if spans.get(child).is_ok() {
+                    commands.entity(child).despawn();
+                }
+            }
+        }
+        for run in &content.runs {
+            let mut font = TextFont::from_font_size(content.font_size);
+            if let Some(face) = &run.font {
+                font.font = face.clone();
+            }
+            let mut span = commands.spawn((
+                TextSpan::new(run.text.clone()),
+                font,
+                TextColor(run.color),
+                ChildOf(entity),
+            ));
+            if let Some(target) = &run.link {
+                span.insert(TextLink {
+                    target: target.clone(),
+                    base_color: run.color,
+                    hovered: false,
+                });
+            }
+        }
+    }
+}
+
+/// An inline link span inside a [`TextContent`]. Hover recolours the
+/// run and swaps the OS cursor to a pointer; clicking opens targets
+/// with a URL scheme in the browser and fires [`TextLinkActivated`]
+/// for everything else. Non-link runs are untouched.
+#[derive(Component, Debug, Clone)]
+pub struct TextLink {
+    pub target: String,
+    base_color: Color,
+    hovered: bool,
+}
+
+/// A schemeless link was clicked; listeners route `target` to in-game
+/// navigation.
+#[derive(Event, Debug, Clone)]
+pub struct TextLinkActivated {
+    pub target: String,
+}
+
+/// Whether a link target leaves the game for a browser.
+pub fn is_external_link(target: &str) -> bool {
+    target.starts_with("http://") || target.starts_with("https://")
+}
+
+/// Union of a span's glyph rects in text-local space (origin at the
+/// block's centre, y up), from layout-space glyph centres and sizes.
+pub fn union_glyph_rect(
+    glyphs: impl IntoIterator<Item = (Vec2, Vec2)>,
+    layout_size: Vec2,
+) -> Option<Rect> {
+    let mut bounds: Option<Rect> = None;
+    for (position, size) in glyphs {
+        let centre = Vec2::new(
+            position.x - layout_size.x * 0.5,
+            layout_size.y * 0.5 - position.y,
+        );
+        let glyph = Rect::from_center_size(centre, size);
+        bounds = Some(match bounds {
+            Some(bounds) => bounds.union(glyph),
+            None => glyph,
+        });
+    }
+    bounds
+}
+
+/// Hands a URL to the platform opener; failures are logged and dropped —
+/// a dead link should never take the game down.
+fn open_url_in_browser(url: &str) {
+    let (program, args): (&str, Vec<&str>) = if cfg!(target_os = "windows") {
+        ("cmd", vec!["/C", "start", url])
+    } else if cfg!(target_os = "macos") {
+        ("open", vec![url])
+    } else {
+        ("xdg-open", vec![url])
+    };
+    if let Err(error) = std::process::Command::new(program).args(args).spawn() {
+        warn!("failed to open {url}: {error}");
+    }
+}
+
+/// Hit-tests the cursor against each link span's laid-out glyph bounds,
+/// driving the hover colour, the pointer cursor and click-through.
+fn interact_text_links(
+    mut commands: Commands,
+    mut was_hovering: Local<bool>,
+    cursor: Res<CustomCursor>,
+    buttons: Res<ButtonInput<MouseButton>>,
+    mut activations: EventWriter<TextLinkActivated>,
+    roots: Query<(&GlobalTransform, &TextLayoutInfo, &Children)>,
+    mut links: Query<(&mut TextLink, &mut TextColor)>,
+    windows: Query<Entity, With<PrimaryWindow>>,
+) {
+    let mut any_hovered = false;
+    for (transform, layout, children) in &roots {
+        let origin = transform.translation().truncate();
+        for (child_index, child) in children.iter().enumerate() {
+            let Ok((mut link, mut color)) = links.get_mut(child) else {
+                continue;
+            };
+            // The root's own (empty) text is span zero; children follow
+            // in hierarchy order.
+            let span_index = child_index + 1;
+            let bounds = union_glyph_rect(
+                layout
+                    .glyphs
+                    .iter()
+                    .filter(|glyph| glyph.span_index == span_index)
+                    .map(|glyph| (glyph.position, glyph.size)),
+                layout.size,
+            );
+            let hovered = bounds.is_some_and(|bounds| bounds.contains(cursor.position - origin));
+            if hovered != link.hovered {
+                link.hovered = hovered;
+                color.0 = if hovered { HIGHLIGHT_COLOR } else { link.base_color };
+            }
+            any_hovered |= hovered;
+            if hovered && buttons.just_pressed(MouseButton::Left) {
+                if is_external_link(&link.target) {
+                    open_url_in_browser(&link.target);
+                } else {
+                    activations.write(TextLinkActivated {
+                        target: link.target.clone(),
+                    });
+                }
+            }
+        }
+    }
+    if any_hovered != *was_hovering {
+        *was_hovering = any_hovered;
+        if let Ok(window) = windows.single() {
+            let icon = if any_hovered {
+                SystemCursorIcon::Pointer
+            } else {
+                SystemCursorIcon::Default
+            };
+            commands.entity(window).insert(CursorIcon::System(icon));
+        }
+    }
+}
+
+/// Key that completes every in-flight reveal at once.
+pub const TYPEWRITER_SKIP_KEY: KeyCode = KeyCode::Space;
+
+/// Sound keys for a typewriter's optional per-character pallet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TypewriterSounds {
+    /// Played once per frame in which new characters appear.
+    Click,
+}
+
+/// Reveals a `Text2d` character by character, sim-time scaled so
+/// dialogue pauses with the game. The full text is captured when the
+/// component lands and restored as reveal progresses; attach a
+/// `TransientAudioPallet<TypewriterSounds>` for a terminal click.
+#[derive(Component, Debug, Clone)]
+pub struct Typewriter {
+    pub chars_per_sec: f32,
+    /// Characters currently shown.
+    pub revealed: usize,
+    /// Fractional progress carried between frames.
+    elapsed_chars: f32,
+    full_text: String,
+}
+
+impl Typewriter {
+    pub fn new(chars_per_sec: f32) -> Self {
+        Self {
+            chars_per_sec,
+            revealed: 0,
+            elapsed_chars: 0.0,
+            full_text: String::new(),
+        }
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.revealed >= self.full_text.chars().count()
+    }
+
+    /// Jumps straight to the fully revealed text.
+    pub fn skip_to_end(&mut self) {
+        self.revealed = self.full_text.chars().count();
+        self.elapsed_chars = self.revealed as f32;
+    }
+
+    /// The first `revealed` characters of the captured text.
+    fn visible_text(&self) -> String {
+        self.full_text.chars().take(self.revealed).collect()
+    }
+
+    /// Advances by `delta_secs`, returning how many new characters this
+    /// step revealed.
+    pub fn advance(&mut self, delta_secs: f32) -> usize {
+        let total = self.full_text.chars().count();
+        self.elapsed_chars =
+            (self.elapsed_chars + self.chars_per_sec * delta_secs).min(total as f32);
+        let target = (self.elapsed_chars.floor() as usize).min(total);
+        let fresh = target.saturating_sub(self.revealed);
+        self.revealed = target;
+        fresh
+    }
+}
+
+/// Captures the full text of newly attached typewriters and blanks the
+/// visible text so the reveal starts from nothing.
+fn start_typewriters(mut texts: Query<(&mut Typewriter, &mut Text2d), Added<Typewriter>>) {
+    for (mut typewriter, mut text) in &mut texts {
+        typewriter.full_text = std::mem::take(&mut text.0);
+    }
+}
+
+/// Drives reveals forward, skipping to completion on the skip key and
+/// clicking the pallet once per frame that shows new characters.
+fn advance_typewriters(
+    mut commands: Commands,
+    time: Res<Time>,
+    dilation: Res<Dilation>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mixer: Res<AudioSettingsState>,
+    mut texts: Query<(
+        &mut Typewriter,
+        &mut Text2d,
+        Option<&TransientAudioPallet<TypewriterSounds>>,
+    )>,
+) {
+    let skip = keys.just_pressed(TYPEWRITER_SKIP_KEY);
+    for (mut typewriter, mut text, pallet) in &mut texts {
+        if typewriter.is_complete() {
+            continue;
+        }
+        if skip {
+            typewriter.skip_to_end();
+            text.0 = typewriter.visible_text();
+            continue;
+        }
+        let fresh = typewriter.advance(dilation.scale(time.delta_secs()));
+        if fresh == 0 {
+            continue;
+        }
+        text.0 = typewriter.visible_text();
+        if let Some(pallet) = pallet {
+            pallet.play_transient_audio(&mut commands, &TypewriterSounds::Click, &mixer.settings);
+        }
+    }
+}
+
+/// Fades an entity and its visual children to transparent over
+/// `duration_secs`, despawning only once fully invisible — dialogue is
+/// never removed mid-fade. Inserting a fresh `FadeOut` restarts the
+/// fade; a new dialogue interrupting one can call [`FadeOut::expire`]
+/// on the old fade to clear it immediately.
+#[derive(Component, Debug, Clone)]
+pub struct FadeOut {
+    pub duration_secs: f32,
+    elapsed_secs: f32,
+}
+
+impl FadeOut {
+    pub fn new(duration_secs: f32) -> Self {
+        Self {
+            duration_secs: duration_secs.max(f32::EPSILON),
+            elapsed_secs: 0.0,
+        }
+    }
+
+    /// Current alpha multiplier: 1 at the start, 0 once elapsed.
+    pub fn alpha(&self) -> f32 {
+        (1.0 - self.elapsed_secs / self.duration_secs).clamp(0.0, 1.0)
+    }
+
+    pub fn finished(&self) -> bool {
+        self.elapsed_secs >= self.duration_secs
+    }
+
+    /// Jumps to the end of the fade, despawning on the next pass.
+    pub fn expire(&mut self) {
+        self.elapsed_secs = self.duration_secs;
+    }
+
+    /// Advances the fade, returning the new alpha.
+    pub fn tick(&mut self, delta_secs: f32) -> f32 {
+        self.elapsed_secs = (self.elapsed_secs + delta_secs).min(self.duration_secs);
+        self.alpha()
+    }
+}
+
+/// Alphas captured on the first fade pass, so each frame writes
+/// `base * alpha` instead of compounding multiplications.
+#[derive(Component, Debug, Clone, Copy)]
+struct FadeOutBase {
+    text: f32,
+    sprite: f32,
+    border: f32,
+    fill: f32,
+}
+
+/// Fades each marked subtree, then despawns the root once invisible.
+fn apply_fade_outs(
+    mut commands: Commands,
+    time: Res<Time>,
+    dilation: Res<Dilation>,
+    mut fades: Query<(Entity, &mut FadeOut)>,
+    children: Query<&Children>,
+    bases: Query<&FadeOutBase>,
+    mut texts: Query<&mut TextColor>,
+    mut sprites: Query<&mut Sprite>,
+    mut rectangles: Query<&mut BorderedRectangle>,
+) {
+    let delta = dilation.scale(time.delta_secs());
+    for (root, mut fade) in &mut fades {
+        let alpha = fade.tick(delta);
+        let mut stack = vec![root];
+        while let Some(entity) = stack.pop() {
+            let base = bases.get(entity).copied().unwrap_or_else(|| {
+                let captured = FadeOutBase {
+                    text: texts.get(entity).map_or(1.0, |c| c.0.alpha()),
+                    sprite: sprites.get(entity).map_or(1.0, |s| s.color.alpha()),
+                    border: rectangles
+                        .get(entity)
+                        .map_or(1.0, |r| r.border_color.alpha()),
+                    fill: rectangles.get(entity).map_or(1.0, |r| r.fill_color.alpha()),
+                };
+                commands.entity(entity).insert(captured);
+                captured
+            });
+            if let Ok(mut color) = texts.get_mut(entity) {
+                color.0.set_alpha(base.text * alpha);
+            }
+            if let Ok(mut sprite) = sprites.get_mut(entity) {
+                sprite.color.set_alpha(base.sprite * alpha);
+            }
+            if let Ok(mut rectangle) = rectangles.get_mut(entity) {
+                rectangle.border_color.set_alpha(base.border * alpha);
+                rectangle.fill_color.set_alpha(base.fill * alpha);
+            }
+            if let Ok(kids) = children.get(entity) {
+                stack.extend(kids.iter());
+            }
+        }
+        if fade.finished() {
+            commands.entity(root).despawn();
+        }
+    }
+}
+
+pub struct RichTextPlugin;
+
+impl Plugin for RichTextPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<TextLinkActivated>()
+            .add_systems(Update, (start_typewriters, advance_typewriters).chain())
+            .add_systems(
+                Update,
+                (sync_text_content, interact_text_links, apply_fade_outs),
+            );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn glyph_bounds_union_and_recentre_around_the_block() {
+        // Two 10x10 glyphs on one line of a 100x20 layout.
+        let bounds = union_glyph_rect(
+            [
+                (Vec2::new(10.0, 10.0), Vec2::splat(10.0)),
+                (Vec2::new(20.0, 10.0), Vec2::splat(10.0)),
+            ],
+            Vec2::new(100.0, 20.0),
+        )
+        .unwrap();
+        // Layout x 10..20 maps to local -45..-25; layout y 10 is the
+        // vertical centre, so the rect straddles zero.
+        assert_eq!(bounds.min, Vec2::new(-45.0, -5.0));
+        assert_eq!(bounds.max, Vec2::new(-25.0, 5.0));
+        assert!(union_glyph_rect([], Vec2::splat(100.0)).is_none());
+    }
+
+    #[test]
+    fn only_scheme_targets_leave_the_game() {
+        assert!(is_external_link("https://example.com"));
+        assert!(is_external_link("http://example.com"));
+        assert!(!is_external_link("menu/options"));
+    }
+
+    #[test]
+    fn fade_reaches_zero_alpha_before_it_finishes() {
+        let mut fade = FadeOut::new(0.5);
+        assert_eq!(fade.alpha(), 1.0);
+        let mid = fade.tick(0.25);
+        assert!(mid > 0.0 && mid < 1.0);
+        // The finishing tick lands at zero alpha, so the despawn that
+        // follows never removes a still-visible dialogue.
+        let last = fade.tick(0.25);
+        assert!(last.abs() < 1e-6);
+        assert!(fade.finished());
+    }
+
+    #[test]
+    fn expiring_a_fade_clears_it_immediately() {
+        let mut fade = FadeOut::new(2.0);
+        fade.expire();
+        assert!(fade.finished());
+        assert_eq!(fade.alpha(), 0.0);
+    }
+
+    #[test]
+    fn typewriter_reveals_at_the_configured_rate_and_skips() {
+        let mut typewriter = Typewriter::new(10.0);
+        typewriter.full_text = "HELLO WORLD".to_string();
+        assert_eq!(typewriter.advance(0.5), 5);
+        assert_eq!(typewriter.visible_text(), "HELLO");
+        // A tiny step may reveal nothing; progress still accumulates.
+        assert_eq!(typewriter.advance(0.05), 0);
+        assert_eq!(typewriter.advance(0.05), 1);
+        typewriter.skip_to_end();
+        assert!(typewriter.is_complete());
+        assert_eq!(typewriter.visible_text(), "HELLO WORLD");
+        assert_eq!(typewriter.advance(1.0), 0);
+    }
+
+    #[test]
+    fn plain_text_concatenates_the_runs() {
+        let content = TextContent::styled(
+            vec![
+                TextRun::new("INSPECTOR: ", PRIMARY_COLOR),
+                TextRun::new("five will die", PRIMARY_COLOR),
+            ],
+            12.0,
+        );
+        assert_eq!(content.plain_text(), "INSPECTOR: five will die");
+    }
+
+    #[test]
+    fn the_single_color_constructor_is_one_run() {
+        let content = TextContent::new("HELLO", 12.0);
+        assert_eq!(content.runs.len(), 1);
+        assert_eq!(content.plain_text(), "HELLO");
+    }
+}
@@ -0,0 +1,299 @@
+use bevy::prelude::*;
+
+use crate::{
+    systems::{
+        colors::{PRIMARY_COLOR, WINDOW_BODY_COLOR},
+        interaction::{Clickable, CustomCursor, Draggable, DraggableRegion, Hoverable},
+    },
+    ui::{
+        shapes::{BorderedRectangle, Plus},
+        window::{clamp_to_viewport, viewport_world_bounds, OffscreenCamera},
+    },
+};
+
+pub const TOOLTIP_FONT_SIZE: f32 = 12.0;
+/// Default hover dwell before a tooltip appears.
+pub const TOOLTIP_DELAY_SECS: f32 = 0.6;
+pub const TOOLTIP_PADDING: f32 = 8.0;
+pub const TOOLTIP_Z: f32 = 600.0;
+/// Hotkey that pins the tooltip under the cursor (and unpins again).
+pub const TOOLTIP_PIN_KEY: KeyCode = KeyCode::KeyP;
+
+const TOOLTIP_CLOSE_SIZE: f32 = 8.0;
+
+/// Hover tooltip on an interactive entity: once its [`Hoverable`] has
+/// been hovered for `delay` seconds, a panel opens near the cursor and
+/// closes again on unhover. Input-policy gating comes for free because
+/// a scoped `Hoverable` never reports hover while unfocused.
+#[derive(Component, Debug, Clone)]
+pub struct Tooltip {
+    pub text: String,
+    pub delay: f32,
+}
+
+impl Tooltip {
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            delay: TOOLTIP_DELAY_SECS,
+        }
+    }
+}
+
+/// A floating tooltip panel. Unpinned panels follow hover lifetime and
+/// despawn when their source loses interest; pinned panels stay until
+/// explicitly dismissed and can be dragged out of the way.
+#[derive(Component, Debug)]
+pub struct TooltipPanel {
+    pub pinned: bool,
+    pub size: Vec2,
+}
+
+#[derive(Component)]
+struct TooltipCloseButton {
+    panel: Entity,
+}
+
+/// Tracks the live tooltip entities. At most one tooltip may be pinned;
+/// while one is, hover must not spawn fresh tooltips.
+#[derive(Resource, Debug, Default)]
+pub struct TooltipState {
+    pub active: Option<Entity>,
+    pub pinned: Option<Entity>,
+    /// The hovered entity that opened the active tooltip, if any.
+    pub source: Option<Entity>,
+}
+
+impl TooltipState {
+    /// Whether hover is currently allowed to open a new tooltip.
+    pub fn hover_allowed(&self) -> bool {
+        self.pinned.is_none()
+    }
+}
+
+/// Rough laid-out size for a tooltip body; good enough for clamping and
+/// hit regions without waiting a frame for text layout.
+pub fn estimate_tooltip_size(text: &str) -> Vec2 {
+    let longest = text.lines().map(str::len).max().unwrap_or(0) as f32;
+    let lines = text.lines().count().max(1) as f32;
+    Vec2::new(
+        longest * TOOLTIP_FONT_SIZE * 0.55 + TOOLTIP_PADDING * 2.0,
+        lines * TOOLTIP_FONT_SIZE * 1.3 + TOOLTIP_PADDING * 2.0,
+    )
+}
+
+/// Spawns a tooltip panel near `position`, clamped into the viewport so
+/// edge tooltips stay readable. Returns the panel entity.
+pub fn spawn_tooltip(
+    commands: &mut Commands,
+    state: &mut TooltipState,
+    text: &str,
+    position: Vec2,
+    bounds: Option<Rect>,
+) -> Entity {
+    let size = estimate_tooltip_size(text);
+    let mut translation = (position + size * Vec2::new(0.5, -0.5)).extend(TOOLTIP_Z);
+    if let Some(bounds) = bounds {
+        clamp_to_viewport(&mut translation, size, bounds);
+    }
+    let panel = commands
+        .spawn((
+            TooltipPanel {
+                pinned: false,
+                size,
+            },
+            BorderedRectangle {
+                dimensions: size,
+                border_thickness: 1.0,
+                border_color: PRIMARY_COLOR,
+                fill_color: WINDOW_BODY_COLOR,
+            },
+            Transform::from_translation(translation),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text2d::new(text.to_string()),
+                TextFont::from_font_size(TOOLTIP_FONT_SIZE),
+                TextColor(PRIMARY_COLOR),
+                Transform::from_xyz(0.0, 0.0, 0.5),
+            ));
+        })
+        .id();
+    state.active = Some(panel);
+    panel
+}
+
+/// Pins the active tooltip: it gains a close glyph, becomes draggable,
+/// and blocks further hover tooltips until dismissed.
+fn pin_tooltip(commands: &mut Commands, state: &mut TooltipState, panel: Entity, size: Vec2) {
+    state.pinned = Some(panel);
+    commands.entity(panel).insert((
+        Draggable::default(),
+        DraggableRegion {
+            dimensions: size,
+            offset: Vec2::ZERO,
+        },
+    ));
+    commands.entity(panel).with_children(|parent| {
+        parent.spawn((
+            TooltipCloseButton { panel },
+            Plus {
+                size: TOOLTIP_CLOSE_SIZE,
+                thickness: 1.5,
+                color: PRIMARY_COLOR,
+            },
+            Transform {
+                translation: Vec3::new(
+                    size.x * 0.5 - TOOLTIP_CLOSE_SIZE,
+                    size.y * 0.5 - TOOLTIP_CLOSE_SIZE,
+                    1.0,
+                ),
+                rotation: Quat::from_rotation_z(std::f32::consts::FRAC_PI_4),
+                ..default()
+            },
+            Clickable::new(Vec2::splat(TOOLTIP_CLOSE_SIZE + 6.0)),
+        ));
+    });
+}
+
+/// Opens a tooltip once a [`Tooltip`] source has been hovered past its
+/// delay, and closes the (unpinned) panel when the hover ends.
+fn update_hover_tooltips(
+    mut commands: Commands,
+    cursor: Res<CustomCursor>,
+    mut state: ResMut<TooltipState>,
+    cameras: Query<(&Camera, &GlobalTransform), With<OffscreenCamera>>,
+    sources: Query<(Entity, &Tooltip, &Hoverable)>,
+    panels: Query<&TooltipPanel>,
+) {
+    if !state.hover_allowed() {
+        return;
+    }
+    if let (Some(active), Some(source)) = (state.active, state.source) {
+        let still_hovered = sources
+            .get(source)
+            .is_ok_and(|(_, _, hoverable)| hoverable.hovered);
+        let unpinned = panels.get(active).is_ok_and(|panel| !panel.pinned);
+        if !still_hovered && unpinned {
+            commands.entity(active).despawn();
+            state.active = None;
+            state.source = None;
+        }
+    }
+    if state.active.is_some() {
+        return;
+    }
+    let bounds = cameras
+        .iter()
+        .next()
+        .and_then(|(camera, transform)| viewport_world_bounds(camera, transform));
+    for (entity, tooltip, hoverable) in &sources {
+        if hoverable.hovered && hoverable.hovered_secs >= tooltip.delay {
+            spawn_tooltip(&mut commands, &mut state, &tooltip.text, cursor.position, bounds);
+            state.source = Some(entity);
+            break;
+        }
+    }
+}
+
+/// The pin hotkey toggles: with an unpinned tooltip showing it pins it;
+/// with a pinned tooltip it dismisses it.
+fn handle_tooltip_pin_hotkey(
+    mut commands: Commands,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut state: ResMut<TooltipState>,
+    mut panels: Query<&mut TooltipPanel>,
+) {
+    if !keys.just_pressed(TOOLTIP_PIN_KEY) {
+        return;
+    }
+    if let Some(pinned) = state.pinned.take() {
+        commands.entity(pinned).despawn();
+        if state.active == Some(pinned) {
+            state.active = None;
+            state.source = None;
+        }
+        return;
+    }
+    let Some(active) = state.active else {
+        return;
+    };
+    let Ok(mut panel) = panels.get_mut(active) else {
+        return;
+    };
+    if !panel.pinned {
+        panel.pinned = true;
+        let size = panel.size;
+        pin_tooltip(&mut commands, &mut state, active, size);
+    }
+}
+
+fn handle_tooltip_close_clicks(
+    mut commands: Commands,
+    mut state: ResMut<TooltipState>,
+    buttons: Query<(&TooltipCloseButton, &Clickable)>,
+) {
+    for (button, clickable) in &buttons {
+        if clickable.triggered {
+            commands.entity(button.panel).despawn();
+            if state.pinned == Some(button.panel) {
+                state.pinned = None;
+            }
+            if state.active == Some(button.panel) {
+                state.active = None;
+                state.source = None;
+            }
+        }
+    }
+}
+
+/// Keeps pinned tooltips inside the viewport while being dragged.
+fn clamp_pinned_tooltips(
+    cameras: Query<(&Camera, &GlobalTransform), With<OffscreenCamera>>,
+    mut panels: Query<(&TooltipPanel, &mut Transform)>,
+) {
+    let Some(bounds) = cameras
+        .iter()
+        .next()
+        .and_then(|(camera, transform)| viewport_world_bounds(camera, transform))
+    else {
+        return;
+    };
+    for (panel, mut transform) in &mut panels {
+        if panel.pinned {
+            clamp_to_viewport(&mut transform.translation, panel.size, bounds);
+        }
+    }
+}
+
+fn clear_dead_tooltips(mut state: ResMut<TooltipState>, panels: Query<(), With<TooltipPanel>>) {
+    if let Some(active) = state.active {
+        if panels.get(active).is_err() {
+            state.active = None;
+            state.source = None;
+        }
+    }
+    if let Some(pinned) = state.pinned {
+        if panels.get(pinned).is_err() {
+            state.pinned = None;
+        }
+    }
+}
+
+pub struct TooltipPlugin;
+
+impl Plugin for TooltipPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TooltipState>().add_systems(
+            Update,
+            (
+                clear_dead_tooltips,
+                update_hover_tooltips,
+                handle_tooltip_pin_hotkey,
+                handle_tooltip_close_clicks,
+                clamp_pinned_tooltips,
+            )
+                .chain(),
+        );
+    }
+}
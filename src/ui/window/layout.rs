@@ -0,0 +1,93 @@
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    data::save::{read_ron, write_ron},
+    ui::window::{Window, WindowId},
+};
+
+const LAYOUT_FILE: &str = "window_layout.ron";
+/// Minimum quiet period between disk writes.
+const LAYOUT_FLUSH_SECS: f32 = 1.0;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct StoredWindowLayout {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+/// Saved placement for every window carrying a [`WindowId`], keyed by
+/// that id. Loaded once at startup and flushed (debounced) whenever a
+/// tracked window moves or resizes.
+#[derive(Resource, Debug, Default)]
+pub struct WindowLayoutStore {
+    entries: HashMap<String, StoredWindowLayout>,
+    dirty: bool,
+    since_flush: f32,
+}
+
+impl WindowLayoutStore {
+    pub fn get(&self, id: &str) -> Option<StoredWindowLayout> {
+        self.entries.get(id).copied()
+    }
+
+    pub fn record(&mut self, id: &str, layout: StoredWindowLayout) {
+        let changed = self
+            .entries
+            .get(id)
+            .is_none_or(|existing| {
+                (existing.x, existing.y, existing.width, existing.height)
+                    != (layout.x, layout.y, layout.width, layout.height)
+            });
+        if changed {
+            self.entries.insert(id.to_string(), layout);
+            self.dirty = true;
+        }
+    }
+}
+
+pub fn load_window_layout_store(mut commands: Commands) {
+    let entries: HashMap<String, StoredWindowLayout> =
+        read_ron(LAYOUT_FILE).unwrap_or_default();
+    commands.insert_resource(WindowLayoutStore {
+        entries,
+        dirty: false,
+        since_flush: 0.0,
+    });
+}
+
+/// Records placements of tracked windows as they move or resize.
+pub fn record_window_layouts(
+    mut store: ResMut<WindowLayoutStore>,
+    roots: Query<
+        (&Window, &WindowId, &Transform),
+        Or<(Changed<Transform>, Changed<Window>)>,
+    >,
+) {
+    for (window, id, transform) in &roots {
+        store.record(
+            id.0,
+            StoredWindowLayout {
+                x: transform.translation.x,
+                y: transform.translation.y,
+                width: window.boundary.dimensions.x,
+                height: window.boundary.dimensions.y,
+            },
+        );
+    }
+}
+
+/// Flushes dirty layouts to disk, debounced so dragging does not write
+/// every frame.
+pub fn flush_window_layouts(time: Res<Time<Real>>, mut store: ResMut<WindowLayoutStore>) {
+    store.since_flush += time.delta_secs();
+    if store.dirty && store.since_flush >= LAYOUT_FLUSH_SECS {
+        write_ron(LAYOUT_FILE, &store.entries);
+        store.dirty = false;
+        store.since_flush = 0.0;
+    }
+}
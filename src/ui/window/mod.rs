@@ -0,0 +1,2639 @@
+use bevy::{
+    ecs::component::HookContext,
+    ecs::world::DeferredWorld,
+    input::keyboard::{Key, KeyboardInput},
+    prelude::*,
+};
+
+use crate::{
+    systems::{
+        colors::{DIM_COLOR, HIGHLIGHT_COLOR, PRIMARY_COLOR, WINDOW_BODY_COLOR},
+        interaction::{
+            run_input_actions, Clickable, CustomCursor, Draggable, DraggableRegion, InputAction,
+            InteractionSystem, RepeatTimer, UiInteractionState,
+        },
+    },
+    ui::{
+        scroll::{clamp_scroll_state, ContentSize, ScrollAxis, ScrollableRoot, ScrollState},
+        shapes::{BorderedRectangle, Plus},
+    },
+};
+
+/// Top of the depth band reserved for scene sprites (trains, tracks,
+/// selectors, ...). Scene plugins keep their roots at or below this;
+/// window roots always stack strictly above it, so scene content can
+/// never z-fight a window.
+pub const SCENE_SPRITE_Z_MAX: f32 = 90.0;
+/// Depth gap between successive window roots in the stack.
+pub const WINDOW_Z_STEP: f32 = 10.0;
+/// Extra depth lifted onto the focused window so its children never
+/// interleave with an unfocused neighbour. Must stay below
+/// [`WINDOW_Z_STEP`] or a raised window would land on the next order's
+/// baseline z.
+pub const WINDOW_FOCUS_DEPTH_SPAN: f32 = 5.0;
+
+const _: () = assert!(WINDOW_FOCUS_DEPTH_SPAN < WINDOW_Z_STEP);
+/// Square hit size of each corner resize handle.
+pub const WINDOW_RESIZE_HANDLE_SIZE: f32 = 12.0;
+/// Pixels a window moves per keyboard nudge.
+pub const WINDOW_KEYBOARD_MOVE_STEP: f32 = 16.0;
+/// Pixels a window grows/shrinks per keyboard resize step.
+pub const WINDOW_KEYBOARD_RESIZE_STEP: f32 = 16.0;
+
+const WINDOW_BORDER_THICKNESS: f32 = 1.5;
+const WINDOW_TITLE_FONT_SIZE: f32 = 14.0;
+const WINDOW_CLOSE_BUTTON_SIZE: f32 = 10.0;
+
+pub mod layout;
+pub mod tabs;
+
+/// Marks the camera whose viewport defines world-space window bounds.
+#[derive(Component)]
+pub struct OffscreenCamera;
+
+/// Stable identity for a window whose placement should persist across
+/// sessions. Windows without one keep purely transient placement.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct WindowId(pub &'static str);
+
+/// Inner content area of a window, excluding header and border.
+#[derive(Debug, Clone, Copy)]
+pub struct WindowBoundary {
+    pub dimensions: Vec2,
+}
+
+/// How a window reacts when its content does not match its inner size.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WindowOverflowPolicy {
+    /// Content may spill past the inner rect; the window never grows.
+    #[default]
+    AllowOverflow,
+    /// The window grows/shrinks to wrap its measured content.
+    ConstrainToContent,
+    /// Content is rendered offscreen and cropped to the inner rect; the
+    /// window keeps its own size regardless of content.
+    ClipReserved,
+}
+
+/// Minimum and maximum inner sizes a window may take, whether resized by
+/// the user or by content constraints.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct WindowContentMetrics {
+    pub min_inner: Vec2,
+    pub max_inner: Option<Vec2>,
+}
+
+impl Default for WindowContentMetrics {
+    fn default() -> Self {
+        Self {
+            min_inner: Vec2::new(80.0, 60.0),
+            max_inner: None,
+        }
+    }
+}
+
+/// Insets content from the window's inner rect, as top/right/bottom/left
+/// in the `Vec4`'s x/y/z/w. The scroll viewport shrinks by the padding
+/// and the content origin moves in by the top-left inset; the default of
+/// zero keeps content flush against the border.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct WindowContentPadding(pub Vec4);
+
+impl WindowContentPadding {
+    pub fn uniform(inset: f32) -> Self {
+        Self(Vec4::splat(inset))
+    }
+
+    /// Total horizontal (left + right) and vertical (top + bottom)
+    /// padding.
+    pub fn total(&self) -> Vec2 {
+        Vec2::new(self.0.y + self.0.w, self.0.x + self.0.z)
+    }
+
+    /// Offset of the content origin from the unpadded scroll origin:
+    /// right by the left inset, down by the top inset.
+    pub fn origin_offset(&self) -> Vec2 {
+        Vec2::new(self.0.w, -self.0.x)
+    }
+
+    /// Metrics with the padding folded into the minimum, so a resize or
+    /// content constraint can't shrink the window below padding plus the
+    /// content minimum.
+    pub fn padded_metrics(&self, metrics: &WindowContentMetrics) -> WindowContentMetrics {
+        WindowContentMetrics {
+            min_inner: metrics.min_inner + self.total(),
+            max_inner: metrics.max_inner,
+        }
+    }
+}
+
+/// Horizontal placement of undersized content within a window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HorizontalContentAlign {
+    #[default]
+    Left,
+    Centre,
+    Right,
+}
+
+/// Vertical placement of undersized content within a window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum VerticalContentAlign {
+    #[default]
+    Top,
+    Centre,
+    Bottom,
+}
+
+/// Positions the content root inside the available inner area when the
+/// window is larger than its content. Axes where content exceeds the
+/// viewport fall back to the scroll origin. Defaults to top-left,
+/// matching the old fixed-origin behaviour.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct WindowContentAlign {
+    pub horizontal: HorizontalContentAlign,
+    pub vertical: VerticalContentAlign,
+}
+
+impl WindowContentAlign {
+    /// Centred on both axes — the usual choice for confirmation windows
+    /// holding a single short row of content.
+    pub fn centre() -> Self {
+        Self {
+            horizontal: HorizontalContentAlign::Centre,
+            vertical: VerticalContentAlign::Centre,
+        }
+    }
+}
+
+/// Offset of the content origin for the given viewport/content sizes.
+/// Per axis, the offset distributes the slack (viewport minus content);
+/// zero slack or overflowing content aligns to the scroll origin.
+pub fn content_alignment_offset(
+    viewport: Vec2,
+    content: Vec2,
+    align: &WindowContentAlign,
+) -> Vec2 {
+    let slack = (viewport - content).max(Vec2::ZERO);
+    let horizontal_factor = match align.horizontal {
+        HorizontalContentAlign::Left => 0.0,
+        HorizontalContentAlign::Centre => 0.5,
+        HorizontalContentAlign::Right => 1.0,
+    };
+    let vertical_factor = match align.vertical {
+        VerticalContentAlign::Top => 0.0,
+        VerticalContentAlign::Centre => 0.5,
+        VerticalContentAlign::Bottom => 1.0,
+    };
+    // +x moves content right; -y moves it down from the top edge.
+    Vec2::new(slack.x * horizontal_factor, -slack.y * vertical_factor)
+}
+
+/// A draggable, closable, scrollable in-world window. The root entity
+/// carries this component; visuals and the scroll runtime are spawned as
+/// children on insert.
+#[derive(Component, Debug, Clone)]
+#[require(Transform, Visibility, WindowContentMetrics)]
+#[component(on_insert = Window::on_insert)]
+pub struct Window {
+    pub boundary: WindowBoundary,
+    pub header_height: f32,
+    pub has_close_button: bool,
+    pub resizable: bool,
+    pub overflow: WindowOverflowPolicy,
+}
+
+impl Default for Window {
+    fn default() -> Self {
+        Self {
+            boundary: WindowBoundary {
+                dimensions: Vec2::new(320.0, 200.0),
+            },
+            header_height: 22.0,
+            has_close_button: true,
+            resizable: true,
+            overflow: WindowOverflowPolicy::AllowOverflow,
+        }
+    }
+}
+
+/// Header title text. Updating `text` re-renders on the next frame.
+#[derive(Component, Debug, Clone, Default)]
+pub struct WindowTitle {
+    pub text: String,
+}
+
+/// Opt-in marker: double-clicking this window's header edits its
+/// [`WindowTitle`] inline. Such windows give up header double-click
+/// maximize; the button still works.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct EditableTitle;
+
+/// Active inline title edit. While present the header stops acting as a
+/// drag handle and keyboard input lands in the title text.
+#[derive(Component, Debug, Clone)]
+pub struct TitleEditing {
+    /// Text restored if the edit is cancelled with Escape.
+    original: String,
+}
+
+/// Border colours a window's body chrome takes as interaction focus
+/// moves. Every window draws the ring; inserting this on a root only
+/// overrides the colours (e.g. `DIM_COLOR` for a stronger drop-back).
+#[derive(Component, Debug, Clone, Copy)]
+pub struct WindowFocusStyle {
+    pub focused_color: Color,
+    pub unfocused_color: Color,
+}
+
+impl Default for WindowFocusStyle {
+    fn default() -> Self {
+        Self {
+            focused_color: HIGHLIGHT_COLOR,
+            unfocused_color: PRIMARY_COLOR,
+        }
+    }
+}
+
+impl WindowFocusStyle {
+    /// The body border colour for the given focus state. While nothing
+    /// holds focus every window counts as focused, matching
+    /// [`window_interaction_allowed`].
+    pub fn border_color(&self, focused: bool) -> Color {
+        if focused {
+            self.focused_color
+        } else {
+            self.unfocused_color
+        }
+    }
+}
+
+/// Marks content that should be routed under a window's scroll content
+/// root. Spawn anywhere; `route_window_content` reparents it. Content
+/// built with [`WindowContent::in_tab`] is only visible while its tab
+/// is active on the window's tab row.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct WindowContent {
+    pub window: Entity,
+    /// When set, visibility follows the window's [`tabs::TabBarState`]:
+    /// shown for the active tab, hidden otherwise.
+    pub tab_index: Option<usize>,
+}
+
+impl WindowContent {
+    pub fn new(window: Entity) -> Self {
+        Self {
+            window,
+            tab_index: None,
+        }
+    }
+
+    /// Content belonging to one tab of a tabbed window.
+    pub fn in_tab(window: Entity, tab_index: usize) -> Self {
+        Self {
+            window,
+            tab_index: Some(tab_index),
+        }
+    }
+}
+
+/// Private runtime wiring between a window and its scroll machinery.
+#[derive(Component, Debug)]
+pub struct WindowScrollRuntime {
+    content_root: Entity,
+    scroll_root: Entity,
+    /// Union of child `ContentSize`s, in content-root space.
+    measured_content_inner_size: Vec2,
+}
+
+impl WindowScrollRuntime {
+    /// The entity content should be parented under to scroll with the
+    /// window. Parenting here directly skips the frame of latency the
+    /// [`WindowContent`] routing system otherwise introduces.
+    pub fn content_root(&self) -> Entity {
+        self.content_root
+    }
+}
+
+/// The content root of `window`, or `None` while its runtime has not
+/// been spawned yet (the same frame the [`Window`] was inserted).
+pub fn window_content_root(
+    runtimes: &Query<&WindowScrollRuntime>,
+    window: Entity,
+) -> Option<Entity> {
+    runtimes
+        .get(window)
+        .ok()
+        .map(WindowScrollRuntime::content_root)
+}
+
+#[derive(Component)]
+struct WindowBody {
+    root: Entity,
+}
+
+#[derive(Component)]
+struct WindowHeader {
+    root: Entity,
+}
+
+#[derive(Component)]
+struct WindowTitleText {
+    root: Entity,
+}
+
+/// Close affordance in the window header. Spawns its cross glyph and
+/// click wiring on insert.
+#[derive(Component)]
+#[component(on_insert = WindowCloseButton::on_insert)]
+pub struct WindowCloseButton {
+    pub root: Entity,
+}
+
+impl WindowCloseButton {
+    fn on_insert(mut world: DeferredWorld, context: HookContext) {
+        let root = world.get::<WindowCloseButton>(context.entity).unwrap().root;
+        world.commands().entity(context.entity).insert((
+            Plus {
+                size: WINDOW_CLOSE_BUTTON_SIZE,
+                thickness: 2.0,
+                color: PRIMARY_COLOR,
+            },
+            Transform::from_rotation(Quat::from_rotation_z(std::f32::consts::FRAC_PI_4)),
+            crate::systems::interaction::Clickable::new(Vec2::splat(
+                WINDOW_CLOSE_BUTTON_SIZE + 6.0,
+            )),
+            crate::systems::interaction::ActionPallet(vec![
+                crate::systems::interaction::InputAction::Despawn(root),
+            ]),
+        ));
+    }
+}
+
+/// Present while a window is collapsed to its header. Stores the inner
+/// dimensions to reapply on restore.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct WindowCollapsed {
+    pub restored_dimensions: Vec2,
+}
+
+/// Collapse/restore toggle in the window header, spawned alongside the
+/// close button.
+#[derive(Component)]
+#[component(on_insert = WindowCollapseButton::on_insert)]
+pub struct WindowCollapseButton {
+    pub root: Entity,
+}
+
+impl WindowCollapseButton {
+    fn on_insert(mut world: DeferredWorld, context: HookContext) {
+        world.commands().entity(context.entity).insert((
+            Sprite::from_color(
+                PRIMARY_COLOR,
+                Vec2::new(WINDOW_CLOSE_BUTTON_SIZE, 2.0),
+            ),
+            crate::systems::interaction::Clickable::new(Vec2::splat(
+                WINDOW_CLOSE_BUTTON_SIZE + 6.0,
+            )),
+        ));
+    }
+}
+
+/// Prior placement of a maximized window, reapplied on restore.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct WindowRestoreState {
+    pub translation: Vec3,
+    pub dimensions: Vec2,
+}
+
+/// Maximize/restore toggle in the window header.
+#[derive(Component)]
+#[component(on_insert = WindowMaximizeButton::on_insert)]
+pub struct WindowMaximizeButton {
+    pub root: Entity,
+}
+
+impl WindowMaximizeButton {
+    fn on_insert(mut world: DeferredWorld, context: HookContext) {
+        world.commands().entity(context.entity).insert((
+            crate::ui::shapes::HollowRectangle {
+                dimensions: Vec2::splat(WINDOW_CLOSE_BUTTON_SIZE - 2.0),
+                thickness: 1.5,
+                color: PRIMARY_COLOR,
+            },
+            crate::systems::interaction::Clickable::new(Vec2::splat(
+                WINDOW_CLOSE_BUTTON_SIZE + 6.0,
+            )),
+        ));
+    }
+}
+
+/// Which corner of a window a resize drag grabbed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResizeCorner {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
+impl ResizeCorner {
+    pub fn is_top(&self) -> bool {
+        matches!(self, ResizeCorner::TopLeft | ResizeCorner::TopRight)
+    }
+
+    pub fn is_left(&self) -> bool {
+        matches!(self, ResizeCorner::TopLeft | ResizeCorner::BottomLeft)
+    }
+}
+
+/// Live state for an in-progress corner resize. The corner opposite the
+/// grabbed one stays fixed: dragging a bottom corner anchors the top
+/// edge, dragging a top corner anchors the bottom edge.
+#[derive(Debug, Clone, Copy)]
+pub struct ActiveWindowResizeState {
+    pub root: Entity,
+    pub corner: ResizeCorner,
+    /// World-space y of the opposite (fixed) horizontal edge at drag start.
+    pub fixed_y_world: f32,
+    /// World-space x of the opposite (fixed) vertical edge at drag start.
+    pub fixed_x_world: f32,
+}
+
+/// Which edge of a window a single-axis resize drag grabbed. The top
+/// edge is the header's drag region, so it is not resizable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResizeEdge {
+    Left,
+    Right,
+    Bottom,
+}
+
+/// Live state for an in-progress edge resize; only the grabbed axis
+/// changes, anchored on the opposite edge.
+#[derive(Debug, Clone, Copy)]
+pub struct ActiveWindowEdgeResizeState {
+    pub root: Entity,
+    pub edge: ResizeEdge,
+    /// World-space coordinate of the opposite (fixed) edge.
+    pub fixed_world: f32,
+}
+
+/// Tracks the single window interaction (resize) currently in flight.
+#[derive(Resource, Debug, Default)]
+pub struct ActiveWindowInteraction {
+    pub resize: Option<ActiveWindowResizeState>,
+    pub edge_resize: Option<ActiveWindowEdgeResizeState>,
+}
+
+/// Edge snapping for dragged windows, against both the viewport and
+/// other windows' edges. Holding `suppress_key` disables it for a drag.
+#[derive(Resource, Debug, Clone)]
+pub struct WindowSnapConfig {
+    pub enabled: bool,
+    /// Snap when an edge pair is within this many pixels.
+    pub threshold_px: f32,
+    pub suppress_key: KeyCode,
+}
+
+impl Default for WindowSnapConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            threshold_px: 10.0,
+            suppress_key: KeyCode::ControlLeft,
+        }
+    }
+}
+
+/// Smallest in-threshold delta that moves `edge` onto one of the
+/// candidate positions.
+fn best_snap_delta(edge: f32, candidates: impl Iterator<Item = f32>, threshold: f32) -> Option<f32> {
+    candidates
+        .map(|candidate| candidate - edge)
+        .filter(|delta| delta.abs() <= threshold)
+        .min_by(|a, b| a.abs().total_cmp(&b.abs()))
+}
+
+/// Nudges actively dragged windows flush against nearby edges. Runs in
+/// `WindowSystem::Resolve` before viewport clamping so a snap can never
+/// push a window out of bounds.
+pub fn snap_dragged_windows(
+    keys: Res<ButtonInput<KeyCode>>,
+    config: Res<WindowSnapConfig>,
+    cameras: Query<(&Camera, &GlobalTransform), With<OffscreenCamera>>,
+    mut roots: Query<(
+        Entity,
+        &Window,
+        Option<&WindowCollapsed>,
+        &Draggable,
+        &mut Transform,
+    )>,
+) {
+    if !config.enabled || keys.pressed(config.suppress_key) {
+        return;
+    }
+    let bounds = cameras
+        .iter()
+        .next()
+        .and_then(|(camera, transform)| viewport_world_bounds(camera, transform));
+
+    // Collect every stationary window's outer rect as a snap target.
+    let mut other_rects: Vec<(Entity, Rect)> = Vec::new();
+    for (entity, window, collapsed, draggable, transform) in &roots {
+        if !draggable.dragging() {
+            other_rects.push((
+                entity,
+                Rect::from_center_size(
+                    transform.translation.truncate() + window.outer_centre_offset(),
+                    window.effective_outer_size(collapsed.is_some()),
+                ),
+            ));
+        }
+    }
+
+    for (entity, window, collapsed, draggable, mut transform) in &mut roots {
+        if !draggable.dragging() {
+            continue;
+        }
+        let outer = window.effective_outer_size(collapsed.is_some());
+        let centre = transform.translation.truncate() + window.outer_centre_offset();
+        let half = outer * 0.5;
+
+        let mut x_candidates_left: Vec<f32> = Vec::new();
+        let mut x_candidates_right: Vec<f32> = Vec::new();
+        let mut y_candidates_bottom: Vec<f32> = Vec::new();
+        let mut y_candidates_top: Vec<f32> = Vec::new();
+        if let Some(bounds) = bounds {
+            x_candidates_left.push(bounds.min.x);
+            x_candidates_right.push(bounds.max.x);
+            y_candidates_bottom.push(bounds.min.y);
+            y_candidates_top.push(bounds.max.y);
+        }
+        for (other, rect) in &other_rects {
+            if *other == entity {
+                continue;
+            }
+            // Flush tiling: my left against their right (and vice versa),
+            // plus aligned same-side edges.
+            x_candidates_left.extend([rect.max.x, rect.min.x]);
+            x_candidates_right.extend([rect.min.x, rect.max.x]);
+            y_candidates_bottom.extend([rect.max.y, rect.min.y]);
+            y_candidates_top.extend([rect.min.y, rect.max.y]);
+        }
+
+        let delta_x = best_snap_delta(
+            centre.x - half.x,
+            x_candidates_left.iter().copied(),
+            config.threshold_px,
+        )
+        .or_else(|| {
+            best_snap_delta(
+                centre.x + half.x,
+                x_candidates_right.iter().copied(),
+                config.threshold_px,
+            )
+        });
+        let delta_y = best_snap_delta(
+            centre.y - half.y,
+            y_candidates_bottom.iter().copied(),
+            config.threshold_px,
+        )
+        .or_else(|| {
+            best_snap_delta(
+                centre.y + half.y,
+                y_candidates_top.iter().copied(),
+                config.threshold_px,
+            )
+        });
+        if let Some(delta) = delta_x {
+            transform.translation.x += delta;
+        }
+        if let Some(delta) = delta_y {
+            transform.translation.y += delta;
+        }
+    }
+}
+
+/// Monotonic stacking order for window roots.
+#[derive(Resource, Debug)]
+pub struct WindowZStack {
+    pub next_order: u32,
+    pub base_z: f32,
+}
+
+impl Default for WindowZStack {
+    fn default() -> Self {
+        Self {
+            next_order: 0,
+            base_z: SCENE_SPRITE_Z_MAX + WINDOW_Z_STEP,
+        }
+    }
+}
+
+impl WindowZStack {
+    pub fn z_for_order(&self, order: u32) -> f32 {
+        self.base_z + order as f32 * WINDOW_Z_STEP
+    }
+}
+
+/// Stack order assigned to a window root.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct WindowStackOrder(pub u32);
+
+/// Returns whether interaction systems should honour input aimed at the
+/// given window root.
+pub fn window_interaction_allowed(state: &UiInteractionState, root: Entity) -> bool {
+    state.focused_owner.is_none() || state.focused_owner == Some(root)
+}
+
+/// World-space rect covered by the offscreen camera's viewport.
+pub fn viewport_world_bounds(
+    camera: &Camera,
+    transform: &GlobalTransform,
+) -> Option<Rect> {
+    let size = camera.logical_viewport_size()?;
+    let centre = transform.translation().truncate();
+    Some(Rect::from_center_size(centre, size))
+}
+
+/// Clamps a window root translation so its outer rect stays inside the
+/// viewport bounds. Oversized windows pin to the top-left.
+pub fn clamp_to_viewport(translation: &mut Vec3, outer: Vec2, bounds: Rect) {
+    let half = outer * 0.5;
+    let min_x = bounds.min.x + half.x;
+    let max_x = (bounds.max.x - half.x).max(min_x);
+    let min_y = (bounds.min.y + half.y).min(bounds.max.y - half.y);
+    let max_y = (bounds.max.y - half.y).max(min_y);
+    translation.x = translation.x.clamp(min_x, max_x);
+    translation.y = translation.y.clamp(min_y, max_y);
+}
+
+impl Window {
+    /// Outer size including header (border is drawn inside the boundary).
+    pub fn outer_size(&self) -> Vec2 {
+        self.boundary.dimensions + Vec2::new(0.0, self.header_height)
+    }
+
+    /// Body height as laid out: zero while collapsed, so the outer rect
+    /// (and viewport clamping) degrades to just the header.
+    pub fn effective_body_height(&self, collapsed: bool) -> f32 {
+        if collapsed {
+            0.0
+        } else {
+            self.boundary.dimensions.y
+        }
+    }
+
+    /// Outer size accounting for collapse.
+    pub fn effective_outer_size(&self, collapsed: bool) -> Vec2 {
+        Vec2::new(
+            self.boundary.dimensions.x,
+            self.effective_body_height(collapsed) + self.header_height,
+        )
+    }
+
+    /// Offset of the outer rect's centre from the root (the root sits at
+    /// the centre of the body; the header pushes the outer centre up).
+    pub fn outer_centre_offset(&self) -> Vec2 {
+        Vec2::new(0.0, self.header_height * 0.5)
+    }
+
+    fn corner_positions(&self, centre: Vec2) -> [(ResizeCorner, Vec2); 4] {
+        let half = self.boundary.dimensions * 0.5;
+        [
+            (ResizeCorner::TopLeft, centre + Vec2::new(-half.x, half.y)),
+            (ResizeCorner::TopRight, centre + Vec2::new(half.x, half.y)),
+            (
+                ResizeCorner::BottomLeft,
+                centre + Vec2::new(-half.x, -half.y),
+            ),
+            (
+                ResizeCorner::BottomRight,
+                centre + Vec2::new(half.x, -half.y),
+            ),
+        ]
+    }
+
+    /// Hit-tests the resize handles at each corner of the body rect.
+    pub fn is_cursor_over_corner(&self, cursor: Vec2, centre: Vec2) -> Option<ResizeCorner> {
+        let half_handle = WINDOW_RESIZE_HANDLE_SIZE * 0.5;
+        self.corner_positions(centre)
+            .into_iter()
+            .find(|(_, position)| {
+                (cursor.x - position.x).abs() <= half_handle
+                    && (cursor.y - position.y).abs() <= half_handle
+            })
+            .map(|(corner, _)| corner)
+    }
+
+    /// Hit-tests the edge bands of the body rect. Corner handles take
+    /// precedence: the bands stop short of each corner's hit square.
+    pub fn is_cursor_over_edge(&self, cursor: Vec2, centre: Vec2) -> Option<ResizeEdge> {
+        if self.is_cursor_over_corner(cursor, centre).is_some() {
+            return None;
+        }
+        let half = self.boundary.dimensions * 0.5;
+        let half_handle = WINDOW_RESIZE_HANDLE_SIZE * 0.5;
+        let inner_x = half.x - half_handle;
+        let inner_y = half.y - half_handle;
+        let local = cursor - centre;
+        if local.y.abs() <= inner_y {
+            if (local.x + half.x).abs() <= half_handle {
+                return Some(ResizeEdge::Left);
+            }
+            if (local.x - half.x).abs() <= half_handle {
+                return Some(ResizeEdge::Right);
+            }
+        }
+        if local.x.abs() <= inner_x && (local.y + half.y).abs() <= half_handle {
+            return Some(ResizeEdge::Bottom);
+        }
+        None
+    }
+
+    /// Applies an edge drag: single-axis resize anchored on the opposite
+    /// edge, clamped to metrics.
+    pub fn enact_edge_resize(
+        &mut self,
+        metrics: &WindowContentMetrics,
+        state: &ActiveWindowEdgeResizeState,
+        cursor: Vec2,
+        translation: &mut Vec3,
+    ) {
+        let max_inner = metrics.max_inner.unwrap_or(Vec2::INFINITY);
+        match state.edge {
+            ResizeEdge::Left => {
+                let width = (state.fixed_world - cursor.x)
+                    .clamp(metrics.min_inner.x, max_inner.x);
+                self.boundary.dimensions.x = width;
+                translation.x = state.fixed_world - width * 0.5;
+            }
+            ResizeEdge::Right => {
+                let width = (cursor.x - state.fixed_world)
+                    .clamp(metrics.min_inner.x, max_inner.x);
+                self.boundary.dimensions.x = width;
+                translation.x = state.fixed_world + width * 0.5;
+            }
+            ResizeEdge::Bottom => {
+                let height = (state.fixed_world - cursor.y)
+                    .clamp(metrics.min_inner.y, max_inner.y);
+                self.boundary.dimensions.y = height;
+                translation.y = state.fixed_world - height * 0.5;
+            }
+        }
+    }
+
+    /// Applies a corner drag: recomputes dimensions from the cursor and
+    /// the fixed anchor, clamps to metrics, and repositions the root so
+    /// the anchor stays put.
+    pub fn enact_resize(
+        &mut self,
+        metrics: &WindowContentMetrics,
+        state: &ActiveWindowResizeState,
+        cursor: Vec2,
+        translation: &mut Vec3,
+    ) {
+        let unclamped_width = if state.corner.is_left() {
+            state.fixed_x_world - cursor.x
+        } else {
+            cursor.x - state.fixed_x_world
+        };
+        let unclamped_height = if state.corner.is_top() {
+            cursor.y - state.fixed_y_world
+        } else {
+            state.fixed_y_world - cursor.y
+        };
+
+        let mut dimensions = Vec2::new(unclamped_width, unclamped_height);
+        dimensions = dimensions.max(metrics.min_inner);
+        if let Some(max_inner) = metrics.max_inner {
+            dimensions = dimensions.min(max_inner);
+        }
+        self.boundary.dimensions = dimensions;
+
+        translation.x = if state.corner.is_left() {
+            state.fixed_x_world - dimensions.x * 0.5
+        } else {
+            state.fixed_x_world + dimensions.x * 0.5
+        };
+        // Top-corner drags keep the bottom edge fixed (the header follows
+        // the moving top edge); bottom-corner drags keep the top fixed.
+        translation.y = if state.corner.is_top() {
+            state.fixed_y_world + dimensions.y * 0.5
+        } else {
+            state.fixed_y_world - dimensions.y * 0.5
+        };
+    }
+
+    fn on_insert(mut world: DeferredWorld, context: HookContext) {
+        let root = context.entity;
+
+        // Windows with a stable id restore their saved placement. The
+        // position still passes through `clamp_to_viewport` afterwards,
+        // so a layout saved off-screen snaps back into view.
+        let stored = world.get::<WindowId>(root).and_then(|id| {
+            world
+                .get_resource::<layout::WindowLayoutStore>()
+                .and_then(|store| store.get(id.0))
+        });
+        if let Some(stored) = stored {
+            if let Some(mut window) = world.get_mut::<Window>(root) {
+                window.boundary.dimensions = Vec2::new(stored.width, stored.height);
+            }
+            if let Some(mut transform) = world.get_mut::<Transform>(root) {
+                transform.translation.x = stored.x;
+                transform.translation.y = stored.y;
+            }
+        }
+
+        let window = world.get::<Window>(root).unwrap().clone();
+        let dimensions = window.boundary.dimensions;
+        let header_height = window.header_height;
+        let has_close_button = window.has_close_button;
+
+        let mut commands = world.commands();
+
+        // Scroll root sits at the body centre; content hangs beneath it.
+        let mut scroll_root_commands = commands.spawn((
+            ScrollableRoot {
+                axis: ScrollAxis::Vertical,
+                viewport_size: dimensions,
+                content_extent: 0.0,
+            },
+            Transform::from_xyz(0.0, 0.0, 0.5),
+            Visibility::Inherited,
+            ChildOf(root),
+        ));
+        if window.overflow == WindowOverflowPolicy::ClipReserved {
+            scroll_root_commands.insert(crate::ui::scroll::clip::ScrollClip);
+        }
+        let scroll_root = scroll_root_commands.id();
+        let content_root = commands
+            .spawn((
+                crate::ui::scroll::ScrollContent::new(scroll_root),
+                Transform::default(),
+                Visibility::Inherited,
+                ChildOf(scroll_root),
+            ))
+            .id();
+        commands.spawn((
+            crate::ui::scroll::ScrollBar {
+                root: scroll_root,
+                axis: ScrollAxis::Vertical,
+                width: crate::ui::scroll::SCROLL_BAR_WIDTH,
+            },
+            crate::ui::scroll::ScrollBarHoverExpand::default(),
+            Sprite::from_color(DIM_COLOR, Vec2::ONE),
+            Transform::from_xyz(0.0, 0.0, 1.5),
+            Visibility::Inherited,
+            ChildOf(root),
+        ));
+
+        // Body and header chrome.
+        commands.spawn((
+            WindowBody { root },
+            BorderedRectangle {
+                dimensions,
+                border_thickness: WINDOW_BORDER_THICKNESS,
+                border_color: PRIMARY_COLOR,
+                fill_color: WINDOW_BODY_COLOR,
+            },
+            Transform::from_xyz(0.0, 0.0, 0.0),
+            ChildOf(root),
+        ));
+        commands.spawn((
+            WindowHeader { root },
+            BorderedRectangle {
+                dimensions: Vec2::new(dimensions.x, header_height),
+                border_thickness: WINDOW_BORDER_THICKNESS,
+                border_color: PRIMARY_COLOR,
+                fill_color: WINDOW_BODY_COLOR,
+            },
+            Transform::from_xyz(0.0, (dimensions.y + header_height) * 0.5, 0.0),
+            ChildOf(root),
+        ));
+        commands.spawn((
+            WindowTitleText { root },
+            Text2d::new(String::new()),
+            TextFont::from_font_size(WINDOW_TITLE_FONT_SIZE),
+            TextColor(PRIMARY_COLOR),
+            Transform::from_xyz(0.0, (dimensions.y + header_height) * 0.5, 1.0),
+            ChildOf(root),
+        ));
+        if has_close_button {
+            commands.spawn((
+                WindowCloseButton { root },
+                Visibility::Inherited,
+                ChildOf(root),
+            ));
+        }
+        commands.spawn((
+            WindowCollapseButton { root },
+            Visibility::Inherited,
+            ChildOf(root),
+        ));
+        commands.spawn((
+            WindowMaximizeButton { root },
+            Visibility::Inherited,
+            ChildOf(root),
+        ));
+
+        commands.entity(root).insert((
+            WindowScrollRuntime {
+                content_root,
+                scroll_root,
+                measured_content_inner_size: Vec2::ZERO,
+            },
+            Draggable::default(),
+            DraggableRegion {
+                dimensions: Vec2::new(dimensions.x, header_height),
+                offset: Vec2::new(0.0, (dimensions.y + header_height) * 0.5),
+            },
+        ));
+    }
+}
+
+/// Assigns a stack order (and z) to freshly inserted window roots, and
+/// resets the counter back to the baseline once all windows are gone.
+pub fn assign_stack_order(
+    mut commands: Commands,
+    mut stack: ResMut<WindowZStack>,
+    new_roots: Query<(Entity, &Transform), (With<Window>, Without<WindowStackOrder>)>,
+    live: Query<Entity, With<Window>>,
+) {
+    if live.is_empty() {
+        stack.next_order = 0;
+        return;
+    }
+    for (entity, transform) in &new_roots {
+        let order = stack.next_order;
+        stack.next_order += 1;
+        let mut transform = *transform;
+        transform.translation.z = stack.z_for_order(order);
+        commands
+            .entity(entity)
+            .insert((WindowStackOrder(order), transform));
+    }
+}
+
+/// Clicking anywhere on a window's outer rect focuses it and raises it to
+/// the top of the stack.
+pub fn raise_window_on_pointer_down(
+    buttons: Res<ButtonInput<MouseButton>>,
+    cursor: Res<CustomCursor>,
+    mut stack: ResMut<WindowZStack>,
+    mut state: ResMut<UiInteractionState>,
+    mut roots: Query<(Entity, &Window, &mut Transform, &mut WindowStackOrder)>,
+) {
+    if !buttons.just_pressed(MouseButton::Left) {
+        return;
+    }
+    // Top-most hit wins.
+    let mut hit: Option<(Entity, f32)> = None;
+    for (entity, window, transform, _) in &roots {
+        let centre = transform.translation.truncate() + window.outer_centre_offset();
+        let half = window.outer_size() * 0.5;
+        let inside = (cursor.position.x - centre.x).abs() <= half.x
+            && (cursor.position.y - centre.y).abs() <= half.y;
+        if inside && hit.is_none_or(|(_, z)| transform.translation.z > z) {
+            hit = Some((entity, transform.translation.z));
+        }
+    }
+    let Some((entity, _)) = hit else {
+        if state.focused_owner.is_some() {
+            state.focused_owner = None;
+        }
+        return;
+    };
+    state.focused_owner = Some(entity);
+    let Ok((_, _, mut transform, mut order)) = roots.get_mut(entity) else {
+        return;
+    };
+    order.0 = stack.next_order;
+    stack.next_order += 1;
+    transform.translation.z = stack.z_for_order(order.0) + WINDOW_FOCUS_DEPTH_SPAN;
+}
+
+/// Which window index focus moves to next. `current` is the focused
+/// window's position in ascending stack order, if any; forward walks to
+/// the least recently raised window, reverse steps back down.
+pub fn next_focus_index(current: Option<usize>, count: usize, reverse: bool) -> Option<usize> {
+    if count == 0 {
+        return None;
+    }
+    let top = count - 1;
+    Some(match current {
+        None => top,
+        Some(index) if reverse => (index + count - 1) % count,
+        Some(index) => (index + 1) % count,
+    })
+}
+
+/// Alt+Tab cycles keyboard focus through the open windows in stack
+/// order (Shift reverses). The chosen window raises through the same
+/// depth logic as a pointer click, so scroll and interaction gating
+/// follow `focused_owner` exactly as for mouse focus.
+pub fn cycle_window_focus(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut stack: ResMut<WindowZStack>,
+    mut state: ResMut<UiInteractionState>,
+    mut roots: Query<(Entity, &mut Transform, &mut WindowStackOrder), With<Window>>,
+) {
+    let alt = keys.pressed(KeyCode::AltLeft) || keys.pressed(KeyCode::AltRight);
+    if !alt || !keys.just_pressed(KeyCode::Tab) || state.text_input_focus.is_some() {
+        return;
+    }
+    let mut ordered: Vec<(Entity, u32)> = roots
+        .iter()
+        .map(|(entity, _, order)| (entity, order.0))
+        .collect();
+    ordered.sort_by_key(|(_, order)| *order);
+    let current = state
+        .focused_owner
+        .and_then(|owner| ordered.iter().position(|(entity, _)| *entity == owner));
+    let reverse = keys.pressed(KeyCode::ShiftLeft) || keys.pressed(KeyCode::ShiftRight);
+    let Some(next) = next_focus_index(current, ordered.len(), reverse) else {
+        return;
+    };
+    let (chosen, _) = ordered[next];
+    state.focused_owner = Some(chosen);
+    let Ok((_, mut transform, mut order)) = roots.get_mut(chosen) else {
+        return;
+    };
+    order.0 = stack.next_order;
+    stack.next_order += 1;
+    transform.translation.z = stack.z_for_order(order.0) + WINDOW_FOCUS_DEPTH_SPAN;
+}
+
+/// Begins/ends corner resize drags and applies them while active.
+pub fn handle_window_resize(
+    buttons: Res<ButtonInput<MouseButton>>,
+    cursor: Res<CustomCursor>,
+    state: Res<UiInteractionState>,
+    mut active: ResMut<ActiveWindowInteraction>,
+    collapsed: Query<(), With<WindowCollapsed>>,
+    mut roots: Query<(
+        Entity,
+        &mut Window,
+        &WindowContentMetrics,
+        &mut Transform,
+        &mut Draggable,
+        Option<&WindowContentPadding>,
+    )>,
+) {
+    if buttons.just_pressed(MouseButton::Left)
+        && active.resize.is_none()
+        && active.edge_resize.is_none()
+    {
+        for (entity, window, _, transform, mut draggable, _) in &mut roots {
+            if !window.resizable
+                || collapsed.get(entity).is_ok()
+                || !window_interaction_allowed(&state, entity)
+            {
+                continue;
+            }
+            let centre = transform.translation.truncate();
+            let half = window.boundary.dimensions * 0.5;
+            // Corners win when corner and edge bands overlap.
+            let Some(corner) = window.is_cursor_over_corner(cursor.position, centre) else {
+                if let Some(edge) = window.is_cursor_over_edge(cursor.position, centre) {
+                    active.edge_resize = Some(ActiveWindowEdgeResizeState {
+                        root: entity,
+                        edge,
+                        fixed_world: match edge {
+                            ResizeEdge::Left => centre.x + half.x,
+                            ResizeEdge::Right => centre.x - half.x,
+                            ResizeEdge::Bottom => centre.y + half.y,
+                        },
+                    });
+                    draggable.grab_offset = None;
+                    break;
+                }
+                continue;
+            };
+            let half = window.boundary.dimensions * 0.5;
+            active.resize = Some(ActiveWindowResizeState {
+                root: entity,
+                corner,
+                fixed_y_world: if corner.is_top() {
+                    centre.y - half.y
+                } else {
+                    centre.y + half.y
+                },
+                fixed_x_world: if corner.is_left() {
+                    centre.x + half.x
+                } else {
+                    centre.x - half.x
+                },
+            });
+            // A resize grab must not also start a header drag.
+            draggable.grab_offset = None;
+            break;
+        }
+    }
+    if !buttons.pressed(MouseButton::Left) {
+        active.resize = None;
+        active.edge_resize = None;
+    }
+    if let Some(resize) = active.resize {
+        if let Ok((_, mut window, metrics, mut transform, _, padding)) =
+            roots.get_mut(resize.root)
+        {
+            let metrics = padding.copied().unwrap_or_default().padded_metrics(metrics);
+            window.enact_resize(&metrics, &resize, cursor.position, &mut transform.translation);
+        } else {
+            active.resize = None;
+        }
+    }
+    if let Some(resize) = active.edge_resize {
+        if let Ok((_, mut window, metrics, mut transform, _, padding)) =
+            roots.get_mut(resize.root)
+        {
+            let metrics = padding.copied().unwrap_or_default().padded_metrics(metrics);
+            window.enact_edge_resize(
+                &metrics,
+                &resize,
+                cursor.position,
+                &mut transform.translation,
+            );
+        } else {
+            active.edge_resize = None;
+        }
+    }
+}
+
+/// Keeps each root's drag region matched to its header strip.
+pub fn sync_root_drag_bounds(
+    mut roots: Query<
+        (&Window, Option<&WindowCollapsed>, &mut DraggableRegion),
+        Or<(Changed<Window>, Changed<WindowCollapsed>)>,
+    >,
+) {
+    for (window, collapsed, mut region) in &mut roots {
+        let body_height = window.effective_body_height(collapsed.is_some());
+        region.dimensions = Vec2::new(window.boundary.dimensions.x, window.header_height);
+        region.offset = Vec2::new(0.0, (body_height + window.header_height) * 0.5);
+    }
+}
+
+/// Keeps window roots inside the camera viewport.
+pub fn clamp_windows_to_viewport(
+    cameras: Query<(&Camera, &GlobalTransform), With<OffscreenCamera>>,
+    mut roots: Query<(&Window, Option<&WindowCollapsed>, &mut Transform)>,
+) {
+    let Some(bounds) = cameras
+        .iter()
+        .next()
+        .and_then(|(camera, transform)| viewport_world_bounds(camera, transform))
+    else {
+        return;
+    };
+    for (window, collapsed, mut transform) in &mut roots {
+        let mut translation = transform.translation + window.outer_centre_offset().extend(0.0);
+        clamp_to_viewport(
+            &mut translation,
+            window.effective_outer_size(collapsed.is_some()),
+            bounds,
+        );
+        transform.translation =
+            translation - window.outer_centre_offset().extend(0.0);
+    }
+}
+
+/// Tab width used by [`WindowBuilder::with_tabs`].
+pub const WINDOW_BUILDER_TAB_WIDTH: f32 = 80.0;
+
+/// What [`WindowBuilder::open`] spawned: the window root and a content
+/// entity already carrying [`WindowContent`], so children parented to it
+/// land under the scroll runtime without further wiring.
+#[derive(Debug, Clone, Copy)]
+pub struct OpenedWindow {
+    pub root: Entity,
+    pub content: Entity,
+}
+
+/// Ergonomic one-call spawner over the existing window components.
+/// Everything it inserts could be spawned by hand; the builder just
+/// keeps scene code from restating the `on_insert` expectations.
+#[derive(Debug, Clone, Default)]
+pub struct WindowBuilder {
+    title: Option<String>,
+    size: Option<Vec2>,
+    closable: Option<bool>,
+    tabs: Option<Vec<String>>,
+    modal: bool,
+}
+
+impl WindowBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Inner dimensions; unset keeps [`Window`]'s default.
+    pub fn size(mut self, size: Vec2) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    pub fn closable(mut self, closable: bool) -> Self {
+        self.closable = Some(closable);
+        self
+    }
+
+    /// Adds a tab row; pair it with [`WindowContent::in_tab`] children
+    /// on the returned content entity's window.
+    pub fn with_tabs(mut self, labels: Vec<String>) -> Self {
+        self.tabs = Some(labels);
+        self
+    }
+
+    /// Pins interaction focus to the window until it closes.
+    pub fn modal(mut self) -> Self {
+        self.modal = true;
+        self
+    }
+
+    /// Spawns the window and a routed content entity.
+    pub fn open(self, commands: &mut Commands) -> OpenedWindow {
+        let mut window = Window::default();
+        if let Some(size) = self.size {
+            window.boundary.dimensions = size;
+        }
+        if let Some(closable) = self.closable {
+            window.has_close_button = closable;
+        }
+        let root = commands.spawn(window).id();
+        if let Some(title) = self.title {
+            commands.entity(root).insert(WindowTitle { text: title });
+        }
+        if self.modal {
+            commands.entity(root).insert(WindowModal);
+        }
+        if let Some(labels) = self.tabs {
+            commands.spawn((
+                tabs::WindowTabRow::new(labels, WINDOW_BUILDER_TAB_WIDTH),
+                WindowContent::new(root),
+            ));
+        }
+        let content = commands
+            .spawn((
+                WindowContent::new(root),
+                Transform::default(),
+                Visibility::Inherited,
+            ))
+            .id();
+        OpenedWindow { root, content }
+    }
+}
+
+/// Reparents `WindowContent` entities under their window's content root.
+pub fn route_window_content(
+    mut commands: Commands,
+    content: Query<(Entity, &WindowContent), Added<WindowContent>>,
+    runtimes: Query<&WindowScrollRuntime>,
+) {
+    for (entity, target) in &content {
+        let Ok(runtime) = runtimes.get(target.window) else {
+            continue;
+        };
+        commands.entity(entity).insert(ChildOf(runtime.content_root));
+    }
+}
+
+/// Measures content and pushes geometry into the scroll runtime: viewport
+/// from the window's inner rect less any content padding, content extent
+/// from the measured union of `ContentSize` children.
+pub fn sync_scroll_runtime_geometry(
+    mut roots: Query<(
+        &Window,
+        &WindowContentMetrics,
+        &mut WindowScrollRuntime,
+        Option<&WindowContentAlign>,
+        Option<&WindowContentPadding>,
+    )>,
+    mut scroll_roots: Query<(&mut ScrollableRoot, &mut ScrollState)>,
+    mut contents: Query<&mut crate::ui::scroll::ScrollContent>,
+    children: Query<&Children>,
+    sized: Query<(&ContentSize, &Transform)>,
+) {
+    for (window, _metrics, mut runtime, align, padding) in &mut roots {
+        let mut measured = Vec2::ZERO;
+        if let Ok(kids) = children.get(runtime.content_root) {
+            for child in kids.iter() {
+                if let Ok((size, transform)) = sized.get(child) {
+                    let extent = transform.translation.truncate().abs() + size.0 * 0.5;
+                    measured = measured.max(extent * 2.0);
+                }
+            }
+        }
+        runtime.measured_content_inner_size = measured;
+
+        let Ok((mut scroll_root, mut state)) = scroll_roots.get_mut(runtime.scroll_root) else {
+            continue;
+        };
+        let padding = padding.copied().unwrap_or_default();
+        scroll_root.viewport_size =
+            (window.boundary.dimensions - padding.total()).max(Vec2::ZERO);
+        scroll_root.content_extent = measured.y;
+        clamp_scroll_state(&mut state);
+
+        if let Ok(mut content) = contents.get_mut(runtime.content_root) {
+            content.origin_offset = padding.origin_offset()
+                + content_alignment_offset(
+                    scroll_root.viewport_size,
+                    measured,
+                    &align.copied().unwrap_or_default(),
+                );
+        }
+    }
+}
+
+/// Inner size a window should adopt for its measured content, or `None`
+/// when the policy leaves the dimensions alone. Only `ConstrainToContent`
+/// tracks content; clipped windows crop instead of growing.
+pub fn content_constrained_inner(
+    policy: WindowOverflowPolicy,
+    measured: Vec2,
+    metrics: &WindowContentMetrics,
+) -> Option<Vec2> {
+    if policy != WindowOverflowPolicy::ConstrainToContent {
+        return None;
+    }
+    let mut inner = measured.max(metrics.min_inner);
+    if let Some(max_inner) = metrics.max_inner {
+        inner = inner.min(max_inner);
+    }
+    Some(inner)
+}
+
+/// Grows windows whose policy constrains them to their content, leaving
+/// room for any content padding.
+pub fn resolve_constraints(
+    mut roots: Query<(
+        &mut Window,
+        &WindowContentMetrics,
+        &WindowScrollRuntime,
+        Option<&WindowContentPadding>,
+    )>,
+) {
+    for (mut window, metrics, runtime, padding) in &mut roots {
+        let padding = padding.copied().unwrap_or_default();
+        let Some(inner) = content_constrained_inner(
+            window.overflow,
+            runtime.measured_content_inner_size + padding.total(),
+            &padding.padded_metrics(metrics),
+        ) else {
+            continue;
+        };
+        if window.boundary.dimensions.distance_squared(inner) > f32::EPSILON {
+            window.boundary.dimensions = inner;
+        }
+    }
+}
+
+/// Syncs chrome visuals (body, header, title, close button) to the
+/// window's current dimensions, and the body border to the focus ring
+/// colour for the root's focus state.
+pub fn update_window_visuals(
+    state: Res<UiInteractionState>,
+    windows: Query<(
+        &Window,
+        Option<&WindowTitle>,
+        Option<&WindowCollapsed>,
+        Has<TitleEditing>,
+        Option<&WindowFocusStyle>,
+    )>,
+    mut bodies: Query<
+        (&WindowBody, &mut BorderedRectangle, &mut Visibility),
+        Without<WindowHeader>,
+    >,
+    mut headers: Query<
+        (&WindowHeader, &mut BorderedRectangle, &mut Transform),
+        Without<WindowBody>,
+    >,
+    mut titles: Query<
+        (&WindowTitleText, &mut Text2d, &mut Transform),
+        (Without<WindowHeader>, Without<WindowCloseButton>),
+    >,
+    mut close_buttons: Query<
+        (&WindowCloseButton, &mut Transform),
+        (
+            Without<WindowHeader>,
+            Without<WindowTitleText>,
+            Without<WindowCollapseButton>,
+            Without<WindowMaximizeButton>,
+        ),
+    >,
+    mut collapse_buttons: Query<
+        (&WindowCollapseButton, &mut Transform),
+        (
+            Without<WindowHeader>,
+            Without<WindowTitleText>,
+            Without<WindowCloseButton>,
+            Without<WindowMaximizeButton>,
+        ),
+    >,
+    mut maximize_buttons: Query<
+        (&WindowMaximizeButton, &mut Transform),
+        (
+            Without<WindowHeader>,
+            Without<WindowTitleText>,
+            Without<WindowCloseButton>,
+            Without<WindowCollapseButton>,
+        ),
+    >,
+) {
+    for (body, mut rectangle, mut visibility) in &mut bodies {
+        if let Ok((window, _, collapsed, _, style)) = windows.get(body.root) {
+            rectangle.dimensions = window.boundary.dimensions;
+            rectangle.border_color = style
+                .copied()
+                .unwrap_or_default()
+                .border_color(window_interaction_allowed(&state, body.root));
+            *visibility = if collapsed.is_some() {
+                Visibility::Hidden
+            } else {
+                Visibility::Inherited
+            };
+        }
+    }
+    for (header, mut rectangle, mut transform) in &mut headers {
+        if let Ok((window, _, collapsed, _, _)) = windows.get(header.root) {
+            rectangle.dimensions =
+                Vec2::new(window.boundary.dimensions.x, window.header_height);
+            transform.translation.y = (window.effective_body_height(collapsed.is_some())
+                + window.header_height)
+                * 0.5;
+        }
+    }
+    for (title, mut text, mut transform) in &mut titles {
+        if let Ok((window, window_title, collapsed, editing, _)) = windows.get(title.root) {
+            if let Some(window_title) = window_title {
+                // A trailing caret marks an in-flight inline edit.
+                let rendered = if editing {
+                    format!("{}_", window_title.text)
+                } else {
+                    window_title.text.clone()
+                };
+                if text.0 != rendered {
+                    text.0 = rendered;
+                }
+            }
+            transform.translation.y = (window.effective_body_height(collapsed.is_some())
+                + window.header_height)
+                * 0.5;
+        }
+    }
+    for (button, mut transform) in &mut close_buttons {
+        if let Ok((window, _, collapsed, _, _)) = windows.get(button.root) {
+            transform.translation.x =
+                window.boundary.dimensions.x * 0.5 - WINDOW_CLOSE_BUTTON_SIZE;
+            transform.translation.y = (window.effective_body_height(collapsed.is_some())
+                + window.header_height)
+                * 0.5;
+        }
+    }
+    for (button, mut transform) in &mut collapse_buttons {
+        if let Ok((window, _, collapsed, _, _)) = windows.get(button.root) {
+            transform.translation.x =
+                window.boundary.dimensions.x * 0.5 - WINDOW_CLOSE_BUTTON_SIZE * 2.5;
+            transform.translation.y = (window.effective_body_height(collapsed.is_some())
+                + window.header_height)
+                * 0.5;
+            transform.translation.z = 1.0;
+        }
+    }
+    for (button, mut transform) in &mut maximize_buttons {
+        if let Ok((window, _, collapsed, _, _)) = windows.get(button.root) {
+            transform.translation.x =
+                window.boundary.dimensions.x * 0.5 - WINDOW_CLOSE_BUTTON_SIZE * 4.0;
+            transform.translation.y = (window.effective_body_height(collapsed.is_some())
+                + window.header_height)
+                * 0.5;
+            transform.translation.z = 1.0;
+        }
+    }
+}
+
+/// Inner dimensions a maximized window should take for the given
+/// viewport bounds, honouring `max_inner` when the window is
+/// content-constrained.
+pub fn maximized_dimensions(
+    window: &Window,
+    metrics: &WindowContentMetrics,
+    bounds: Rect,
+) -> Vec2 {
+    let mut dimensions = bounds.size() - Vec2::new(0.0, window.header_height);
+    if window.overflow == WindowOverflowPolicy::ConstrainToContent {
+        if let Some(max_inner) = metrics.max_inner {
+            dimensions = dimensions.min(max_inner);
+        }
+    }
+    dimensions
+}
+
+fn toggle_window_maximize(
+    commands: &mut Commands,
+    root: Entity,
+    window: &mut Window,
+    metrics: &WindowContentMetrics,
+    transform: &mut Transform,
+    restore: Option<&WindowRestoreState>,
+    bounds: Rect,
+) {
+    if let Some(restore) = restore {
+        window.boundary.dimensions = restore.dimensions;
+        let z = transform.translation.z;
+        transform.translation = restore.translation.with_z(z);
+        commands.entity(root).remove::<WindowRestoreState>();
+    } else {
+        commands.entity(root).insert(WindowRestoreState {
+            translation: transform.translation,
+            dimensions: window.boundary.dimensions,
+        });
+        window.boundary.dimensions = maximized_dimensions(window, metrics, bounds);
+        let centre = bounds.center() - window.outer_centre_offset();
+        transform.translation.x = centre.x;
+        transform.translation.y = centre.y;
+    }
+}
+
+/// Starts an inline title edit on a header double-click for windows
+/// carrying [`EditableTitle`]. The drag region collapses for the
+/// edit's duration so presses land in the edit instead of moving the
+/// window; `capture_title_edit_input` restores it on commit or cancel.
+pub fn begin_title_edits(
+    mut commands: Commands,
+    time: Res<Time<Real>>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    cursor: Res<CustomCursor>,
+    mut state: ResMut<UiInteractionState>,
+    mut roots: Query<
+        (Entity, &Transform, &mut DraggableRegion, &WindowTitle),
+        (With<Window>, With<EditableTitle>, Without<TitleEditing>),
+    >,
+    mut last_header_click: Local<Option<(Entity, f64)>>,
+) {
+    if !mouse.just_pressed(MouseButton::Left) || state.text_input_focus.is_some() {
+        return;
+    }
+    let now = time.elapsed_secs_f64();
+    for (entity, transform, mut region, title) in &mut roots {
+        let centre = transform.translation.truncate() + region.offset;
+        let half = region.dimensions * 0.5;
+        let inside = (cursor.position.x - centre.x).abs() <= half.x
+            && (cursor.position.y - centre.y).abs() <= half.y;
+        if !inside {
+            continue;
+        }
+        if let Some((last_entity, at)) = *last_header_click {
+            if last_entity == entity && now - at < 0.35 {
+                commands.entity(entity).insert(TitleEditing {
+                    original: title.text.clone(),
+                });
+                state.text_input_focus = Some(entity);
+                region.dimensions = Vec2::ZERO;
+                *last_header_click = None;
+                return;
+            }
+        }
+        *last_header_click = Some((entity, now));
+        return;
+    }
+}
+
+/// Captures keyboard input into the edited title: printable characters
+/// append, Backspace deletes, Enter commits and Escape restores the
+/// original text. Either ending re-arms the drag region and releases
+/// the text focus; the rendered header text follows through the usual
+/// title update path.
+pub fn capture_title_edit_input(
+    mut commands: Commands,
+    mut input: EventReader<KeyboardInput>,
+    mut state: ResMut<UiInteractionState>,
+    mut roots: Query<(
+        Entity,
+        &Window,
+        Option<&WindowCollapsed>,
+        &mut WindowTitle,
+        &TitleEditing,
+        &mut DraggableRegion,
+    )>,
+) {
+    for (entity, window, collapsed, mut title, editing, mut region) in &mut roots {
+        let mut finished = false;
+        for event in input.read() {
+            if !event.state.is_pressed() {
+                continue;
+            }
+            match &event.logical_key {
+                Key::Enter => finished = true,
+                Key::Escape => {
+                    title.text = editing.original.clone();
+                    finished = true;
+                }
+                Key::Backspace => {
+                    title.text.pop();
+                }
+                Key::Space => title.text.push(' '),
+                Key::Character(typed) => {
+                    for character in typed.chars().filter(|c| !c.is_control()) {
+                        title.text.push(character);
+                    }
+                }
+                _ => {}
+            }
+            if finished {
+                break;
+            }
+        }
+        if !finished {
+            continue;
+        }
+        commands.entity(entity).remove::<TitleEditing>();
+        if state.text_input_focus == Some(entity) {
+            state.text_input_focus = None;
+        }
+        let body_height = window.effective_body_height(collapsed.is_some());
+        region.dimensions = Vec2::new(window.boundary.dimensions.x, window.header_height);
+        region.offset = Vec2::new(0.0, (body_height + window.header_height) * 0.5);
+    }
+}
+
+/// Maximize button clicks and header double-clicks toggle between the
+/// viewport-filling placement and the remembered one. Constraint
+/// resolution runs right after in `WindowSystem::Resolve`, so the scroll
+/// extents update the same frame.
+pub fn handle_window_maximize(
+    mut commands: Commands,
+    time: Res<Time<Real>>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    cursor: Res<CustomCursor>,
+    cameras: Query<(&Camera, &GlobalTransform), With<OffscreenCamera>>,
+    buttons: Query<(&WindowMaximizeButton, &Clickable)>,
+    mut roots: Query<(
+        Entity,
+        &mut Window,
+        &WindowContentMetrics,
+        &mut Transform,
+        &DraggableRegion,
+        Option<&WindowRestoreState>,
+        Has<EditableTitle>,
+    )>,
+    mut last_header_click: Local<Option<(Entity, f64)>>,
+) {
+    let Some(bounds) = cameras
+        .iter()
+        .next()
+        .and_then(|(camera, transform)| viewport_world_bounds(camera, transform))
+    else {
+        return;
+    };
+    let mut toggle_targets: Vec<Entity> = buttons
+        .iter()
+        .filter(|(_, clickable)| clickable.triggered)
+        .map(|(button, _)| button.root)
+        .collect();
+
+    // Double-click on the header drag region also toggles.
+    if mouse.just_pressed(MouseButton::Left) {
+        let now = time.elapsed_secs_f64();
+        for (entity, _, _, transform, region, _, editable_title) in &roots {
+            // Editable-title headers give their double-click to renaming.
+            if editable_title {
+                continue;
+            }
+            let centre = transform.translation.truncate() + region.offset;
+            let half = region.dimensions * 0.5;
+            let inside = (cursor.position.x - centre.x).abs() <= half.x
+                && (cursor.position.y - centre.y).abs() <= half.y;
+            if !inside {
+                continue;
+            }
+            if let Some((last_entity, at)) = *last_header_click {
+                if last_entity == entity && now - at < 0.35 {
+                    toggle_targets.push(entity);
+                    *last_header_click = None;
+                    break;
+                }
+            }
+            *last_header_click = Some((entity, now));
+            break;
+        }
+    }
+
+    for target in toggle_targets {
+        let Ok((entity, mut window, metrics, mut transform, _, restore, _)) =
+            roots.get_mut(target)
+        else {
+            continue;
+        };
+        toggle_window_maximize(
+            &mut commands,
+            entity,
+            &mut window,
+            metrics,
+            &mut transform,
+            restore,
+            bounds,
+        );
+    }
+}
+
+/// Click handling for the collapse toggle, plus hiding the scroll
+/// machinery while collapsed.
+pub fn handle_window_collapse(
+    mut commands: Commands,
+    buttons: Query<(&WindowCollapseButton, &Clickable)>,
+    mut roots: Query<(&mut Window, Option<&WindowCollapsed>, &WindowScrollRuntime)>,
+    mut visibilities: Query<&mut Visibility>,
+    scrollbars: Query<(Entity, &crate::ui::scroll::ScrollBar)>,
+) {
+    for (button, clickable) in &buttons {
+        if !clickable.triggered {
+            continue;
+        }
+        let Ok((mut window, collapsed, runtime)) = roots.get_mut(button.root) else {
+            continue;
+        };
+        let collapsing = collapsed.is_none();
+        if collapsing {
+            commands.entity(button.root).insert(WindowCollapsed {
+                restored_dimensions: window.boundary.dimensions,
+            });
+        } else if let Some(collapsed) = collapsed {
+            window.boundary.dimensions = collapsed.restored_dimensions;
+            commands.entity(button.root).remove::<WindowCollapsed>();
+        }
+        let scroll_visibility = if collapsing {
+            Visibility::Hidden
+        } else {
+            Visibility::Inherited
+        };
+        if let Ok(mut visibility) = visibilities.get_mut(runtime.scroll_root) {
+            *visibility = scroll_visibility;
+        }
+        for (entity, bar) in &scrollbars {
+            if bar.root == runtime.scroll_root {
+                if let Ok(mut visibility) = visibilities.get_mut(entity) {
+                    *visibility = scroll_visibility;
+                }
+            }
+        }
+    }
+}
+
+// --- Fade transitions -----------------------------------------------------
+
+/// Opt-in fade timings for a window root. Spawn fades the chrome and
+/// content in; a close request fades out before the actual despawn.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct WindowTransition {
+    pub fade_in_secs: f32,
+    pub fade_out_secs: f32,
+}
+
+impl Default for WindowTransition {
+    fn default() -> Self {
+        Self {
+            fade_in_secs: 0.15,
+            fade_out_secs: 0.12,
+        }
+    }
+}
+
+/// A window mid fade-in.
+#[derive(Component, Debug, Clone, Copy, Default)]
+struct WindowFadingIn {
+    elapsed_secs: f32,
+}
+
+/// A window fading towards its despawn. Interaction-wise the window is
+/// already gone: the request that started this fade came through
+/// [`DespawnRequested`], so nothing else waits on it.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct WindowClosing {
+    elapsed_secs: f32,
+}
+
+/// Alpha a faded child had before the fade touched it, restored when a
+/// fade-in completes.
+#[derive(Component, Debug, Clone, Copy)]
+struct FadeBaseAlpha(f32);
+
+/// Converts close requests on transitioned windows into fade-outs
+/// before `finalize_requested_despawns` would consume them.
+fn intercept_window_despawns(
+    mut commands: Commands,
+    requested: Query<
+        Entity,
+        (
+            With<crate::systems::interaction::DespawnRequested>,
+            With<WindowTransition>,
+            With<Window>,
+            Without<WindowClosing>,
+        ),
+    >,
+) {
+    for entity in &requested {
+        commands
+            .entity(entity)
+            .remove::<crate::systems::interaction::DespawnRequested>()
+            .insert(WindowClosing::default());
+    }
+}
+
+fn begin_window_fade_ins(
+    mut commands: Commands,
+    new_roots: Query<Entity, (Added<Window>, With<WindowTransition>)>,
+) {
+    for entity in &new_roots {
+        commands.entity(entity).insert(WindowFadingIn::default());
+    }
+}
+
+/// Multiplies the whole window subtree's sprite and text alpha by `t`,
+/// capturing each child's resting alpha so a completed fade-in can put
+/// it back. Covers chrome, tab rows, scroll surfaces and routed content
+/// alike, since they are all descendants of the root.
+#[allow(clippy::too_many_arguments)]
+fn apply_fade_alpha(
+    commands: &mut Commands,
+    root: Entity,
+    t: f32,
+    restore: bool,
+    children: &Query<&Children>,
+    sprites: &mut Query<&mut Sprite>,
+    texts: &mut Query<&mut TextColor>,
+    bases: &Query<&FadeBaseAlpha>,
+) {
+    let mut stack = vec![root];
+    while let Some(entity) = stack.pop() {
+        if let Ok(descendants) = children.get(entity) {
+            stack.extend(descendants.iter());
+        }
+        let base = bases.get(entity).ok().map(|base| base.0);
+        if let Ok(mut sprite) = sprites.get_mut(entity) {
+            let base = base.unwrap_or_else(|| {
+                let alpha = sprite.color.alpha();
+                commands.entity(entity).insert(FadeBaseAlpha(alpha));
+                alpha
+            });
+            sprite.color.set_alpha(base * t);
+        }
+        if let Ok(mut text) = texts.get_mut(entity) {
+            let base = base.unwrap_or_else(|| {
+                let alpha = text.0.alpha();
+                commands.entity(entity).insert(FadeBaseAlpha(alpha));
+                alpha
+            });
+            text.0.set_alpha(base * t);
+        }
+        if restore {
+            commands.entity(entity).remove::<FadeBaseAlpha>();
+        }
+    }
+}
+
+/// Drives both fade directions: fade-ins shed their marker when done,
+/// fade-outs despawn the root.
+#[allow(clippy::too_many_arguments)]
+fn animate_window_fades(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut fading_in: Query<(Entity, &WindowTransition, &mut WindowFadingIn), Without<WindowClosing>>,
+    mut closing: Query<(Entity, &WindowTransition, &mut WindowClosing)>,
+    children: Query<&Children>,
+    mut sprites: Query<&mut Sprite>,
+    mut texts: Query<&mut TextColor>,
+    bases: Query<&FadeBaseAlpha>,
+) {
+    let delta = time.delta_secs();
+    for (entity, transition, mut fade) in &mut fading_in {
+        fade.elapsed_secs += delta;
+        let t = if transition.fade_in_secs <= 0.0 {
+            1.0
+        } else {
+            (fade.elapsed_secs / transition.fade_in_secs).clamp(0.0, 1.0)
+        };
+        let done = t >= 1.0;
+        apply_fade_alpha(
+            &mut commands,
+            entity,
+            t,
+            done,
+            &children,
+            &mut sprites,
+            &mut texts,
+            &bases,
+        );
+        if done {
+            commands.entity(entity).remove::<WindowFadingIn>();
+        }
+    }
+    for (entity, transition, mut fade) in &mut closing {
+        fade.elapsed_secs += delta;
+        let t = if transition.fade_out_secs <= 0.0 {
+            0.0
+        } else {
+            (1.0 - fade.elapsed_secs / transition.fade_out_secs).clamp(0.0, 1.0)
+        };
+        if t <= 0.0 {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        apply_fade_alpha(
+            &mut commands,
+            entity,
+            t,
+            false,
+            &children,
+            &mut sprites,
+            &mut texts,
+            &bases,
+        );
+    }
+}
+
+// --- Cascade placement ----------------------------------------------------
+
+/// Diagonal offset applied to successive cascaded windows, and how far
+/// from the viewport edge the run wraps back to its start.
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct WindowCascadeConfig {
+    pub step: Vec2,
+    pub margin: f32,
+    next_slot: u32,
+}
+
+impl Default for WindowCascadeConfig {
+    fn default() -> Self {
+        Self {
+            step: Vec2::new(28.0, -28.0),
+            margin: 24.0,
+            next_slot: 0,
+        }
+    }
+}
+
+/// Opt-in marker: position this window root in the running cascade
+/// instead of wherever its spawn transform pointed. Useful for debug
+/// windows that would otherwise stack exactly on top of each other.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct WindowCascade;
+
+/// Top-left anchored position of cascade slot `slot`, wrapping once the
+/// diagonal run would push the window within `margin` of the viewport
+/// edge.
+pub fn cascade_position(slot: u32, step: Vec2, margin: f32, outer: Vec2, bounds: Rect) -> Vec2 {
+    let half = outer * 0.5;
+    let start = Vec2::new(
+        bounds.min.x + margin + half.x,
+        bounds.max.y - margin - half.y,
+    );
+    let available = Vec2::new(
+        (bounds.max.x - margin - half.x - start.x).max(0.0),
+        (start.y - (bounds.min.y + margin + half.y)).max(0.0),
+    );
+    let capacity_x = if step.x.abs() > f32::EPSILON {
+        (available.x / step.x.abs()) as u32 + 1
+    } else {
+        u32::MAX
+    };
+    let capacity_y = if step.y.abs() > f32::EPSILON {
+        (available.y / step.y.abs()) as u32 + 1
+    } else {
+        u32::MAX
+    };
+    let wrapped = slot % capacity_x.min(capacity_y).max(1);
+    start + step * wrapped as f32
+}
+
+/// Places freshly spawned cascaded windows, restarting the run once all
+/// cascaded windows are gone (mirroring the stack-order baseline reset).
+pub fn apply_cascade_placement(
+    mut config: ResMut<WindowCascadeConfig>,
+    cameras: Query<(&Camera, &GlobalTransform), With<OffscreenCamera>>,
+    mut new_roots: Query<(&Window, &mut Transform), (Added<WindowCascade>, With<Window>)>,
+    live: Query<(), (With<WindowCascade>, With<Window>)>,
+) {
+    if live.is_empty() {
+        config.next_slot = 0;
+        return;
+    }
+    let Some(bounds) = cameras
+        .single()
+        .ok()
+        .and_then(|(camera, transform)| viewport_world_bounds(camera, transform))
+    else {
+        return;
+    };
+    for (window, mut transform) in &mut new_roots {
+        let position = cascade_position(
+            config.next_slot,
+            config.step,
+            config.margin,
+            window.outer_size(),
+            bounds,
+        );
+        transform.translation.x = position.x;
+        transform.translation.y = position.y;
+        config.next_slot += 1;
+    }
+}
+
+/// Keyboard shortcuts for dismissing windows. The base key combines
+/// with Ctrl for the focused window and Ctrl+Shift for every window.
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct WindowCloseShortcuts {
+    pub key: KeyCode,
+}
+
+impl Default for WindowCloseShortcuts {
+    fn default() -> Self {
+        Self { key: KeyCode::KeyW }
+    }
+}
+
+/// Ctrl+W closes the focused window, Ctrl+Shift+W closes all of them,
+/// through the same [`InputAction::Despawn`] path as the close button.
+/// Windows without a close button are skipped either way, and
+/// [`assign_stack_order`]'s baseline reset takes care of
+/// `WindowZStack::next_order` once the last window is gone.
+pub fn close_windows_with_keyboard(
+    mut commands: Commands,
+    keys: Res<ButtonInput<KeyCode>>,
+    shortcuts: Res<WindowCloseShortcuts>,
+    state: Res<UiInteractionState>,
+    mut next_main: ResMut<NextState<crate::data::states::MainState>>,
+    roots: Query<(Entity, &Window)>,
+) {
+    let ctrl = keys.pressed(KeyCode::ControlLeft) || keys.pressed(KeyCode::ControlRight);
+    if !ctrl || !keys.just_pressed(shortcuts.key) || state.text_input_focus.is_some() {
+        return;
+    }
+    let all = keys.pressed(KeyCode::ShiftLeft) || keys.pressed(KeyCode::ShiftRight);
+    let actions: Vec<InputAction> = roots
+        .iter()
+        .filter(|(entity, window)| {
+            window.has_close_button && (all || state.focused_owner == Some(*entity))
+        })
+        .map(|(entity, _)| InputAction::Despawn(entity))
+        .collect();
+    run_input_actions(&mut commands, &mut next_main, &actions);
+}
+
+// --- Modal windows --------------------------------------------------------
+
+/// Marks a window root as modal: interaction focus is pinned to it and
+/// a dim backdrop covers everything beneath, so
+/// [`window_interaction_allowed`] (and every `UiInputPolicy::ScopedTo`
+/// check) rejects the rest of the UI until the modal closes.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct WindowModal;
+
+/// The dim sheet behind a modal. Carries the focus to restore when the
+/// modal closes, since the modal root itself is gone by then.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct ModalBackdrop {
+    pub modal: Entity,
+    restore_focus: Option<Entity>,
+}
+
+const MODAL_BACKDROP_COLOR: Color = Color::srgba(0.0, 0.0, 0.0, 0.6);
+
+/// Spawns a backdrop for each fresh modal, remembering the focus it
+/// displaced.
+pub fn activate_window_modals(
+    mut commands: Commands,
+    mut state: ResMut<UiInteractionState>,
+    cameras: Query<(&Camera, &GlobalTransform), With<OffscreenCamera>>,
+    new_modals: Query<Entity, Added<WindowModal>>,
+) {
+    for modal in &new_modals {
+        let size = cameras
+            .single()
+            .ok()
+            .and_then(|(camera, transform)| viewport_world_bounds(camera, transform))
+            .map(|bounds| bounds.size())
+            .unwrap_or(Vec2::new(1920.0, 1080.0));
+        commands.spawn((
+            ModalBackdrop {
+                modal,
+                restore_focus: state.focused_owner.filter(|owner| *owner != modal),
+            },
+            Sprite {
+                color: MODAL_BACKDROP_COLOR,
+                custom_size: Some(size * 1.5),
+                ..default()
+            },
+            Transform::default(),
+        ));
+        state.focused_owner = Some(modal);
+    }
+}
+
+/// Keeps each backdrop just beneath its modal in depth, pins focus to
+/// the topmost live modal (pointer raises cannot steal it), and on
+/// close despawns the backdrop and hands focus back to whoever held it.
+pub fn sync_modal_backdrops(
+    mut commands: Commands,
+    mut state: ResMut<UiInteractionState>,
+    mut backdrops: Query<(Entity, &ModalBackdrop, &mut Transform), Without<Window>>,
+    modals: Query<&Transform, (With<WindowModal>, With<Window>)>,
+    roots: Query<Entity, With<Window>>,
+) {
+    let mut top_modal: Option<(Entity, f32)> = None;
+    for (entity, backdrop, mut transform) in &mut backdrops {
+        let Ok(modal_transform) = modals.get(backdrop.modal) else {
+            // Modal closed: restore the displaced focus if it survives.
+            if state.focused_owner == Some(backdrop.modal) {
+                state.focused_owner =
+                    backdrop.restore_focus.filter(|owner| roots.get(*owner).is_ok());
+            }
+            commands.entity(entity).despawn();
+            continue;
+        };
+        transform.translation.z = modal_transform.translation.z - WINDOW_Z_STEP * 0.5;
+        if top_modal.is_none_or(|(_, z)| modal_transform.translation.z > z) {
+            top_modal = Some((backdrop.modal, modal_transform.translation.z));
+        }
+    }
+    if let Some((modal, _)) = top_modal {
+        if state.focused_owner != Some(modal) {
+            state.focused_owner = Some(modal);
+        }
+    }
+}
+
+/// Drops focus when the focused window root disappears.
+pub fn clear_dead_focus(
+    mut state: ResMut<UiInteractionState>,
+    roots: Query<Entity, With<Window>>,
+) {
+    if let Some(owner) = state.focused_owner {
+        if roots.get(owner).is_err() {
+            state.focused_owner = None;
+        }
+    }
+}
+
+// --- Keyboard move/resize -------------------------------------------------
+
+/// Repeat timers for held keyboard window nudging, one per arrow so
+/// diagonal movement repeats correctly.
+#[derive(Resource)]
+pub struct WindowKeyboardNav {
+    repeats: [RepeatTimer; 4],
+}
+
+impl Default for WindowKeyboardNav {
+    fn default() -> Self {
+        Self {
+            repeats: [
+                RepeatTimer::menu_nav(),
+                RepeatTimer::menu_nav(),
+                RepeatTimer::menu_nav(),
+                RepeatTimer::menu_nav(),
+            ],
+        }
+    }
+}
+
+const ARROW_KEYS: [(KeyCode, Vec2); 4] = [
+    (KeyCode::ArrowLeft, Vec2::new(-1.0, 0.0)),
+    (KeyCode::ArrowRight, Vec2::new(1.0, 0.0)),
+    (KeyCode::ArrowUp, Vec2::new(0.0, 1.0)),
+    (KeyCode::ArrowDown, Vec2::new(0.0, -1.0)),
+];
+
+/// Applies a keyboard nudge to a window translation, clamped to bounds.
+pub fn keyboard_nudged_translation(
+    mut translation: Vec3,
+    direction: Vec2,
+    outer: Vec2,
+    outer_centre_offset: Vec2,
+    bounds: Rect,
+) -> Vec3 {
+    translation.x += direction.x * WINDOW_KEYBOARD_MOVE_STEP;
+    translation.y += direction.y * WINDOW_KEYBOARD_MOVE_STEP;
+    let mut outer_centre = translation + outer_centre_offset.extend(0.0);
+    clamp_to_viewport(&mut outer_centre, outer, bounds);
+    outer_centre - outer_centre_offset.extend(0.0)
+}
+
+/// Applies a keyboard resize step to window dimensions, clamped to the
+/// window's metrics. Right/up grow, left/down shrink.
+pub fn keyboard_resized_dimensions(
+    dimensions: Vec2,
+    direction: Vec2,
+    metrics: &WindowContentMetrics,
+) -> Vec2 {
+    let mut resized = dimensions
+        + Vec2::new(direction.x, direction.y) * WINDOW_KEYBOARD_RESIZE_STEP;
+    resized = resized.max(metrics.min_inner);
+    if let Some(max_inner) = metrics.max_inner {
+        resized = resized.min(max_inner);
+    }
+    resized
+}
+
+/// Alt+Arrows nudge the focused window; Alt+Shift+Arrows resize it. Held
+/// keys repeat at the menu-navigation cadence. Inactive while a text
+/// field owns typing or no window holds focus.
+pub fn handle_window_keyboard_move_resize(
+    time: Res<Time>,
+    keys: Res<ButtonInput<KeyCode>>,
+    state: Res<UiInteractionState>,
+    mut nav: ResMut<WindowKeyboardNav>,
+    cameras: Query<(&Camera, &GlobalTransform), With<OffscreenCamera>>,
+    mut roots: Query<(
+        &mut Window,
+        &WindowContentMetrics,
+        &mut Transform,
+        Option<&WindowContentPadding>,
+    )>,
+) {
+    let alt = keys.pressed(KeyCode::AltLeft) || keys.pressed(KeyCode::AltRight);
+    if !alt || state.text_input_focus.is_some() {
+        return;
+    }
+    let Some(owner) = state.focused_owner else {
+        return;
+    };
+    let Ok((mut window, metrics, mut transform, padding)) = roots.get_mut(owner) else {
+        return;
+    };
+    let metrics = padding.copied().unwrap_or_default().padded_metrics(metrics);
+    let resizing = keys.pressed(KeyCode::ShiftLeft) || keys.pressed(KeyCode::ShiftRight);
+    let bounds = cameras
+        .iter()
+        .next()
+        .and_then(|(camera, camera_transform)| viewport_world_bounds(camera, camera_transform));
+
+    for (index, (key, direction)) in ARROW_KEYS.into_iter().enumerate() {
+        let fired = nav.repeats[index].tick(
+            keys.pressed(key),
+            keys.just_pressed(key),
+            time.delta_secs(),
+        );
+        if !fired || !keys.pressed(key) {
+            continue;
+        }
+        if resizing {
+            window.boundary.dimensions =
+                keyboard_resized_dimensions(window.boundary.dimensions, direction, &metrics);
+        } else if let Some(bounds) = bounds {
+            transform.translation = keyboard_nudged_translation(
+                transform.translation,
+                direction,
+                window.outer_size(),
+                window.outer_centre_offset(),
+                bounds,
+            );
+        } else {
+            transform.translation.x += direction.x * WINDOW_KEYBOARD_MOVE_STEP;
+            transform.translation.y += direction.y * WINDOW_KEYBOARD_MOVE_STEP;
+        }
+    }
+}
+
+/// Ordering anchors for window processing within `Update`.
+#[derive(SystemSet, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WindowSystem {
+    /// Pointer/keyboard intent capture (raise, resize grab, nudge).
+    Input,
+    /// Geometry resolution (constraints, clamping, drag bounds).
+    Resolve,
+    /// Visual sync of chrome and scroll geometry.
+    Visuals,
+}
+
+pub struct WindowPlugin;
+
+impl Plugin for WindowPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<WindowZStack>()
+            .init_resource::<WindowCloseShortcuts>()
+            .init_resource::<WindowCascadeConfig>()
+            .add_event::<tabs::WindowTabClosed>()
+            .add_event::<tabs::WindowTabReordered>()
+            .init_resource::<WindowSnapConfig>()
+            .init_resource::<ActiveWindowInteraction>()
+            .init_resource::<WindowKeyboardNav>()
+            .add_systems(PreStartup, layout::load_window_layout_store)
+            .add_systems(
+                Update,
+                (layout::record_window_layouts, layout::flush_window_layouts)
+                    .chain()
+                    .after(WindowSystem::Resolve),
+            )
+            .add_plugins((crate::ui::shapes::ShapesPlugin, crate::ui::scroll::ScrollPlugin))
+            .configure_sets(
+                Update,
+                (
+                    WindowSystem::Input,
+                    WindowSystem::Resolve,
+                    WindowSystem::Visuals,
+                )
+                    .chain()
+                    .after(InteractionSystem::Sense),
+            )
+            .add_systems(
+                Update,
+                (
+                    raise_window_on_pointer_down,
+                    cycle_window_focus,
+                    close_windows_with_keyboard,
+                    handle_window_resize,
+                    handle_window_maximize,
+                    handle_window_keyboard_move_resize,
+                    begin_title_edits,
+                    capture_title_edit_input,
+                    tabs::handle_tab_close_clicks,
+                    tabs::handle_tab_clicks,
+                    tabs::handle_tab_drag_lifecycle,
+                    tabs::handle_tab_drag_reorder,
+                )
+                    .chain()
+                    .in_set(WindowSystem::Input),
+            )
+            .add_systems(
+                Update,
+                (
+                    assign_stack_order,
+                    apply_cascade_placement,
+                    clear_dead_focus,
+                    activate_window_modals,
+                    sync_modal_backdrops,
+                    resolve_constraints,
+                    sync_root_drag_bounds,
+                    snap_dragged_windows,
+                    clamp_windows_to_viewport,
+                    route_window_content,
+                )
+                    .chain()
+                    .in_set(WindowSystem::Resolve),
+            )
+            .add_systems(
+                Update,
+                (
+                    handle_window_collapse,
+                    sync_scroll_runtime_geometry,
+                    update_window_visuals,
+                    tabs::sync_tab_row_layout,
+                    tabs::animate_tab_cell_positions,
+                    tabs::sync_tab_row_visuals,
+                    tabs::sync_tabbed_content_visibility,
+                    // Fades multiply whatever the visual pass wrote, so
+                    // they come last in the set.
+                    begin_window_fade_ins,
+                    intercept_window_despawns,
+                    animate_window_fades,
+                )
+                    .chain()
+                    .in_set(WindowSystem::Visuals),
+            );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bounds() -> Rect {
+        Rect::from_center_size(Vec2::ZERO, Vec2::new(800.0, 600.0))
+    }
+
+    #[test]
+    fn cascade_steps_diagonally_and_wraps_at_the_edge() {
+        let step = Vec2::new(28.0, -28.0);
+        let outer = Vec2::new(200.0, 100.0);
+        let first = cascade_position(0, step, 24.0, outer, bounds());
+        let second = cascade_position(1, step, 24.0, outer, bounds());
+        assert_eq!(second - first, step);
+        // A deep slot wraps back onto an earlier position rather than
+        // walking off-screen.
+        let deep = cascade_position(40, step, 24.0, outer, bounds());
+        assert!(deep.x + outer.x * 0.5 <= bounds().max.x - 24.0 + 1e-3);
+        assert!(deep.y - outer.y * 0.5 >= bounds().min.y + 24.0 - 1e-3);
+    }
+
+    #[test]
+    fn focus_cycle_starts_at_the_top_and_wraps() {
+        assert_eq!(next_focus_index(None, 3, false), Some(2));
+        assert_eq!(next_focus_index(Some(2), 3, false), Some(0));
+        assert_eq!(next_focus_index(Some(2), 3, true), Some(1));
+        assert_eq!(next_focus_index(Some(0), 3, true), Some(2));
+        assert_eq!(next_focus_index(None, 0, false), None);
+    }
+
+    #[test]
+    fn clipped_windows_do_not_grow_to_oversized_content() {
+        let metrics = WindowContentMetrics::default();
+        let oversized = Vec2::new(1000.0, 800.0);
+        assert_eq!(
+            content_constrained_inner(WindowOverflowPolicy::ClipReserved, oversized, &metrics),
+            None,
+        );
+        // The constraining policy still tracks content.
+        assert_eq!(
+            content_constrained_inner(
+                WindowOverflowPolicy::ConstrainToContent,
+                oversized,
+                &metrics,
+            ),
+            Some(oversized),
+        );
+    }
+
+    #[test]
+    fn stacked_and_raised_windows_never_share_a_z() {
+        let mut stack = WindowZStack::default();
+        let mut zs: Vec<f32> = Vec::new();
+        // Three spawns, then several raise cycles: raising reuses the
+        // counter, so orders keep climbing.
+        for _ in 0..3 {
+            let order = stack.next_order;
+            stack.next_order += 1;
+            zs.push(stack.z_for_order(order));
+        }
+        for _ in 0..5 {
+            let order = stack.next_order;
+            stack.next_order += 1;
+            zs.push(stack.z_for_order(order) + WINDOW_FOCUS_DEPTH_SPAN);
+        }
+        // Everything sits above the scene band, and no two z collide.
+        for (index, a) in zs.iter().enumerate() {
+            assert!(*a > SCENE_SPRITE_Z_MAX);
+            for b in &zs[index + 1..] {
+                assert!((a - b).abs() > f32::EPSILON, "{a} collides with {b}");
+            }
+        }
+    }
+
+    #[test]
+    fn padding_shrinks_the_viewport_and_raises_the_minimum() {
+        // top/right/bottom/left.
+        let padding = WindowContentPadding(Vec4::new(8.0, 4.0, 8.0, 4.0));
+        assert_eq!(padding.total(), Vec2::new(8.0, 16.0));
+        assert_eq!(padding.origin_offset(), Vec2::new(4.0, -8.0));
+        let padded = padding.padded_metrics(&WindowContentMetrics::default());
+        assert_eq!(
+            padded.min_inner,
+            WindowContentMetrics::default().min_inner + Vec2::new(8.0, 16.0)
+        );
+        // The zero default changes nothing.
+        assert_eq!(WindowContentPadding::default().total(), Vec2::ZERO);
+        assert_eq!(WindowContentPadding::default().origin_offset(), Vec2::ZERO);
+    }
+
+    #[test]
+    fn snap_picks_the_nearest_edge_within_threshold() {
+        let delta = best_snap_delta(98.0, [100.0, 90.0, 400.0].into_iter(), 10.0);
+        assert_eq!(delta, Some(2.0));
+        assert_eq!(best_snap_delta(98.0, [400.0].into_iter(), 10.0), None);
+    }
+
+    #[test]
+    fn keyboard_move_shifts_by_step() {
+        let translation = keyboard_nudged_translation(
+            Vec3::ZERO,
+            Vec2::new(1.0, 0.0),
+            Vec2::new(200.0, 100.0),
+            Vec2::new(0.0, 11.0),
+            bounds(),
+        );
+        assert_eq!(translation.x, WINDOW_KEYBOARD_MOVE_STEP);
+        assert_eq!(translation.y, 0.0);
+    }
+
+    #[test]
+    fn keyboard_move_stays_within_viewport() {
+        // Start flush against the right edge; a further nudge is a no-op.
+        let start = Vec3::new(300.0, 0.0, 0.0);
+        let outer = Vec2::new(200.0, 100.0);
+        let mut at_edge = start;
+        clamp_to_viewport(&mut at_edge, outer, bounds());
+        let nudged = keyboard_nudged_translation(
+            at_edge,
+            Vec2::new(1.0, 0.0),
+            outer,
+            Vec2::ZERO,
+            bounds(),
+        );
+        assert_eq!(nudged.x, at_edge.x);
+    }
+
+    #[test]
+    fn top_corner_resize_anchors_the_bottom_edge() {
+        let mut window = Window::default();
+        window.boundary.dimensions = Vec2::new(200.0, 100.0);
+        let metrics = WindowContentMetrics::default();
+        // Root at origin: bottom edge at y = -50, right edge at x = 100.
+        let state = ActiveWindowResizeState {
+            root: Entity::PLACEHOLDER,
+            corner: ResizeCorner::TopRight,
+            fixed_y_world: -50.0,
+            fixed_x_world: -100.0,
+        };
+        let mut translation = Vec3::ZERO;
+        // Drag the top-right corner up and out.
+        window.enact_resize(&metrics, &state, Vec2::new(140.0, 90.0), &mut translation);
+        assert_eq!(window.boundary.dimensions, Vec2::new(240.0, 140.0));
+        // Bottom edge unchanged; the centre (and header above it) moved up.
+        assert_eq!(translation.y - window.boundary.dimensions.y * 0.5, -50.0);
+        assert_eq!(translation.y, 20.0);
+    }
+
+    #[test]
+    fn edge_resize_changes_only_the_grabbed_axis() {
+        let mut window = Window::default();
+        window.boundary.dimensions = Vec2::new(200.0, 100.0);
+        let metrics = WindowContentMetrics::default();
+        let state = ActiveWindowEdgeResizeState {
+            root: Entity::PLACEHOLDER,
+            edge: ResizeEdge::Right,
+            fixed_world: -100.0,
+        };
+        let mut translation = Vec3::ZERO;
+        window.enact_edge_resize(&metrics, &state, Vec2::new(160.0, -400.0), &mut translation);
+        assert_eq!(window.boundary.dimensions, Vec2::new(260.0, 100.0));
+        assert_eq!(translation.y, 0.0);
+    }
+
+    #[test]
+    fn corners_take_precedence_over_edges() {
+        let mut window = Window::default();
+        window.boundary.dimensions = Vec2::new(200.0, 100.0);
+        // Directly on the bottom-right corner: edge hit must yield None.
+        let corner = Vec2::new(100.0, -50.0);
+        assert!(window.is_cursor_over_corner(corner, Vec2::ZERO).is_some());
+        assert!(window.is_cursor_over_edge(corner, Vec2::ZERO).is_none());
+        // Mid-right edge: edge hit, no corner.
+        let edge = Vec2::new(100.0, 0.0);
+        assert!(window.is_cursor_over_corner(edge, Vec2::ZERO).is_none());
+        assert_eq!(
+            window.is_cursor_over_edge(edge, Vec2::ZERO),
+            Some(ResizeEdge::Right)
+        );
+    }
+
+    #[test]
+    fn centred_content_offsets_by_half_the_slack() {
+        let align = WindowContentAlign {
+            horizontal: HorizontalContentAlign::Centre,
+            vertical: VerticalContentAlign::Centre,
+        };
+        let offset =
+            content_alignment_offset(Vec2::new(400.0, 300.0), Vec2::new(200.0, 100.0), &align);
+        assert_eq!(offset, Vec2::new(100.0, -100.0));
+    }
+
+    #[test]
+    fn overflowing_content_falls_back_to_scroll_origin() {
+        let align = WindowContentAlign {
+            horizontal: HorizontalContentAlign::Centre,
+            vertical: VerticalContentAlign::Bottom,
+        };
+        let offset =
+            content_alignment_offset(Vec2::new(200.0, 100.0), Vec2::new(400.0, 300.0), &align);
+        assert_eq!(offset, Vec2::ZERO);
+    }
+
+    #[test]
+    fn default_alignment_is_top_left() {
+        let offset = content_alignment_offset(
+            Vec2::new(400.0, 300.0),
+            Vec2::new(200.0, 100.0),
+            &WindowContentAlign::default(),
+        );
+        assert_eq!(offset, Vec2::ZERO);
+    }
+
+    #[test]
+    fn keyboard_resize_clamps_to_metrics() {
+        let metrics = WindowContentMetrics {
+            min_inner: Vec2::new(100.0, 80.0),
+            max_inner: Some(Vec2::new(120.0, 200.0)),
+        };
+        let grown = keyboard_resized_dimensions(
+            Vec2::new(110.0, 100.0),
+            Vec2::new(1.0, 0.0),
+            &metrics,
+        );
+        assert_eq!(grown.x, 120.0);
+        let shrunk = keyboard_resized_dimensions(
+            Vec2::new(110.0, 100.0),
+            Vec2::new(-1.0, -1.0),
+            &metrics,
+        );
+        assert_eq!(shrunk.x, 100.0);
+    }
+}
@@ -0,0 +1,446 @@
+use bevy::prelude::*;
+
+use crate::{
+    systems::{
+        colors::{DIM_COLOR, HIGHLIGHT_COLOR, PRIMARY_COLOR, WINDOW_BODY_COLOR},
+        interaction::{Clickable, CustomCursor},
+    },
+    ui::shapes::{BorderedRectangle, Plus},
+};
+
+pub const TAB_HEIGHT: f32 = 20.0;
+pub const TAB_GAP: f32 = 2.0;
+/// Speed at which displaced tab cells slide to their new slot.
+pub const TAB_REORDER_EASE_PX_S: f32 = 480.0;
+
+const TAB_LABEL_FONT_SIZE: f32 = 12.0;
+const TAB_CLOSE_SIZE: f32 = 6.0;
+const TAB_BORDER_THICKNESS: f32 = 1.0;
+
+/// A row of selectable tabs, centred on its entity. Cells are rebuilt
+/// whenever the component changes, so labels may be added or removed at
+/// runtime; the active index lives in the required [`TabBarState`].
+#[derive(Component, Debug, Clone)]
+#[require(Transform, Visibility, TabBarState)]
+pub struct WindowTabRow {
+    pub labels: Vec<String>,
+    pub tab_width: f32,
+    /// When set, each tab carries a small close cross that removes it and
+    /// emits [`WindowTabClosed`].
+    pub closable: bool,
+}
+
+impl WindowTabRow {
+    pub fn new(labels: Vec<String>, tab_width: f32) -> Self {
+        Self {
+            labels,
+            tab_width,
+            closable: false,
+        }
+    }
+
+    pub fn closable(mut self) -> Self {
+        self.closable = true;
+        self
+    }
+
+    /// Width of the whole row for the current tab count.
+    pub fn total_width(&self) -> f32 {
+        let count = self.labels.len();
+        if count == 0 {
+            return 0.0;
+        }
+        count as f32 * self.tab_width + (count - 1) as f32 * TAB_GAP
+    }
+
+    /// Centre x of the tab at `index`, relative to the row entity.
+    pub fn tab_centre_x(&self, index: usize) -> f32 {
+        -self.total_width() * 0.5
+            + self.tab_width * 0.5
+            + index as f32 * (self.tab_width + TAB_GAP)
+    }
+}
+
+/// Which tab in a [`WindowTabRow`] is active.
+#[derive(Component, Debug, Default, Clone, Copy)]
+pub struct TabBarState {
+    pub active: usize,
+}
+
+/// Keeps the active index valid after tabs are removed.
+pub fn clamp_active_tab(state: &mut TabBarState, len: usize) {
+    state.active = state.active.min(len.saturating_sub(1));
+}
+
+/// Fired when a closable tab's cross is clicked, after the label has been
+/// removed from the row.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct WindowTabClosed {
+    pub tab_root: Entity,
+    pub index: usize,
+}
+
+/// Fired after a drag carries one tab past another.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct WindowTabReordered {
+    pub tab_root: Entity,
+    pub from: usize,
+    pub to: usize,
+}
+
+#[derive(Component)]
+struct TabCell {
+    row: Entity,
+    index: usize,
+}
+
+/// In-flight drag on a tab cell. `anchor_x` is the cursor x the drag is
+/// measured from; it advances one slot per swap so each further swap
+/// needs another half tab width of travel.
+#[derive(Component, Debug, Clone, Copy)]
+struct TabDrag {
+    anchor_x: f32,
+}
+
+#[derive(Component)]
+struct TabCloseGlyph {
+    row: Entity,
+    index: usize,
+}
+
+/// Rebuilds the cell entities of changed rows: one bordered cell, label
+/// and (optionally) close cross per remaining tab, positioned so the row
+/// stays centred as its `total_width` changes.
+pub fn sync_tab_row_layout(
+    mut commands: Commands,
+    rows: Query<(Entity, &WindowTabRow, Option<&Children>), Changed<WindowTabRow>>,
+    cells: Query<(), With<TabCell>>,
+) {
+    for (entity, row, children) in &rows {
+        if let Some(children) = children {
+            for child in children.iter() {
+                if cells.get(child).is_ok() {
+                    commands.entity(child).despawn();
+                }
+            }
+        }
+        for (index, label) in row.labels.iter().enumerate() {
+            let cell = commands
+                .spawn((
+                    TabCell { row: entity, index },
+                    BorderedRectangle {
+                        dimensions: Vec2::new(row.tab_width, TAB_HEIGHT),
+                        border_thickness: TAB_BORDER_THICKNESS,
+                        border_color: DIM_COLOR,
+                        fill_color: WINDOW_BODY_COLOR,
+                    },
+                    Clickable::new(Vec2::new(row.tab_width, TAB_HEIGHT)),
+                    Transform::from_xyz(row.tab_centre_x(index), 0.0, 0.2),
+                    ChildOf(entity),
+                ))
+                .id();
+            commands.spawn((
+                Text2d::new(label.clone()),
+                TextFont::from_font_size(TAB_LABEL_FONT_SIZE),
+                TextColor(PRIMARY_COLOR),
+                Transform::from_xyz(0.0, 0.0, 0.2),
+                ChildOf(cell),
+            ));
+            if row.closable {
+                commands.spawn((
+                    TabCloseGlyph { row: entity, index },
+                    Plus {
+                        size: TAB_CLOSE_SIZE,
+                        thickness: 1.5,
+                        color: PRIMARY_COLOR,
+                    },
+                    Clickable::new(Vec2::splat(TAB_CLOSE_SIZE + 4.0)),
+                    Transform::from_xyz(
+                        row.tab_width * 0.5 - TAB_CLOSE_SIZE,
+                        0.0,
+                        0.3,
+                    )
+                    .with_rotation(Quat::from_rotation_z(std::f32::consts::FRAC_PI_4)),
+                    ChildOf(cell),
+                ));
+            }
+        }
+    }
+}
+
+/// Activates a tab on cell click. Close-glyph clicks land inside the cell
+/// region too, so close handling runs first and wins via the rebuild.
+pub fn handle_tab_clicks(
+    cells: Query<(&TabCell, &Clickable)>,
+    mut rows: Query<&mut TabBarState>,
+) {
+    for (cell, clickable) in &cells {
+        if !clickable.triggered {
+            continue;
+        }
+        if let Ok(mut state) = rows.get_mut(cell.row) {
+            state.active = cell.index;
+        }
+    }
+}
+
+/// Removes a tab when its cross is clicked, clamps the active index and
+/// emits [`WindowTabClosed`]. Mutating the row retriggers the layout
+/// rebuild, which recomputes `total_width` and cell positions.
+pub fn handle_tab_close_clicks(
+    glyphs: Query<(&TabCloseGlyph, &Clickable)>,
+    mut rows: Query<(&mut WindowTabRow, &mut TabBarState)>,
+    mut closed: EventWriter<WindowTabClosed>,
+) {
+    for (glyph, clickable) in &glyphs {
+        if !clickable.triggered {
+            continue;
+        }
+        let Ok((mut row, mut state)) = rows.get_mut(glyph.row) else {
+            continue;
+        };
+        if glyph.index >= row.labels.len() {
+            continue;
+        }
+        row.labels.remove(glyph.index);
+        clamp_active_tab(&mut state, row.labels.len());
+        closed.write(WindowTabClosed {
+            tab_root: glyph.row,
+            index: glyph.index,
+        });
+    }
+}
+
+/// Swap target for a drag of `delta_x` pixels from its anchor: the
+/// neighbouring index once travel exceeds half a tab width, `None`
+/// while the drag is still within its own slot or at the row's edge.
+pub fn tab_swap_target(index: usize, len: usize, delta_x: f32, tab_width: f32) -> Option<usize> {
+    if delta_x.abs() <= tab_width * 0.5 {
+        return None;
+    }
+    if delta_x > 0.0 {
+        (index + 1 < len).then_some(index + 1)
+    } else {
+        index.checked_sub(1)
+    }
+}
+
+/// Grabs the tab cell under the cursor on press and drops every drag on
+/// release. Grabbing is independent of `Clickable`: a press that never
+/// travels half a tab width releases as a plain activating click.
+pub fn handle_tab_drag_lifecycle(
+    mut commands: Commands,
+    mouse: Res<ButtonInput<MouseButton>>,
+    cursor: Res<CustomCursor>,
+    rows: Query<&WindowTabRow>,
+    cells: Query<(Entity, &TabCell, &GlobalTransform, Has<TabDrag>)>,
+) {
+    if !mouse.pressed(MouseButton::Left) {
+        for (entity, _, _, dragging) in &cells {
+            if dragging {
+                commands.entity(entity).remove::<TabDrag>();
+            }
+        }
+    }
+    if !mouse.just_pressed(MouseButton::Left) {
+        return;
+    }
+    for (entity, cell, transform, _) in &cells {
+        let Ok(row) = rows.get(cell.row) else {
+            continue;
+        };
+        let centre = transform.translation().truncate();
+        if (cursor.position.x - centre.x).abs() <= row.tab_width * 0.5
+            && (cursor.position.y - centre.y).abs() <= TAB_HEIGHT * 0.5
+        {
+            commands.entity(entity).insert(TabDrag {
+                anchor_x: cursor.position.x,
+            });
+        }
+    }
+}
+
+/// Carries a grabbed tab past its neighbours: each half tab width of
+/// horizontal travel swaps the label order, keeps the active index with
+/// the moved tab and emits [`WindowTabReordered`]. Labels are swapped
+/// behind change detection — the `Changed` rebuild would despawn the
+/// grabbed cell and end the drag — and the cells reorder incrementally
+/// instead.
+pub fn handle_tab_drag_reorder(
+    cursor: Res<CustomCursor>,
+    mut rows: Query<(&mut WindowTabRow, &mut TabBarState)>,
+    mut cells: Query<(&mut TabCell, Option<&mut TabDrag>)>,
+    mut glyphs: Query<&mut TabCloseGlyph>,
+    mut reordered: EventWriter<WindowTabReordered>,
+) {
+    // At most one cell can hold a grab, so the first hit is the drag.
+    let mut swap = None;
+    for (cell, drag) in &mut cells {
+        let Some(mut drag) = drag else {
+            continue;
+        };
+        let Ok((mut row, mut state)) = rows.get_mut(cell.row) else {
+            continue;
+        };
+        let delta_x = cursor.position.x - drag.anchor_x;
+        let Some(to) = tab_swap_target(cell.index, row.labels.len(), delta_x, row.tab_width)
+        else {
+            continue;
+        };
+        let from = cell.index;
+        row.bypass_change_detection().labels.swap(from, to);
+        if state.active == from {
+            state.active = to;
+        } else if state.active == to {
+            state.active = from;
+        }
+        let direction = if to > from { 1.0 } else { -1.0 };
+        drag.anchor_x += (row.tab_width + TAB_GAP) * direction;
+        reordered.write(WindowTabReordered {
+            tab_root: cell.row,
+            from,
+            to,
+        });
+        swap = Some((cell.row, from, to));
+        break;
+    }
+    let Some((row_entity, from, to)) = swap else {
+        return;
+    };
+    for (mut cell, _) in &mut cells {
+        if cell.row != row_entity {
+            continue;
+        }
+        if cell.index == from {
+            cell.index = to;
+        } else if cell.index == to {
+            cell.index = from;
+        }
+    }
+    for mut glyph in &mut glyphs {
+        if glyph.row != row_entity {
+            continue;
+        }
+        if glyph.index == from {
+            glyph.index = to;
+        } else if glyph.index == to {
+            glyph.index = from;
+        }
+    }
+}
+
+/// Slides each cell toward its slot so reorders animate instead of
+/// snapping. Full rebuilds spawn cells at their slot, so this only has
+/// work to do after an incremental reorder.
+pub fn animate_tab_cell_positions(
+    time: Res<Time>,
+    rows: Query<&WindowTabRow>,
+    mut cells: Query<(&TabCell, &mut Transform)>,
+) {
+    for (cell, mut transform) in &mut cells {
+        let Ok(row) = rows.get(cell.row) else {
+            continue;
+        };
+        let target = row.tab_centre_x(cell.index);
+        let current = transform.translation.x;
+        let step = TAB_REORDER_EASE_PX_S * time.delta_secs();
+        let next = if (target - current).abs() <= step {
+            target
+        } else {
+            current + step * (target - current).signum()
+        };
+        if next != current {
+            transform.translation.x = next;
+        }
+    }
+}
+
+/// Shows only the routed content matching the window's active tab.
+/// The window's tab row is itself routed as [`WindowContent`] to the
+/// same window; content without a `tab_index` is always visible.
+pub fn sync_tabbed_content_visibility(
+    rows: Query<(&super::WindowContent, &TabBarState)>,
+    mut contents: Query<(&super::WindowContent, &mut Visibility), Without<TabBarState>>,
+) {
+    for (content, mut visibility) in &mut contents {
+        let Some(tab_index) = content.tab_index else {
+            continue;
+        };
+        let Some(active) = rows
+            .iter()
+            .find(|(row, _)| row.window == content.window)
+            .map(|(_, state)| state.active)
+        else {
+            continue;
+        };
+        let wanted = if tab_index == active {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+        if *visibility != wanted {
+            *visibility = wanted;
+        }
+    }
+}
+
+/// Highlights the active tab's border.
+pub fn sync_tab_row_visuals(
+    rows: Query<&TabBarState, With<WindowTabRow>>,
+    mut cells: Query<(&TabCell, &mut BorderedRectangle)>,
+) {
+    for (cell, mut rectangle) in &mut cells {
+        let Ok(state) = rows.get(cell.row) else {
+            continue;
+        };
+        let color = if state.active == cell.index {
+            HIGHLIGHT_COLOR
+        } else {
+            DIM_COLOR
+        };
+        if rectangle.border_color != color {
+            rectangle.border_color = color;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(count: usize) -> WindowTabRow {
+        WindowTabRow::new(
+            (0..count).map(|i| format!("TAB {i}")).collect(),
+            80.0,
+        )
+    }
+
+    #[test]
+    fn total_width_shrinks_with_the_tab_count() {
+        let mut tabs = row(3);
+        let before = tabs.total_width();
+        tabs.labels.remove(1);
+        assert_eq!(tabs.total_width(), before - 80.0 - TAB_GAP);
+        tabs.labels.clear();
+        assert_eq!(tabs.total_width(), 0.0);
+    }
+
+    #[test]
+    fn drags_swap_only_past_half_a_tab_width_and_inside_the_row() {
+        assert_eq!(tab_swap_target(1, 3, 30.0, 80.0), None);
+        assert_eq!(tab_swap_target(1, 3, 50.0, 80.0), Some(2));
+        assert_eq!(tab_swap_target(1, 3, -50.0, 80.0), Some(0));
+        // Edge tabs have no neighbour to swap with on that side.
+        assert_eq!(tab_swap_target(2, 3, 50.0, 80.0), None);
+        assert_eq!(tab_swap_target(0, 3, -50.0, 80.0), None);
+    }
+
+    #[test]
+    fn active_index_clamps_after_removal() {
+        let mut state = TabBarState { active: 2 };
+        clamp_active_tab(&mut state, 2);
+        assert_eq!(state.active, 1);
+        clamp_active_tab(&mut state, 0);
+        assert_eq!(state.active, 0);
+    }
+}